@@ -0,0 +1,93 @@
+//! Benchmarks comparing the bulk slice conversions of unaligned integers
+//! against converting the values one by one via `From` and `TryFrom`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use intx::{I24, I48, U24, U40};
+
+criterion_group!(bench_slice, bench_from_repr, bench_to_repr);
+criterion_main!(bench_slice);
+
+/// The number of values per benchmark input.
+const LEN: usize = 1000;
+
+/// Returns `LEN` pseudo-random `u128` values.
+fn inputs() -> Vec<u128> {
+    let mut state = 0x853C_49E6_748F_EA9B_u64;
+    (0..LEN)
+        .map(|_| {
+            // Note: xorshift64 is good enough to shuffle benchmark inputs.
+            let mut next = || {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state
+            };
+            (u128::from(next()) << 64) | u128::from(next())
+        })
+        .collect()
+}
+
+macro_rules! values {
+    ( $ty:ty ) => {
+        inputs()
+            .into_iter()
+            .map(|value| <$ty as intx::UnalignedInteger>::from_repr_truncating(value as _))
+            .collect::<Vec<$ty>>()
+    };
+}
+
+fn bench_from_repr(c: &mut Criterion) {
+    let mut g = c.benchmark_group("from_repr");
+    macro_rules! bench {
+        ( $( $ty:ty => $from_repr:ident ),* ) => {
+            $(
+                let reprs = values!($ty).into_iter().map(|value| value.to_repr()).collect::<Vec<_>>();
+                let mut dst = vec![<$ty>::default(); LEN];
+                g.bench_function(concat!(stringify!($ty), "/slice"), |b| {
+                    b.iter(|| {
+                        <$ty>::$from_repr(black_box(&reprs), &mut dst).unwrap();
+                        black_box(&dst);
+                    })
+                });
+                g.bench_function(concat!(stringify!($ty), "/scalar"), |b| {
+                    b.iter(|| {
+                        for (src, dst) in black_box(&reprs).iter().zip(&mut dst) {
+                            *dst = <$ty>::try_from(*src).unwrap();
+                        }
+                        black_box(&dst);
+                    })
+                });
+            )*
+        };
+    }
+    bench!(U24 => slice_from_u32, I24 => slice_from_i32, U40 => slice_from_u64, I48 => slice_from_i64);
+    g.finish();
+}
+
+fn bench_to_repr(c: &mut Criterion) {
+    let mut g = c.benchmark_group("to_repr");
+    macro_rules! bench {
+        ( $( $ty:ty => $to_repr:ident ),* ) => {
+            $(
+                let values = values!($ty);
+                let mut dst = vec![<$ty>::default().to_repr(); LEN];
+                g.bench_function(concat!(stringify!($ty), "/slice"), |b| {
+                    b.iter(|| {
+                        <$ty>::$to_repr(black_box(&values), &mut dst);
+                        black_box(&dst);
+                    })
+                });
+                g.bench_function(concat!(stringify!($ty), "/scalar"), |b| {
+                    b.iter(|| {
+                        for (src, dst) in black_box(&values).iter().zip(&mut dst) {
+                            *dst = (*src).into();
+                        }
+                        black_box(&dst);
+                    })
+                });
+            )*
+        };
+    }
+    bench!(U24 => slice_to_u32, I24 => slice_to_i32, U40 => slice_to_u64, I48 => slice_to_i64);
+    g.finish();
+}
@@ -0,0 +1,483 @@
+//! Module to implement the checked, wrapping, saturating and overflowing
+//! arithmetic method families for all unaligned integer types provided by this crate.
+//!
+//! All computations are performed on the `Repr` primitive of the unaligned integer
+//! after which the result is truncated to the bitwidth of the unaligned integer.
+//! An overflow is reported whenever the `Repr` computation overflows or whenever
+//! the truncated result differs from the `Repr` result. This way overflow is always
+//! detected at the bitwidth of the unaligned integer, e.g. 24 bits for `U24`,
+//! instead of the bitwidth of its `Repr`, e.g. 32 bits for `U24`.
+
+/// Implements the arithmetic method families shared by signed and unsigned integers.
+macro_rules! impl_arith_methods {
+    ( $name:ident, $repr:ty ) => {
+        impl $name {
+            /// Truncates the `repr` result of an overflowing `Repr` operation to `Self`.
+            ///
+            /// Returns `true` in addition to the truncated result if either the `Repr`
+            /// computation overflowed or if `repr` does not fit into `Self`.
+            #[inline]
+            const fn from_overflowing_repr(
+                (repr, overflow): ($repr, ::core::primitive::bool),
+            ) -> (Self, ::core::primitive::bool) {
+                let result = Self::from_repr_truncating(repr);
+                (result, overflow || result.to_repr() != repr)
+            }
+
+            /// Calculates `self + rhs`.
+            ///
+            /// Returns a tuple of the addition along with a boolean indicating whether an arithmetic
+            /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+            #[inline]
+            pub const fn overflowing_add(self, rhs: Self) -> (Self, ::core::primitive::bool) {
+                Self::from_overflowing_repr(<$repr>::overflowing_add(self.to_repr(), rhs.to_repr()))
+            }
+
+            /// Calculates `self - rhs`.
+            ///
+            /// Returns a tuple of the subtraction along with a boolean indicating whether an arithmetic
+            /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+            #[inline]
+            pub const fn overflowing_sub(self, rhs: Self) -> (Self, ::core::primitive::bool) {
+                Self::from_overflowing_repr(<$repr>::overflowing_sub(self.to_repr(), rhs.to_repr()))
+            }
+
+            /// Calculates `self * rhs`.
+            ///
+            /// Returns a tuple of the multiplication along with a boolean indicating whether an arithmetic
+            /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+            #[inline]
+            pub const fn overflowing_mul(self, rhs: Self) -> (Self, ::core::primitive::bool) {
+                Self::from_overflowing_repr(<$repr>::overflowing_mul(self.to_repr(), rhs.to_repr()))
+            }
+
+            /// Calculates `self / rhs`.
+            ///
+            /// Returns a tuple of the quotient along with a boolean indicating whether an arithmetic
+            /// overflow would occur. If an overflow would have occurred then `self` is returned.
+            ///
+            /// # Panics
+            ///
+            /// If `rhs` is zero.
+            #[inline]
+            pub const fn overflowing_div(self, rhs: Self) -> (Self, ::core::primitive::bool) {
+                match Self::from_overflowing_repr(<$repr>::overflowing_div(
+                    self.to_repr(),
+                    rhs.to_repr(),
+                )) {
+                    (_, true) => (self, true),
+                    result => result,
+                }
+            }
+
+            /// Calculates the remainder of `self / rhs`.
+            ///
+            /// Returns a tuple of the remainder along with a boolean indicating whether an arithmetic
+            /// overflow would occur. If an overflow would have occurred then `0` is returned.
+            ///
+            /// # Panics
+            ///
+            /// If `rhs` is zero.
+            #[inline]
+            pub const fn overflowing_rem(self, rhs: Self) -> (Self, ::core::primitive::bool) {
+                let remainder = <$repr>::wrapping_rem(self.to_repr(), rhs.to_repr());
+                // Note: the remainder overflows exactly when the quotient does.
+                let (_, overflow) = self.overflowing_div(rhs);
+                match overflow {
+                    true => (Self::ZERO, true),
+                    false => (Self::from_repr_truncating(remainder), false),
+                }
+            }
+
+            /// Negates `self` in a wrapping fashion.
+            ///
+            /// Returns a tuple of the negated value along with a boolean indicating whether an arithmetic
+            /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+            #[inline]
+            pub const fn overflowing_neg(self) -> (Self, ::core::primitive::bool) {
+                Self::from_overflowing_repr(<$repr>::overflowing_neg(self.to_repr()))
+            }
+
+            /// Shifts `self` left by `rhs` bits.
+            ///
+            /// Returns a tuple of the shifted value along with a boolean indicating whether the shift
+            /// amount is larger than or equal to the number of bits. If the shift amount is too large,
+            /// then it is reduced modulo `Self::BITS` and this value is used to perform the shift.
+            #[inline]
+            #[allow(clippy::modulo_one)] // Note: `Self::BITS` is 1 for `U1` and `I1`.
+            pub const fn overflowing_shl(
+                self,
+                rhs: ::core::primitive::u32,
+            ) -> (Self, ::core::primitive::bool) {
+                let repr = <$repr>::wrapping_shl(self.to_repr(), rhs % Self::BITS);
+                (Self::from_repr_truncating(repr), rhs >= Self::BITS)
+            }
+
+            /// Shifts `self` right by `rhs` bits.
+            ///
+            /// Returns a tuple of the shifted value along with a boolean indicating whether the shift
+            /// amount is larger than or equal to the number of bits. If the shift amount is too large,
+            /// then it is reduced modulo `Self::BITS` and this value is used to perform the shift.
+            #[inline]
+            #[allow(clippy::modulo_one)] // Note: `Self::BITS` is 1 for `U1` and `I1`.
+            pub const fn overflowing_shr(
+                self,
+                rhs: ::core::primitive::u32,
+            ) -> (Self, ::core::primitive::bool) {
+                let repr = <$repr>::wrapping_shr(self.to_repr(), rhs % Self::BITS);
+                (Self::from_repr_truncating(repr), rhs >= Self::BITS)
+            }
+
+            /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+            ///
+            /// Returns a tuple of the exponentiation along with a boolean indicating whether an arithmetic
+            /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+            #[inline]
+            pub const fn overflowing_pow(
+                self,
+                exp: ::core::primitive::u32,
+            ) -> (Self, ::core::primitive::bool) {
+                Self::from_overflowing_repr(<$repr>::overflowing_pow(self.to_repr(), exp))
+            }
+
+            /// Checked integer addition. Computes `self + rhs`, returning `None` if overflow occurred.
+            #[inline]
+            pub const fn checked_add(self, rhs: Self) -> ::core::option::Option<Self> {
+                match self.overflowing_add(rhs) {
+                    (result, false) => ::core::option::Option::Some(result),
+                    (_, true) => ::core::option::Option::None,
+                }
+            }
+
+            /// Checked integer subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+            #[inline]
+            pub const fn checked_sub(self, rhs: Self) -> ::core::option::Option<Self> {
+                match self.overflowing_sub(rhs) {
+                    (result, false) => ::core::option::Option::Some(result),
+                    (_, true) => ::core::option::Option::None,
+                }
+            }
+
+            /// Checked integer multiplication. Computes `self * rhs`, returning `None` if overflow occurred.
+            #[inline]
+            pub const fn checked_mul(self, rhs: Self) -> ::core::option::Option<Self> {
+                match self.overflowing_mul(rhs) {
+                    (result, false) => ::core::option::Option::Some(result),
+                    (_, true) => ::core::option::Option::None,
+                }
+            }
+
+            /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0`
+            /// or the division results in overflow.
+            #[inline]
+            pub const fn checked_div(self, rhs: Self) -> ::core::option::Option<Self> {
+                if rhs.const_eq(Self::ZERO) {
+                    return ::core::option::Option::None;
+                }
+                match self.overflowing_div(rhs) {
+                    (result, false) => ::core::option::Option::Some(result),
+                    (_, true) => ::core::option::Option::None,
+                }
+            }
+
+            /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0`
+            /// or the division results in overflow.
+            #[inline]
+            pub const fn checked_rem(self, rhs: Self) -> ::core::option::Option<Self> {
+                if rhs.const_eq(Self::ZERO) {
+                    return ::core::option::Option::None;
+                }
+                match self.overflowing_rem(rhs) {
+                    (result, false) => ::core::option::Option::Some(result),
+                    (_, true) => ::core::option::Option::None,
+                }
+            }
+
+            /// Checked negation. Computes `-self`, returning `None` if overflow occurred.
+            #[inline]
+            pub const fn checked_neg(self) -> ::core::option::Option<Self> {
+                match self.overflowing_neg() {
+                    (result, false) => ::core::option::Option::Some(result),
+                    (_, true) => ::core::option::Option::None,
+                }
+            }
+
+            /// Checked shift left. Computes `self << rhs`, returning `None` if `rhs` is larger
+            /// than or equal to the number of bits in `self`.
+            #[inline]
+            pub const fn checked_shl(
+                self,
+                rhs: ::core::primitive::u32,
+            ) -> ::core::option::Option<Self> {
+                match self.overflowing_shl(rhs) {
+                    (result, false) => ::core::option::Option::Some(result),
+                    (_, true) => ::core::option::Option::None,
+                }
+            }
+
+            /// Checked shift right. Computes `self >> rhs`, returning `None` if `rhs` is larger
+            /// than or equal to the number of bits in `self`.
+            #[inline]
+            pub const fn checked_shr(
+                self,
+                rhs: ::core::primitive::u32,
+            ) -> ::core::option::Option<Self> {
+                match self.overflowing_shr(rhs) {
+                    (result, false) => ::core::option::Option::Some(result),
+                    (_, true) => ::core::option::Option::None,
+                }
+            }
+
+            /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if overflow occurred.
+            #[inline]
+            pub const fn checked_pow(
+                self,
+                exp: ::core::primitive::u32,
+            ) -> ::core::option::Option<Self> {
+                match self.overflowing_pow(exp) {
+                    (result, false) => ::core::option::Option::Some(result),
+                    (_, true) => ::core::option::Option::None,
+                }
+            }
+
+            /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the boundary of the type.
+            #[inline]
+            pub const fn wrapping_add(self, rhs: Self) -> Self {
+                self.overflowing_add(rhs).0
+            }
+
+            /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the boundary of the type.
+            #[inline]
+            pub const fn wrapping_sub(self, rhs: Self) -> Self {
+                self.overflowing_sub(rhs).0
+            }
+
+            /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at the boundary of the type.
+            #[inline]
+            pub const fn wrapping_mul(self, rhs: Self) -> Self {
+                self.overflowing_mul(rhs).0
+            }
+
+            /// Wrapping (modular) division. Computes `self / rhs`, wrapping around at the boundary of the type.
+            ///
+            /// # Panics
+            ///
+            /// If `rhs` is zero.
+            #[inline]
+            pub const fn wrapping_div(self, rhs: Self) -> Self {
+                self.overflowing_div(rhs).0
+            }
+
+            /// Wrapping (modular) remainder. Computes `self % rhs`, wrapping around at the boundary of the type.
+            ///
+            /// # Panics
+            ///
+            /// If `rhs` is zero.
+            #[inline]
+            pub const fn wrapping_rem(self, rhs: Self) -> Self {
+                self.overflowing_rem(rhs).0
+            }
+
+            /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type.
+            #[inline]
+            pub const fn wrapping_neg(self) -> Self {
+                self.overflowing_neg().0
+            }
+
+            /// Panic-free bitwise shift-left; yields `self << (rhs % Self::BITS)`.
+            #[inline]
+            pub const fn wrapping_shl(self, rhs: ::core::primitive::u32) -> Self {
+                self.overflowing_shl(rhs).0
+            }
+
+            /// Panic-free bitwise shift-right; yields `self >> (rhs % Self::BITS)`.
+            #[inline]
+            pub const fn wrapping_shr(self, rhs: ::core::primitive::u32) -> Self {
+                self.overflowing_shr(rhs).0
+            }
+
+            /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping around at the boundary of the type.
+            #[inline]
+            pub const fn wrapping_pow(self, exp: ::core::primitive::u32) -> Self {
+                self.overflowing_pow(exp).0
+            }
+        }
+    };
+}
+pub(crate) use impl_arith_methods;
+
+/// Implements the arithmetic methods specific to unsigned integers.
+macro_rules! impl_unsigned_arith_methods {
+    ( $name:ident, $repr:ty ) => {
+        impl $name {
+            /// Saturating integer addition. Computes `self + rhs`, saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                match self.checked_add(rhs) {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => Self::MAX,
+                }
+            }
+
+            /// Saturating integer subtraction. Computes `self - rhs`, saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                match self.checked_sub(rhs) {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => Self::MIN,
+                }
+            }
+
+            /// Saturating integer multiplication. Computes `self * rhs`, saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub const fn saturating_mul(self, rhs: Self) -> Self {
+                match self.checked_mul(rhs) {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => Self::MAX,
+                }
+            }
+
+            /// Saturating integer division. Computes `self / rhs`, saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Panics
+            ///
+            /// If `rhs` is zero.
+            #[inline]
+            pub const fn saturating_div(self, rhs: Self) -> Self {
+                self.wrapping_div(rhs)
+            }
+
+            /// Saturating integer exponentiation. Computes `self.pow(exp)`, saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub const fn saturating_pow(self, exp: ::core::primitive::u32) -> Self {
+                match self.checked_pow(exp) {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => Self::MAX,
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_unsigned_arith_methods;
+
+/// Implements the arithmetic methods specific to signed integers.
+macro_rules! impl_signed_arith_methods {
+    ( $name:ident, $repr:ty ) => {
+        impl $name {
+            /// Returns `true` if `self` is negative and `false` if `self` is zero or positive.
+            #[inline]
+            pub const fn is_negative(self) -> ::core::primitive::bool {
+                !self.is_positive()
+            }
+
+            /// Returns the bound that is saturated to if an overflowing result would have been negative.
+            #[inline]
+            const fn saturating_bound(is_negative: ::core::primitive::bool) -> Self {
+                match is_negative {
+                    true => Self::MIN,
+                    false => Self::MAX,
+                }
+            }
+
+            /// Computes the absolute value of `self`.
+            ///
+            /// Returns a tuple of the absolute value of `self` along with a boolean indicating whether an
+            /// overflow happened. If `self` is the minimum value, then the minimum value will be returned
+            /// again and `true` will be returned for an overflow happening.
+            #[inline]
+            pub const fn overflowing_abs(self) -> (Self, ::core::primitive::bool) {
+                match self.is_negative() {
+                    true => self.overflowing_neg(),
+                    false => (self, false),
+                }
+            }
+
+            /// Checked absolute value. Computes `self.abs()`, returning `None` if `self == MIN`.
+            #[inline]
+            pub const fn checked_abs(self) -> ::core::option::Option<Self> {
+                match self.overflowing_abs() {
+                    (result, false) => ::core::option::Option::Some(result),
+                    (_, true) => ::core::option::Option::None,
+                }
+            }
+
+            /// Wrapping (modular) absolute value. Computes `self.abs()`, wrapping around at the boundary of the type.
+            #[inline]
+            pub const fn wrapping_abs(self) -> Self {
+                self.overflowing_abs().0
+            }
+
+            /// Saturating absolute value. Computes `self.abs()`, returning `MAX` if `self == MIN` instead of overflowing.
+            #[inline]
+            pub const fn saturating_abs(self) -> Self {
+                match self.checked_abs() {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => Self::MAX,
+                }
+            }
+
+            /// Saturating integer negation. Computes `-self`, returning `MAX` if `self == MIN` instead of overflowing.
+            #[inline]
+            pub const fn saturating_neg(self) -> Self {
+                match self.checked_neg() {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => Self::MAX,
+                }
+            }
+
+            /// Saturating integer addition. Computes `self + rhs`, saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                match self.checked_add(rhs) {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => Self::saturating_bound(rhs.is_negative()),
+                }
+            }
+
+            /// Saturating integer subtraction. Computes `self - rhs`, saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                match self.checked_sub(rhs) {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => Self::saturating_bound(!rhs.is_negative()),
+                }
+            }
+
+            /// Saturating integer multiplication. Computes `self * rhs`, saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub const fn saturating_mul(self, rhs: Self) -> Self {
+                match self.checked_mul(rhs) {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => {
+                        Self::saturating_bound(self.is_negative() != rhs.is_negative())
+                    }
+                }
+            }
+
+            /// Saturating integer division. Computes `self / rhs`, saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Panics
+            ///
+            /// If `rhs` is zero.
+            #[inline]
+            pub const fn saturating_div(self, rhs: Self) -> Self {
+                match self.overflowing_div(rhs) {
+                    (result, false) => result,
+                    (_, true) => Self::MAX,
+                }
+            }
+
+            /// Saturating integer exponentiation. Computes `self.pow(exp)`, saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub const fn saturating_pow(self, exp: ::core::primitive::u32) -> Self {
+                match self.checked_pow(exp) {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => {
+                        Self::saturating_bound(self.is_negative() && exp % 2 == 1)
+                    }
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_signed_arith_methods;
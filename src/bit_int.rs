@@ -0,0 +1,597 @@
+//! Module to define the bit-granular integer types such as `U12`, `U20` or `I7`.
+//!
+//! Unlike the integer types defined in `defs.rs` these have a bitwidth that is not
+//! a multiple of 8. Their values are stored in the smallest Rust primitive that can
+//! represent them, e.g. `U12` is stored in a `u16`, and are always kept within the
+//! bounds of their bitwidth. Signed values are stored sign-extended.
+//!
+//! Use `BitPacked` to store many of them contiguously at the bit level.
+
+macro_rules! bit_int {
+    (
+        $(
+            $( #[$docs:meta] )*
+            @[bits($bits:literal), $signedness:ident]
+            $vis:vis struct $name:ident($repr:ty);
+        )*
+    ) => {
+        $(
+            $( #[$docs] )*
+            #[derive(
+                ::core::marker::Copy,
+                ::core::clone::Clone,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::cmp::PartialOrd,
+                ::core::cmp::Ord,
+                ::core::hash::Hash,
+                ::core::default::Default,
+            )]
+            #[cfg_attr(
+                feature = "zerocopy",
+                derive(::zerocopy::IntoBytes, ::zerocopy::Immutable, ::zerocopy::KnownLayout)
+            )]
+            #[repr(transparent)]
+            $vis struct $name($repr);
+
+            bit_int!(@impl $name, $repr, $bits);
+            bit_int!(@impl $name, $repr, $bits, $signedness);
+        )*
+    };
+    ( @impl $name:ident, $repr:ty, $bits:literal ) => {
+        impl $name {
+            /// The amount of bits required by this integer type.
+            pub const BITS: ::core::primitive::u32 = $bits;
+
+            /// The integer value `0`.
+            const ZERO: Self = Self(0);
+
+            /// Creates an integer from the `Repr` primitive `value` if it is within bounds.
+            #[inline]
+            pub const fn new(value: $repr) -> ::core::option::Option<Self> {
+                let result = Self::from_repr_truncating(value);
+                if result.0 != value {
+                    return ::core::option::Option::None;
+                }
+                ::core::option::Option::Some(result)
+            }
+
+            /// Creates an integer from the `Repr` primitive `value`.
+            ///
+            /// This is meant to be used in `const` contexts where out of bounds
+            /// values result in a compilation error instead of a runtime panic.
+            ///
+            /// # Panics
+            ///
+            /// If `value` is out of bounds for `Self`.
+            #[inline]
+            pub const fn new_const(value: $repr) -> Self {
+                match Self::new(value) {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => ::core::panic!(::core::concat!(
+                        "value is out of bounds for ",
+                        ::core::stringify!($name),
+                    )),
+                }
+            }
+
+            /// Returns the integer value converted to its `Repr` primitive type.
+            ///
+            /// # Example
+            ///
+            /// For `U12` the `Repr` primitive type is `u16`.
+            #[inline]
+            pub const fn to_repr(self) -> $repr {
+                self.0
+            }
+
+            /// Returns `true` if `self` and `other` are equal.
+            ///
+            /// This is the `const` equivalent of `PartialEq::eq`.
+            #[inline]
+            pub const fn const_eq(self, other: Self) -> ::core::primitive::bool {
+                self.0 == other.0
+            }
+
+            /// Returns the [`Ordering`](::core::cmp::Ordering) between `self` and `other`.
+            ///
+            /// This is the `const` equivalent of `Ord::cmp`.
+            #[inline]
+            pub const fn const_cmp(self, other: Self) -> ::core::cmp::Ordering {
+                if self.0 < other.0 {
+                    return ::core::cmp::Ordering::Less;
+                }
+                if self.0 > other.0 {
+                    return ::core::cmp::Ordering::Greater;
+                }
+                ::core::cmp::Ordering::Equal
+            }
+        }
+
+        $crate::arith::impl_arith_methods!($name, $repr);
+        $crate::ops::impl_arith_ops!($name);
+        $crate::ops::impl_shift_ops!($name);
+        $crate::parse::impl_from_str!($name, $repr);
+        bit_int!(@bit_ops $name, BitAnd::bitand, BitAndAssign::bitand_assign, &);
+        bit_int!(@bit_ops $name, BitOr::bitor, BitOrAssign::bitor_assign, |);
+        bit_int!(@bit_ops $name, BitXor::bitxor, BitXorAssign::bitxor_assign, ^);
+        bit_int!(@fmt $name: Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp);
+
+        impl<'a> ::core::ops::Not for &'a $name {
+            type Output = $name;
+
+            #[inline]
+            fn not(self) -> Self::Output {
+                <$name as ::core::ops::Not>::not(*self)
+            }
+        }
+
+        impl $crate::traits::sealed::Sealed for $name {}
+
+        impl $crate::BitPackable for $name {
+            const BITS: ::core::primitive::u32 = $bits;
+
+            #[inline]
+            #[allow(clippy::unnecessary_cast)] // Note: `$repr` might be `u64` already.
+            fn to_bits(self) -> ::core::primitive::u64 {
+                // Note: signed values are sign-extended and thus need to be masked.
+                (self.0 as ::core::primitive::u64) & (::core::primitive::u64::MAX >> (64 - $bits))
+            }
+
+            #[inline]
+            #[allow(clippy::unnecessary_cast)] // Note: `$repr` might be `u64` already.
+            fn from_bits(bits: ::core::primitive::u64) -> Self {
+                Self::from_repr_truncating(bits as $repr)
+            }
+        }
+
+        // SAFETY: `$name` is a `#[repr(transparent)]` wrapper around a Rust primitive
+        //         integer for which the all-zero bit pattern is the valid value `0`.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::Zeroable for $name {}
+
+        // SAFETY: `$name` is a `#[repr(transparent)]` wrapper around a Rust primitive
+        //         integer which has no padding bytes.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::NoUninit for $name {}
+    };
+    ( @impl $name:ident, $repr:ty, $bits:literal, unsigned ) => {
+        impl $name {
+            /// The smallest value that can be represented by this integer type.
+            pub const MIN: Self = Self(0);
+
+            /// The largest value that can be represented by this integer type.
+            pub const MAX: Self = Self(<$repr>::MAX >> (<$repr>::BITS - $bits));
+
+            /// Creates an integer from the `Repr` primitive truncating its most significant bits.
+            #[inline]
+            pub(crate) const fn from_repr_truncating(repr: $repr) -> Self {
+                Self(repr & Self::MAX.0)
+            }
+
+            /// Returns the sign extension byte which is always `0x00` for unsigned integers.
+            #[inline]
+            pub(crate) const fn sign_ext_byte(self) -> ::core::primitive::u8 {
+                0x00_u8
+            }
+        }
+
+        $crate::arith::impl_unsigned_arith_methods!($name, $repr);
+
+        impl ::core::ops::Not for $name {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self::Output {
+                Self::from_repr_truncating(!self.0)
+            }
+        }
+    };
+    ( @impl $name:ident, $repr:ty, $bits:literal, signed ) => {
+        impl $name {
+            /// The smallest value that can be represented by this integer type.
+            pub const MIN: Self = Self(<$repr>::MIN >> (<$repr>::BITS - $bits));
+
+            /// The largest value that can be represented by this integer type.
+            pub const MAX: Self = Self(<$repr>::MAX >> (<$repr>::BITS - $bits));
+
+            /// Creates an integer from the `Repr` primitive truncating its most significant bits.
+            #[inline]
+            pub(crate) const fn from_repr_truncating(repr: $repr) -> Self {
+                let shift = <$repr>::BITS - $bits;
+                // Note: the arithmetic right shift sign-extends the truncated value.
+                Self(repr.wrapping_shl(shift) >> shift)
+            }
+
+            /// Returns `true` if `self` is positive.
+            #[inline]
+            pub(crate) const fn is_positive(self) -> ::core::primitive::bool {
+                self.0 >= 0
+            }
+
+            /// Returns the sign extension byte of `self`.
+            #[inline]
+            pub(crate) const fn sign_ext_byte(self) -> ::core::primitive::u8 {
+                $crate::utils::sign_ext_byte(self.is_positive())
+            }
+        }
+
+        $crate::arith::impl_signed_arith_methods!($name, $repr);
+        $crate::ops::impl_neg_op!($name);
+
+        impl ::core::ops::Not for $name {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self::Output {
+                // Note: the complement of a sign-extended value is sign-extended as well.
+                Self(!self.0)
+            }
+        }
+    };
+    (
+        @bit_ops $name:ident,
+        $trait:ident::$method:ident,
+        $assign_trait:ident::$assign_method:ident,
+        $op:tt
+    ) => {
+        impl ::core::ops::$trait for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self(self.0 $op rhs.0)
+            }
+        }
+
+        $crate::ops::forward_ref_binop!(impl $trait::$method, $assign_trait::$assign_method for $name);
+    };
+    ( @fmt $name:ident: $( $fmt_trait:ident ),* ) => {
+        $(
+            impl ::core::fmt::$fmt_trait for $name {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::$fmt_trait::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+bit_int! {
+    /// 1-bit unsigned integer stored in a `u8`.
+    @[bits(1), unsigned]
+    pub struct U1(u8);
+    /// 1-bit signed integer stored in an `i8`.
+    @[bits(1), signed]
+    pub struct I1(i8);
+    /// 2-bit unsigned integer stored in a `u8`.
+    @[bits(2), unsigned]
+    pub struct U2(u8);
+    /// 2-bit signed integer stored in an `i8`.
+    @[bits(2), signed]
+    pub struct I2(i8);
+    /// 3-bit unsigned integer stored in a `u8`.
+    @[bits(3), unsigned]
+    pub struct U3(u8);
+    /// 3-bit signed integer stored in an `i8`.
+    @[bits(3), signed]
+    pub struct I3(i8);
+    /// 4-bit unsigned integer stored in a `u8`.
+    @[bits(4), unsigned]
+    pub struct U4(u8);
+    /// 4-bit signed integer stored in an `i8`.
+    @[bits(4), signed]
+    pub struct I4(i8);
+    /// 5-bit unsigned integer stored in a `u8`.
+    @[bits(5), unsigned]
+    pub struct U5(u8);
+    /// 5-bit signed integer stored in an `i8`.
+    @[bits(5), signed]
+    pub struct I5(i8);
+    /// 6-bit unsigned integer stored in a `u8`.
+    @[bits(6), unsigned]
+    pub struct U6(u8);
+    /// 6-bit signed integer stored in an `i8`.
+    @[bits(6), signed]
+    pub struct I6(i8);
+    /// 7-bit unsigned integer stored in a `u8`.
+    @[bits(7), unsigned]
+    pub struct U7(u8);
+    /// 7-bit signed integer stored in an `i8`.
+    @[bits(7), signed]
+    pub struct I7(i8);
+    /// 9-bit unsigned integer stored in a `u16`.
+    @[bits(9), unsigned]
+    pub struct U9(u16);
+    /// 9-bit signed integer stored in an `i16`.
+    @[bits(9), signed]
+    pub struct I9(i16);
+    /// 10-bit unsigned integer stored in a `u16`.
+    @[bits(10), unsigned]
+    pub struct U10(u16);
+    /// 10-bit signed integer stored in an `i16`.
+    @[bits(10), signed]
+    pub struct I10(i16);
+    /// 11-bit unsigned integer stored in a `u16`.
+    @[bits(11), unsigned]
+    pub struct U11(u16);
+    /// 11-bit signed integer stored in an `i16`.
+    @[bits(11), signed]
+    pub struct I11(i16);
+    /// 12-bit unsigned integer stored in a `u16`.
+    @[bits(12), unsigned]
+    pub struct U12(u16);
+    /// 12-bit signed integer stored in an `i16`.
+    @[bits(12), signed]
+    pub struct I12(i16);
+    /// 13-bit unsigned integer stored in a `u16`.
+    @[bits(13), unsigned]
+    pub struct U13(u16);
+    /// 13-bit signed integer stored in an `i16`.
+    @[bits(13), signed]
+    pub struct I13(i16);
+    /// 14-bit unsigned integer stored in a `u16`.
+    @[bits(14), unsigned]
+    pub struct U14(u16);
+    /// 14-bit signed integer stored in an `i16`.
+    @[bits(14), signed]
+    pub struct I14(i16);
+    /// 15-bit unsigned integer stored in a `u16`.
+    @[bits(15), unsigned]
+    pub struct U15(u16);
+    /// 15-bit signed integer stored in an `i16`.
+    @[bits(15), signed]
+    pub struct I15(i16);
+    /// 17-bit unsigned integer stored in a `u32`.
+    @[bits(17), unsigned]
+    pub struct U17(u32);
+    /// 17-bit signed integer stored in an `i32`.
+    @[bits(17), signed]
+    pub struct I17(i32);
+    /// 18-bit unsigned integer stored in a `u32`.
+    @[bits(18), unsigned]
+    pub struct U18(u32);
+    /// 18-bit signed integer stored in an `i32`.
+    @[bits(18), signed]
+    pub struct I18(i32);
+    /// 19-bit unsigned integer stored in a `u32`.
+    @[bits(19), unsigned]
+    pub struct U19(u32);
+    /// 19-bit signed integer stored in an `i32`.
+    @[bits(19), signed]
+    pub struct I19(i32);
+    /// 20-bit unsigned integer stored in a `u32`.
+    @[bits(20), unsigned]
+    pub struct U20(u32);
+    /// 20-bit signed integer stored in an `i32`.
+    @[bits(20), signed]
+    pub struct I20(i32);
+    /// 21-bit unsigned integer stored in a `u32`.
+    @[bits(21), unsigned]
+    pub struct U21(u32);
+    /// 21-bit signed integer stored in an `i32`.
+    @[bits(21), signed]
+    pub struct I21(i32);
+    /// 22-bit unsigned integer stored in a `u32`.
+    @[bits(22), unsigned]
+    pub struct U22(u32);
+    /// 22-bit signed integer stored in an `i32`.
+    @[bits(22), signed]
+    pub struct I22(i32);
+    /// 23-bit unsigned integer stored in a `u32`.
+    @[bits(23), unsigned]
+    pub struct U23(u32);
+    /// 23-bit signed integer stored in an `i32`.
+    @[bits(23), signed]
+    pub struct I23(i32);
+    /// 25-bit unsigned integer stored in a `u32`.
+    @[bits(25), unsigned]
+    pub struct U25(u32);
+    /// 25-bit signed integer stored in an `i32`.
+    @[bits(25), signed]
+    pub struct I25(i32);
+    /// 26-bit unsigned integer stored in a `u32`.
+    @[bits(26), unsigned]
+    pub struct U26(u32);
+    /// 26-bit signed integer stored in an `i32`.
+    @[bits(26), signed]
+    pub struct I26(i32);
+    /// 27-bit unsigned integer stored in a `u32`.
+    @[bits(27), unsigned]
+    pub struct U27(u32);
+    /// 27-bit signed integer stored in an `i32`.
+    @[bits(27), signed]
+    pub struct I27(i32);
+    /// 28-bit unsigned integer stored in a `u32`.
+    @[bits(28), unsigned]
+    pub struct U28(u32);
+    /// 28-bit signed integer stored in an `i32`.
+    @[bits(28), signed]
+    pub struct I28(i32);
+    /// 29-bit unsigned integer stored in a `u32`.
+    @[bits(29), unsigned]
+    pub struct U29(u32);
+    /// 29-bit signed integer stored in an `i32`.
+    @[bits(29), signed]
+    pub struct I29(i32);
+    /// 30-bit unsigned integer stored in a `u32`.
+    @[bits(30), unsigned]
+    pub struct U30(u32);
+    /// 30-bit signed integer stored in an `i32`.
+    @[bits(30), signed]
+    pub struct I30(i32);
+    /// 31-bit unsigned integer stored in a `u32`.
+    @[bits(31), unsigned]
+    pub struct U31(u32);
+    /// 31-bit signed integer stored in an `i32`.
+    @[bits(31), signed]
+    pub struct I31(i32);
+    /// 33-bit unsigned integer stored in a `u64`.
+    @[bits(33), unsigned]
+    pub struct U33(u64);
+    /// 33-bit signed integer stored in an `i64`.
+    @[bits(33), signed]
+    pub struct I33(i64);
+    /// 34-bit unsigned integer stored in a `u64`.
+    @[bits(34), unsigned]
+    pub struct U34(u64);
+    /// 34-bit signed integer stored in an `i64`.
+    @[bits(34), signed]
+    pub struct I34(i64);
+    /// 35-bit unsigned integer stored in a `u64`.
+    @[bits(35), unsigned]
+    pub struct U35(u64);
+    /// 35-bit signed integer stored in an `i64`.
+    @[bits(35), signed]
+    pub struct I35(i64);
+    /// 36-bit unsigned integer stored in a `u64`.
+    @[bits(36), unsigned]
+    pub struct U36(u64);
+    /// 36-bit signed integer stored in an `i64`.
+    @[bits(36), signed]
+    pub struct I36(i64);
+    /// 37-bit unsigned integer stored in a `u64`.
+    @[bits(37), unsigned]
+    pub struct U37(u64);
+    /// 37-bit signed integer stored in an `i64`.
+    @[bits(37), signed]
+    pub struct I37(i64);
+    /// 38-bit unsigned integer stored in a `u64`.
+    @[bits(38), unsigned]
+    pub struct U38(u64);
+    /// 38-bit signed integer stored in an `i64`.
+    @[bits(38), signed]
+    pub struct I38(i64);
+    /// 39-bit unsigned integer stored in a `u64`.
+    @[bits(39), unsigned]
+    pub struct U39(u64);
+    /// 39-bit signed integer stored in an `i64`.
+    @[bits(39), signed]
+    pub struct I39(i64);
+    /// 41-bit unsigned integer stored in a `u64`.
+    @[bits(41), unsigned]
+    pub struct U41(u64);
+    /// 41-bit signed integer stored in an `i64`.
+    @[bits(41), signed]
+    pub struct I41(i64);
+    /// 42-bit unsigned integer stored in a `u64`.
+    @[bits(42), unsigned]
+    pub struct U42(u64);
+    /// 42-bit signed integer stored in an `i64`.
+    @[bits(42), signed]
+    pub struct I42(i64);
+    /// 43-bit unsigned integer stored in a `u64`.
+    @[bits(43), unsigned]
+    pub struct U43(u64);
+    /// 43-bit signed integer stored in an `i64`.
+    @[bits(43), signed]
+    pub struct I43(i64);
+    /// 44-bit unsigned integer stored in a `u64`.
+    @[bits(44), unsigned]
+    pub struct U44(u64);
+    /// 44-bit signed integer stored in an `i64`.
+    @[bits(44), signed]
+    pub struct I44(i64);
+    /// 45-bit unsigned integer stored in a `u64`.
+    @[bits(45), unsigned]
+    pub struct U45(u64);
+    /// 45-bit signed integer stored in an `i64`.
+    @[bits(45), signed]
+    pub struct I45(i64);
+    /// 46-bit unsigned integer stored in a `u64`.
+    @[bits(46), unsigned]
+    pub struct U46(u64);
+    /// 46-bit signed integer stored in an `i64`.
+    @[bits(46), signed]
+    pub struct I46(i64);
+    /// 47-bit unsigned integer stored in a `u64`.
+    @[bits(47), unsigned]
+    pub struct U47(u64);
+    /// 47-bit signed integer stored in an `i64`.
+    @[bits(47), signed]
+    pub struct I47(i64);
+    /// 49-bit unsigned integer stored in a `u64`.
+    @[bits(49), unsigned]
+    pub struct U49(u64);
+    /// 49-bit signed integer stored in an `i64`.
+    @[bits(49), signed]
+    pub struct I49(i64);
+    /// 50-bit unsigned integer stored in a `u64`.
+    @[bits(50), unsigned]
+    pub struct U50(u64);
+    /// 50-bit signed integer stored in an `i64`.
+    @[bits(50), signed]
+    pub struct I50(i64);
+    /// 51-bit unsigned integer stored in a `u64`.
+    @[bits(51), unsigned]
+    pub struct U51(u64);
+    /// 51-bit signed integer stored in an `i64`.
+    @[bits(51), signed]
+    pub struct I51(i64);
+    /// 52-bit unsigned integer stored in a `u64`.
+    @[bits(52), unsigned]
+    pub struct U52(u64);
+    /// 52-bit signed integer stored in an `i64`.
+    @[bits(52), signed]
+    pub struct I52(i64);
+    /// 53-bit unsigned integer stored in a `u64`.
+    @[bits(53), unsigned]
+    pub struct U53(u64);
+    /// 53-bit signed integer stored in an `i64`.
+    @[bits(53), signed]
+    pub struct I53(i64);
+    /// 54-bit unsigned integer stored in a `u64`.
+    @[bits(54), unsigned]
+    pub struct U54(u64);
+    /// 54-bit signed integer stored in an `i64`.
+    @[bits(54), signed]
+    pub struct I54(i64);
+    /// 55-bit unsigned integer stored in a `u64`.
+    @[bits(55), unsigned]
+    pub struct U55(u64);
+    /// 55-bit signed integer stored in an `i64`.
+    @[bits(55), signed]
+    pub struct I55(i64);
+    /// 57-bit unsigned integer stored in a `u64`.
+    @[bits(57), unsigned]
+    pub struct U57(u64);
+    /// 57-bit signed integer stored in an `i64`.
+    @[bits(57), signed]
+    pub struct I57(i64);
+    /// 58-bit unsigned integer stored in a `u64`.
+    @[bits(58), unsigned]
+    pub struct U58(u64);
+    /// 58-bit signed integer stored in an `i64`.
+    @[bits(58), signed]
+    pub struct I58(i64);
+    /// 59-bit unsigned integer stored in a `u64`.
+    @[bits(59), unsigned]
+    pub struct U59(u64);
+    /// 59-bit signed integer stored in an `i64`.
+    @[bits(59), signed]
+    pub struct I59(i64);
+    /// 60-bit unsigned integer stored in a `u64`.
+    @[bits(60), unsigned]
+    pub struct U60(u64);
+    /// 60-bit signed integer stored in an `i64`.
+    @[bits(60), signed]
+    pub struct I60(i64);
+    /// 61-bit unsigned integer stored in a `u64`.
+    @[bits(61), unsigned]
+    pub struct U61(u64);
+    /// 61-bit signed integer stored in an `i64`.
+    @[bits(61), signed]
+    pub struct I61(i64);
+    /// 62-bit unsigned integer stored in a `u64`.
+    @[bits(62), unsigned]
+    pub struct U62(u64);
+    /// 62-bit signed integer stored in an `i64`.
+    @[bits(62), signed]
+    pub struct I62(i64);
+    /// 63-bit unsigned integer stored in a `u64`.
+    @[bits(63), unsigned]
+    pub struct U63(u64);
+    /// 63-bit signed integer stored in an `i64`.
+    @[bits(63), signed]
+    pub struct I63(i64);
+}
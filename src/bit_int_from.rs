@@ -0,0 +1,804 @@
+//! Module to implement all `From` and `TryFrom` implementations between
+//! the bit-granular integer types and Rust primitives as well as the
+//! byte-width unaligned integer types such as `U16` or `U24`.
+//!
+//! Conversions are infallible if they are lossless and fallible otherwise.
+//! Conversions from and to `usize` and `isize` are infallible only if they
+//! are lossless on all supported pointer widths, namely 16, 32 and 64 bits.
+
+use crate::{
+    I1, I10, I104, I11, I112, I12, I120, I128, I13, I14, I15, I16, I17, I18, I19, I2, I20, I21,
+    I22, I23, I24, I25, I26, I27, I28, I29, I3, I30, I31, I32, I33, I34, I35, I36, I37, I38, I39,
+    I4, I40, I41, I42, I43, I44, I45, I46, I47, I48, I49, I5, I50, I51, I52, I53, I54, I55, I56,
+    I57, I58, I59, I6, I60, I61, I62, I63, I64, I7, I72, I80, I88, I9, I96, U1, U10, U104, U11,
+    U112, U12, U120, U128, U13, U14, U15, U16, U17, U18, U19, U2, U20, U21, U22, U23, U24, U25,
+    U26, U27, U28, U29, U3, U30, U31, U32, U33, U34, U35, U36, U37, U38, U39, U4, U40, U41, U42,
+    U43, U44, U45, U46, U47, U48, U49, U5, U50, U51, U52, U53, U54, U55, U56, U57, U58, U59, U6,
+    U60, U61, U62, U63, U64, U7, U72, U80, U88, U9, U96,
+};
+
+macro_rules! impl_bit_int_conversions {
+    (
+        $(
+            $name:ident {
+                into: [$( $into_prim:ty ),*], [$( $into_unaligned:ty ),*];
+                try_into: [$( $try_into_prim:ty ),*], [$( $try_into_unaligned:ty ),*];
+                from: [$( $from_prim:ty ),*], [$( $from_unaligned:ty ),*];
+                try_from: [$( $try_from_prim:ty ),*], [$( $try_from_unaligned:ty ),*];
+            }
+        )*
+    ) => {
+        $(
+            $( impl_bit_int_conversions!(@into $name => $into_prim as primitive); )*
+            $( impl_bit_int_conversions!(@into $name => $into_unaligned as unaligned); )*
+            $( impl_bit_int_conversions!(@try_into $name => $try_into_prim); )*
+            $( impl_bit_int_conversions!(@try_into $name => $try_into_unaligned); )*
+            $( impl_bit_int_conversions!(@from $from_prim => $name as primitive); )*
+            $( impl_bit_int_conversions!(@from $from_unaligned => $name as unaligned); )*
+            $( impl_bit_int_conversions!(@try_from $try_from_prim => $name as primitive); )*
+            $( impl_bit_int_conversions!(@try_from $try_from_unaligned => $name as unaligned); )*
+        )*
+    };
+    ( @into $from:ty => $to:ty as primitive ) => {
+        impl ::core::convert::From<$from> for $to {
+            #[inline]
+            #[allow(clippy::unnecessary_cast)] // Note: `$to` might be the `Repr` of `$from` already.
+            fn from(value: $from) -> Self {
+                value.to_repr() as $to
+            }
+        }
+    };
+    ( @into $from:ty => $to:ty as unaligned ) => {
+        impl ::core::convert::From<$from> for $to {
+            #[inline]
+            fn from(value: $from) -> Self {
+                let bytes = value.to_repr().to_ne_bytes();
+                Self::from_ne_bytes($crate::utils::resize_bytes(bytes, value.sign_ext_byte()))
+            }
+        }
+    };
+    ( @try_into $from:ty => $to:ty ) => {
+        impl ::core::convert::TryFrom<$from> for $to {
+            type Error = $crate::TryFromIntError;
+
+            #[inline]
+            fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
+                <$to>::try_from(value.to_repr())
+                    .map_err(|_| $crate::error::try_from_int_error!($to, value.sign_ext_byte() != 0x00_u8))
+            }
+        }
+    };
+    ( @from $from:ty => $to:ty as primitive ) => {
+        impl ::core::convert::From<$from> for $to {
+            #[inline]
+            fn from(value: $from) -> Self {
+                Self::from_repr_truncating(value as _)
+            }
+        }
+    };
+    ( @from $from:ty => $to:ty as unaligned ) => {
+        impl ::core::convert::From<$from> for $to {
+            #[inline]
+            fn from(value: $from) -> Self {
+                Self::from_repr_truncating(value.to_repr() as _)
+            }
+        }
+    };
+    ( @try_from $from:ty => $to:ty as primitive ) => {
+        impl ::core::convert::TryFrom<$from> for $to {
+            type Error = $crate::TryFromIntError;
+
+            #[inline]
+            #[allow(clippy::unnecessary_fallible_conversions)] // Note: `$from` might be the `Repr` of `$to`.
+            fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
+                ::core::convert::TryFrom::try_from(value)
+                    .ok()
+                    .and_then(Self::new)
+                    .ok_or_else(|| {
+                        $crate::error::try_from_int_error!(
+                            Self,
+                            value < <$from as ::core::default::Default>::default()
+                        )
+                    })
+            }
+        }
+    };
+    ( @try_from $from:ty => $to:ty as unaligned ) => {
+        impl ::core::convert::TryFrom<$from> for $to {
+            type Error = $crate::TryFromIntError;
+
+            #[inline]
+            #[allow(clippy::unnecessary_fallible_conversions)] // Note: the `Repr` of `$from` might be the `Repr` of `$to`.
+            fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
+                ::core::convert::TryFrom::try_from(value.to_repr())
+                    .ok()
+                    .and_then(Self::new)
+                    .ok_or_else(|| $crate::error::try_from_int_error!(
+                        Self,
+                        <$from as $crate::Integer>::sign_ext_byte(value) != 0x00_u8
+                    ))
+            }
+        }
+    };
+}
+// The following macro call declares the `From` and `TryFrom` implementations of
+// every bit-granular integer type ordered by bitwidth with unsigned types first.
+//
+// - `into` and `try_into` list the types that the bit-granular integer converts into.
+// - `from` and `try_from` list the types that the bit-granular integer converts from.
+//
+// Each list is split into Rust primitives first and byte-width unaligned integers second.
+impl_bit_int_conversions! {
+    U1 {
+        into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [], [];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I1 {
+        into: [i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U2 {
+        into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [], [];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I2 {
+        into: [i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U3 {
+        into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [], [];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I3 {
+        into: [i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U4 {
+        into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [], [];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I4 {
+        into: [i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U5 {
+        into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [], [];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I5 {
+        into: [i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U6 {
+        into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [], [];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I6 {
+        into: [i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U7 {
+        into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [], [];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I7 {
+        into: [i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [], [];
+        try_from: [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U9 {
+        into: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, i8], [];
+        from: [u8], [];
+        try_from: [u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I9 {
+        into: [i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, i8], [];
+        try_from: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U10 {
+        into: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, i8], [];
+        from: [u8], [];
+        try_from: [u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I10 {
+        into: [i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, i8], [];
+        try_from: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U11 {
+        into: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, i8], [];
+        from: [u8], [];
+        try_from: [u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I11 {
+        into: [i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, i8], [];
+        try_from: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U12 {
+        into: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, i8], [];
+        from: [u8], [];
+        try_from: [u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I12 {
+        into: [i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, i8], [];
+        try_from: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U13 {
+        into: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, i8], [];
+        from: [u8], [];
+        try_from: [u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I13 {
+        into: [i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, i8], [];
+        try_from: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U14 {
+        into: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, i8], [];
+        from: [u8], [];
+        try_from: [u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I14 {
+        into: [i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, i8], [];
+        try_from: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U15 {
+        into: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, i8], [];
+        from: [u8], [];
+        try_from: [u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I15 {
+        into: [i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8], [U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, i8], [];
+        try_from: [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U17 {
+        into: [u32, u64, u128, i32, i64, i128], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16];
+        from: [u8, u16], [U16];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I17 {
+        into: [i32, i64, i128], [I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U18 {
+        into: [u32, u64, u128, i32, i64, i128], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16];
+        from: [u8, u16], [U16];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I18 {
+        into: [i32, i64, i128], [I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U19 {
+        into: [u32, u64, u128, i32, i64, i128], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16];
+        from: [u8, u16], [U16];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I19 {
+        into: [i32, i64, i128], [I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U20 {
+        into: [u32, u64, u128, i32, i64, i128], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16];
+        from: [u8, u16], [U16];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I20 {
+        into: [i32, i64, i128], [I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U21 {
+        into: [u32, u64, u128, i32, i64, i128], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16];
+        from: [u8, u16], [U16];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I21 {
+        into: [i32, i64, i128], [I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U22 {
+        into: [u32, u64, u128, i32, i64, i128], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16];
+        from: [u8, u16], [U16];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I22 {
+        into: [i32, i64, i128], [I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U23 {
+        into: [u32, u64, u128, i32, i64, i128], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16];
+        from: [u8, u16], [U16];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I23 {
+        into: [i32, i64, i128], [I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U25 {
+        into: [u32, u64, u128, i32, i64, i128], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16, U24, I24];
+        from: [u8, u16], [U16, U24];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I25 {
+        into: [i32, i64, i128], [I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, I24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16, U24, I24];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U26 {
+        into: [u32, u64, u128, i32, i64, i128], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16, U24, I24];
+        from: [u8, u16], [U16, U24];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I26 {
+        into: [i32, i64, i128], [I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, I24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16, U24, I24];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U27 {
+        into: [u32, u64, u128, i32, i64, i128], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16, U24, I24];
+        from: [u8, u16], [U16, U24];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I27 {
+        into: [i32, i64, i128], [I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, I24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16, U24, I24];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U28 {
+        into: [u32, u64, u128, i32, i64, i128], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16, U24, I24];
+        from: [u8, u16], [U16, U24];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I28 {
+        into: [i32, i64, i128], [I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, I24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16, U24, I24];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U29 {
+        into: [u32, u64, u128, i32, i64, i128], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16, U24, I24];
+        from: [u8, u16], [U16, U24];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I29 {
+        into: [i32, i64, i128], [I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, I24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16, U24, I24];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U30 {
+        into: [u32, u64, u128, i32, i64, i128], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16, U24, I24];
+        from: [u8, u16], [U16, U24];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I30 {
+        into: [i32, i64, i128], [I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, I24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16, U24, I24];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U31 {
+        into: [u32, u64, u128, i32, i64, i128], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, usize, i8, i16, isize], [U16, I16, U24, I24];
+        from: [u8, u16], [U16, U24];
+        try_from: [u32, u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I31 {
+        into: [i32, i64, i128], [I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, isize], [U16, I16, U24, I24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, i8, i16], [U16, I16, U24, I24];
+        try_from: [u32, u64, u128, usize, i32, i64, i128, isize], [U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U33 {
+        into: [u64, u128, i64, i128], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32];
+        from: [u8, u16, u32], [U16, U24, U32];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I33 {
+        into: [i64, i128], [I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32];
+        try_from: [u64, u128, usize, i64, i128, isize], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U34 {
+        into: [u64, u128, i64, i128], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32];
+        from: [u8, u16, u32], [U16, U24, U32];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I34 {
+        into: [i64, i128], [I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32];
+        try_from: [u64, u128, usize, i64, i128, isize], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U35 {
+        into: [u64, u128, i64, i128], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32];
+        from: [u8, u16, u32], [U16, U24, U32];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I35 {
+        into: [i64, i128], [I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32];
+        try_from: [u64, u128, usize, i64, i128, isize], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U36 {
+        into: [u64, u128, i64, i128], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32];
+        from: [u8, u16, u32], [U16, U24, U32];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I36 {
+        into: [i64, i128], [I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32];
+        try_from: [u64, u128, usize, i64, i128, isize], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U37 {
+        into: [u64, u128, i64, i128], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32];
+        from: [u8, u16, u32], [U16, U24, U32];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I37 {
+        into: [i64, i128], [I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32];
+        try_from: [u64, u128, usize, i64, i128, isize], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U38 {
+        into: [u64, u128, i64, i128], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32];
+        from: [u8, u16, u32], [U16, U24, U32];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I38 {
+        into: [i64, i128], [I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32];
+        try_from: [u64, u128, usize, i64, i128, isize], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U39 {
+        into: [u64, u128, i64, i128], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32];
+        from: [u8, u16, u32], [U16, U24, U32];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I39 {
+        into: [i64, i128], [I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32];
+        try_from: [u64, u128, usize, i64, i128, isize], [U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U41 {
+        into: [u64, u128, i64, i128], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40];
+        from: [u8, u16, u32], [U16, U24, U32, U40];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I41 {
+        into: [i64, i128], [I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40];
+        try_from: [u64, u128, usize, i64, i128, isize], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U42 {
+        into: [u64, u128, i64, i128], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40];
+        from: [u8, u16, u32], [U16, U24, U32, U40];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I42 {
+        into: [i64, i128], [I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40];
+        try_from: [u64, u128, usize, i64, i128, isize], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U43 {
+        into: [u64, u128, i64, i128], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40];
+        from: [u8, u16, u32], [U16, U24, U32, U40];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I43 {
+        into: [i64, i128], [I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40];
+        try_from: [u64, u128, usize, i64, i128, isize], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U44 {
+        into: [u64, u128, i64, i128], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40];
+        from: [u8, u16, u32], [U16, U24, U32, U40];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I44 {
+        into: [i64, i128], [I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40];
+        try_from: [u64, u128, usize, i64, i128, isize], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U45 {
+        into: [u64, u128, i64, i128], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40];
+        from: [u8, u16, u32], [U16, U24, U32, U40];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I45 {
+        into: [i64, i128], [I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40];
+        try_from: [u64, u128, usize, i64, i128, isize], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U46 {
+        into: [u64, u128, i64, i128], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40];
+        from: [u8, u16, u32], [U16, U24, U32, U40];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I46 {
+        into: [i64, i128], [I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40];
+        try_from: [u64, u128, usize, i64, i128, isize], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U47 {
+        into: [u64, u128, i64, i128], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40];
+        from: [u8, u16, u32], [U16, U24, U32, U40];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I47 {
+        into: [i64, i128], [I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40];
+        try_from: [u64, u128, usize, i64, i128, isize], [U48, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U49 {
+        into: [u64, u128, i64, i128], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I49 {
+        into: [i64, i128], [I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        try_from: [u64, u128, usize, i64, i128, isize], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U50 {
+        into: [u64, u128, i64, i128], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I50 {
+        into: [i64, i128], [I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        try_from: [u64, u128, usize, i64, i128, isize], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U51 {
+        into: [u64, u128, i64, i128], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I51 {
+        into: [i64, i128], [I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        try_from: [u64, u128, usize, i64, i128, isize], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U52 {
+        into: [u64, u128, i64, i128], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I52 {
+        into: [i64, i128], [I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        try_from: [u64, u128, usize, i64, i128, isize], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U53 {
+        into: [u64, u128, i64, i128], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I53 {
+        into: [i64, i128], [I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        try_from: [u64, u128, usize, i64, i128, isize], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U54 {
+        into: [u64, u128, i64, i128], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I54 {
+        into: [i64, i128], [I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        try_from: [u64, u128, usize, i64, i128, isize], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U55 {
+        into: [u64, u128, i64, i128], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I55 {
+        into: [i64, i128], [I56, I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48];
+        try_from: [u64, u128, usize, i64, i128, isize], [U56, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U57 {
+        into: [u64, u128, i64, i128], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48, U56];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I57 {
+        into: [i64, i128], [I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        try_from: [u64, u128, usize, i64, i128, isize], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U58 {
+        into: [u64, u128, i64, i128], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48, U56];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I58 {
+        into: [i64, i128], [I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        try_from: [u64, u128, usize, i64, i128, isize], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U59 {
+        into: [u64, u128, i64, i128], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48, U56];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I59 {
+        into: [i64, i128], [I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        try_from: [u64, u128, usize, i64, i128, isize], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U60 {
+        into: [u64, u128, i64, i128], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48, U56];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I60 {
+        into: [i64, i128], [I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        try_from: [u64, u128, usize, i64, i128, isize], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U61 {
+        into: [u64, u128, i64, i128], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48, U56];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I61 {
+        into: [i64, i128], [I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        try_from: [u64, u128, usize, i64, i128, isize], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U62 {
+        into: [u64, u128, i64, i128], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48, U56];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I62 {
+        into: [i64, i128], [I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        try_from: [u64, u128, usize, i64, i128, isize], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    U63 {
+        into: [u64, u128, i64, i128], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+        try_into: [u8, u16, u32, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        from: [u8, u16, u32], [U16, U24, U32, U40, U48, U56];
+        try_from: [u64, u128, usize, i8, i16, i32, i64, i128, isize], [I16, I24, I32, I40, I48, I56, U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+    I63 {
+        into: [i64, i128], [I64, I72, I80, I88, I96, I104, I112, I120, I128];
+        try_into: [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, U72, U80, U88, U96, U104, U112, U120, U128];
+        from: [u8, u16, u32, i8, i16, i32], [U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56];
+        try_from: [u64, u128, usize, i64, i128, isize], [U64, I64, U72, I72, U80, I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128];
+    }
+}
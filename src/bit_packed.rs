@@ -0,0 +1,215 @@
+//! Module to define the `BitPacked` container that stores bit-granular
+//! integers such as `U12` contiguously at the bit level.
+//!
+//! The values are stored in little-endian bit order. This means that the
+//! value at index `i` occupies the bits `i * T::BITS` up to `(i + 1) * T::BITS`
+//! where bit `n` refers to bit `n % 8` of byte `n / 8`.
+
+use crate::traits::sealed::Sealed;
+use core::marker::PhantomData;
+
+/// Trait implemented by all bit-granular integer types such as `U12`, `U20` or `I7`.
+///
+/// This allows them to be stored in a [`BitPacked`] container.
+/// The trait is sealed and cannot be implemented outside of this crate.
+pub trait BitPackable: Sealed + Copy {
+    /// The amount of bits required by this integer type.
+    const BITS: u32;
+
+    /// Returns the two's-complement bits of `self` in the least significant `Self::BITS` bits.
+    fn to_bits(self) -> u64;
+
+    /// Creates an integer from the least significant `Self::BITS` bits of `bits`.
+    ///
+    /// The remaining most significant bits of `bits` are ignored.
+    fn from_bits(bits: u64) -> Self;
+}
+
+/// Container of `BYTES` bytes storing values of type `T` contiguously at the bit level.
+///
+/// It can hold up to [`BitPacked::LEN`] values which is `BYTES * 8 / T::BITS`.
+/// Every bit pattern is valid and decodes to a value for each index.
+///
+/// # Example
+///
+/// ```
+/// use intx::{BitPacked, U12, U24};
+///
+/// let mut registers = BitPacked::<U12, 6>::new();
+/// assert_eq!(BitPacked::<U12, 6>::LEN, 4);
+/// registers.set(1, U12::new_const(0xABC));
+/// assert_eq!(registers.get(1), Some(U12::new_const(0xABC)));
+/// assert_eq!(registers.get(4), None);
+/// assert_eq!(registers.to_bytes(), [0x00, 0xC0, 0xAB, 0x00, 0x00, 0x00]);
+/// assert_eq!(U24::from(registers.get(1).unwrap()), U24::new_const(0xABC));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct BitPacked<T, const BYTES: usize> {
+    bytes: [u8; BYTES],
+    marker: PhantomData<T>,
+}
+
+impl<T, const BYTES: usize> BitPacked<T, BYTES>
+where
+    T: BitPackable,
+{
+    /// The amount of values of type `T` that fit into `BYTES` bytes.
+    pub const LEN: usize = BYTES * 8 / T::BITS as usize;
+
+    /// Creates a new container with all values set to `0`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::from_bytes([0x00_u8; BYTES])
+    }
+
+    /// Creates a container from its underlying bytes.
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; BYTES]) -> Self {
+        Self {
+            bytes,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the underlying bytes of the container.
+    #[inline]
+    pub const fn to_bytes(self) -> [u8; BYTES] {
+        self.bytes
+    }
+
+    /// Returns a shared reference to the underlying bytes of the container.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; BYTES] {
+        &self.bytes
+    }
+
+    /// Creates a container from the `values` with all remaining values set to `0`.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is larger than [`Self::LEN`].
+    #[inline]
+    pub fn from_array<const N: usize>(values: [T; N]) -> Self {
+        assert!(
+            N <= Self::LEN,
+            "too many values for the bit-packed container"
+        );
+        let mut result = Self::new();
+        for (index, value) in values.into_iter().enumerate() {
+            result.set(index, value);
+        }
+        result
+    }
+
+    /// Returns the amount of values stored in the container which is always [`Self::LEN`].
+    #[inline]
+    pub const fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    /// Returns `true` if the container cannot store any values.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        Self::LEN == 0
+    }
+
+    /// Returns the value at `index` or `None` if `index` is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= Self::LEN {
+            return None;
+        }
+        let bits = read_bits(&self.bytes, index * T::BITS as usize, T::BITS);
+        Some(T::from_bits(bits))
+    }
+
+    /// Sets the value at `index` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    #[inline]
+    pub fn set(&mut self, index: usize, value: T) {
+        assert!(
+            index < Self::LEN,
+            "index out of bounds: the len is {} but the index is {}",
+            Self::LEN,
+            index,
+        );
+        write_bits(
+            &mut self.bytes,
+            index * T::BITS as usize,
+            T::BITS,
+            value.to_bits(),
+        );
+    }
+
+    /// Returns an iterator over all values of the container.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = T> + DoubleEndedIterator + '_ {
+        (0..Self::LEN).map(move |index| {
+            let bits = read_bits(&self.bytes, index * T::BITS as usize, T::BITS);
+            T::from_bits(bits)
+        })
+    }
+}
+
+impl<T, const BYTES: usize> Default for BitPacked<T, BYTES>
+where
+    T: BitPackable,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const BYTES: usize> core::fmt::Debug for BitPacked<T, BYTES>
+where
+    T: BitPackable + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Returns a mask for the least significant `len` bits.
+#[inline]
+const fn mask(len: u32) -> u64 {
+    u64::MAX >> (64 - len)
+}
+
+/// Reads `len` bits starting at bit `offset` of `bytes` in little-endian bit order.
+///
+/// # Note
+///
+/// The bit range must be within the bounds of `bytes` and `len` must be within `1..=64`.
+#[inline]
+fn read_bits(bytes: &[u8], offset: usize, len: u32) -> u64 {
+    let start = offset / 8;
+    let end = (offset + len as usize).div_ceil(8);
+    let mut bits = 0_u128;
+    for (i, byte) in bytes[start..end].iter().enumerate() {
+        bits |= u128::from(*byte) << (i * 8);
+    }
+    (bits >> (offset % 8)) as u64 & mask(len)
+}
+
+/// Writes the least significant `len` bits of `value` starting at bit `offset` of `bytes`.
+///
+/// # Note
+///
+/// The bit range must be within the bounds of `bytes` and `len` must be within `1..=64`.
+#[inline]
+fn write_bits(bytes: &mut [u8], offset: usize, len: u32, value: u64) {
+    let start = offset / 8;
+    let end = (offset + len as usize).div_ceil(8);
+    let shift = offset % 8;
+    let bits = u128::from(value & mask(len)) << shift;
+    let keep = !(u128::from(mask(len)) << shift);
+    for (i, byte) in bytes[start..end].iter_mut().enumerate() {
+        let keep = (keep >> (i * 8)) as u8;
+        *byte = (*byte & keep) | (bits >> (i * 8)) as u8;
+    }
+}
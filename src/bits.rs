@@ -0,0 +1,176 @@
+//! Module to implement the bit inspection and manipulation methods
+//! for all unaligned integer types provided by this crate.
+//!
+//! All methods respect the bitwidth of the unaligned integer. For example
+//! `U40::leading_zeros` counts at most 40 zero bits and never includes
+//! the padding bits of the `u64` `Repr` primitive.
+
+/// Implements the bit inspection and manipulation methods shared by signed and unsigned integers.
+macro_rules! impl_bit_methods {
+    ( $name:ident, $repr:ty ) => {
+        impl $name {
+            /// The number of padding bits of the `Repr` primitive compared to `Self`.
+            const REPR_PADDING: ::core::primitive::u32 = <$repr>::BITS - Self::BITS;
+
+            /// Returns the number of ones in the binary representation of `self`.
+            #[inline]
+            pub const fn count_ones(self) -> ::core::primitive::u32 {
+                let mut count = 0;
+                let mut i = 0;
+                while i < ::core::mem::size_of::<Self>() {
+                    count += self.0[i].count_ones();
+                    i += 1;
+                }
+                count
+            }
+
+            /// Returns the number of zeros in the binary representation of `self`.
+            #[inline]
+            pub const fn count_zeros(self) -> ::core::primitive::u32 {
+                Self::BITS - self.count_ones()
+            }
+
+            /// Returns the number of leading zeros in the binary representation of `self`.
+            #[inline]
+            pub const fn leading_zeros(self) -> ::core::primitive::u32 {
+                // Note: shifting out the padding bits also gets rid of the sign extension.
+                let aligned = <$repr>::wrapping_shl(self.to_repr(), Self::REPR_PADDING);
+                $crate::bits::min(aligned.leading_zeros(), Self::BITS)
+            }
+
+            /// Returns the number of trailing zeros in the binary representation of `self`.
+            #[inline]
+            pub const fn trailing_zeros(self) -> ::core::primitive::u32 {
+                $crate::bits::min(self.to_repr().trailing_zeros(), Self::BITS)
+            }
+
+            /// Returns the number of leading ones in the binary representation of `self`.
+            #[inline]
+            pub const fn leading_ones(self) -> ::core::primitive::u32 {
+                Self::from_repr_truncating(!self.to_repr()).leading_zeros()
+            }
+
+            /// Returns the number of trailing ones in the binary representation of `self`.
+            #[inline]
+            pub const fn trailing_ones(self) -> ::core::primitive::u32 {
+                Self::from_repr_truncating(!self.to_repr()).trailing_zeros()
+            }
+
+            /// Shifts the bits to the left by a specified amount, `n`,
+            /// wrapping the truncated bits to the end of the resulting integer.
+            #[inline]
+            pub const fn rotate_left(self, n: ::core::primitive::u32) -> Self {
+                match n % Self::BITS {
+                    0 => self,
+                    n => Self::from_repr_truncating(
+                        self.wrapping_shl(n).to_repr() | self.logical_shr(Self::BITS - n).to_repr(),
+                    ),
+                }
+            }
+
+            /// Shifts the bits to the right by a specified amount, `n`,
+            /// wrapping the truncated bits to the beginning of the resulting integer.
+            #[inline]
+            pub const fn rotate_right(self, n: ::core::primitive::u32) -> Self {
+                match n % Self::BITS {
+                    0 => self,
+                    n => Self::from_repr_truncating(
+                        self.logical_shr(n).to_repr() | self.wrapping_shl(Self::BITS - n).to_repr(),
+                    ),
+                }
+            }
+
+            /// Shifts `self` right by `n` bits filling up with zeros regardless of signedness.
+            ///
+            /// # Note
+            ///
+            /// The shift amount `n` must be within `1..Self::BITS`.
+            #[inline]
+            const fn logical_shr(self, n: ::core::primitive::u32) -> Self {
+                debug_assert!(0 < n && n < Self::BITS);
+                let ones = Self([0xFF_u8; ::core::mem::size_of::<Self>()]);
+                let mask = !ones.wrapping_shl(Self::BITS - n).to_repr();
+                Self::from_repr_truncating(self.wrapping_shr(n).to_repr() & mask)
+            }
+
+            /// Reverses the byte order of the integer.
+            #[inline]
+            pub const fn swap_bytes(self) -> Self {
+                Self($crate::utils::reverse_bytes(self.0))
+            }
+
+            /// Reverses the order of bits in the integer.
+            ///
+            /// The least significant bit becomes the most significant bit,
+            /// second least-significant bit becomes second most-significant bit, etc.
+            #[inline]
+            pub const fn reverse_bits(self) -> Self {
+                let mut bytes = $crate::utils::reverse_bytes(self.0);
+                let mut i = 0;
+                while i < ::core::mem::size_of::<Self>() {
+                    bytes[i] = bytes[i].reverse_bits();
+                    i += 1;
+                }
+                Self(bytes)
+            }
+        }
+    };
+}
+pub(crate) use impl_bit_methods;
+
+/// Implements the bit inspection and manipulation methods specific to unsigned integers.
+macro_rules! impl_unsigned_bit_methods {
+    ( $name:ident ) => {
+        impl $name {
+            /// Returns `true` if and only if `self == 2^k` for some `k`.
+            #[inline]
+            pub const fn is_power_of_two(self) -> ::core::primitive::bool {
+                self.count_ones() == 1
+            }
+
+            /// Returns the smallest power of two greater than or equal to `self`.
+            ///
+            /// If the next power of two is greater than the type's maximum value,
+            /// `None` is returned, otherwise the power of two is wrapped in `Some`.
+            #[inline]
+            pub const fn checked_next_power_of_two(self) -> ::core::option::Option<Self> {
+                let one = Self::from_repr_truncating(1);
+                if !::core::matches!(self.const_cmp(one), ::core::cmp::Ordering::Greater) {
+                    return ::core::option::Option::Some(one);
+                }
+                let exp = Self::BITS - self.wrapping_sub(one).leading_zeros();
+                one.checked_shl(exp)
+            }
+
+            /// Returns the smallest power of two greater than or equal to `self`.
+            ///
+            /// When return value overflows, it panics in debug mode and the
+            /// return value is wrapped to 0 in release mode.
+            #[inline]
+            pub const fn next_power_of_two(self) -> Self {
+                match self.checked_next_power_of_two() {
+                    ::core::option::Option::Some(result) => result,
+                    ::core::option::Option::None => {
+                        if ::core::cfg!(debug_assertions) {
+                            ::core::panic!("attempt to add with overflow")
+                        }
+                        Self::ZERO
+                    }
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_unsigned_bit_methods;
+
+/// Returns the minimum of `a` and `b`.
+///
+/// This is the `const` equivalent of `Ord::min` for `u32`.
+#[inline]
+pub(crate) const fn min(a: u32, b: u32) -> u32 {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
@@ -0,0 +1,237 @@
+//! Module to define the `ByteReader` and `ByteWriter` cursors over byte slices.
+//!
+//! They read and write integers in the byte order `B` and are available without the `std` crate feature.
+//! All accesses are bounds-checked and report the failing offset via [`OutOfBoundsError`].
+//! A failed access leaves the cursor unchanged.
+
+use crate::{ByteOrder, IntegerBytes, OutOfBoundsError};
+use core::marker::PhantomData;
+
+/// Cursor that reads integers in the byte order `B` from a byte slice.
+///
+/// # Example
+///
+/// ```
+/// use intx::{BigEndian, ByteReader, U24, U48};
+///
+/// let packet = [0x01, 0x00, 0x00, 0x2A, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00];
+/// let mut reader = ByteReader::<BigEndian>::new(&packet);
+/// assert_eq!(reader.read::<u8>(), Ok(1));
+/// assert_eq!(reader.peek::<U24>(), Ok(U24::from(42_u8)));
+/// reader.skip(3).unwrap();
+/// assert_eq!(reader.read::<U48>(), Ok(U48::from(0x0100_u16)));
+/// let error = reader.read::<u8>().unwrap_err();
+/// assert_eq!((error.offset(), error.required(), error.remaining()), (10, 1, 0));
+/// assert!(reader.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ByteReader<'a, B> {
+    bytes: &'a [u8],
+    offset: usize,
+    marker: PhantomData<fn() -> B>,
+}
+
+impl<'a, B> ByteReader<'a, B>
+where
+    B: ByteOrder,
+{
+    /// Creates a new reader at the start of `bytes`.
+    #[inline]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            offset: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the offset in bytes of the next read.
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the amount of bytes that remain to be read.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    /// Returns `true` if all bytes have been read.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Returns the bytes that remain to be read.
+    #[inline]
+    pub fn remaining_bytes(&self) -> &'a [u8] {
+        &self.bytes[self.offset..]
+    }
+
+    /// Returns the next `len` bytes without advancing the reader.
+    fn get(&self, len: usize) -> Result<&'a [u8], OutOfBoundsError> {
+        self.remaining_bytes()
+            .get(..len)
+            .ok_or(OutOfBoundsError::new(self.offset, len, self.remaining()))
+    }
+
+    /// Reads the next integer of type `T` without advancing the reader.
+    ///
+    /// # Errors
+    ///
+    /// If less than `size_of::<T>()` bytes remain.
+    #[inline]
+    pub fn peek<T>(&self) -> Result<T, OutOfBoundsError>
+    where
+        T: IntegerBytes,
+    {
+        let mut bytes = T::Bytes::default();
+        let src = self.get(bytes.as_ref().len())?;
+        bytes.as_mut().copy_from_slice(src);
+        Ok(B::from_bytes(bytes))
+    }
+
+    /// Reads the next integer of type `T` and advances the reader past it.
+    ///
+    /// # Errors
+    ///
+    /// If less than `size_of::<T>()` bytes remain.
+    #[inline]
+    pub fn read<T>(&mut self) -> Result<T, OutOfBoundsError>
+    where
+        T: IntegerBytes,
+    {
+        let value = self.peek::<T>()?;
+        self.offset += core::mem::size_of::<T::Bytes>();
+        Ok(value)
+    }
+
+    /// Reads the next `len` bytes and advances the reader past them.
+    ///
+    /// # Errors
+    ///
+    /// If less than `len` bytes remain.
+    #[inline]
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], OutOfBoundsError> {
+        let bytes = self.get(len)?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    /// Advances the reader by `len` bytes.
+    ///
+    /// # Errors
+    ///
+    /// If less than `len` bytes remain.
+    #[inline]
+    pub fn skip(&mut self, len: usize) -> Result<(), OutOfBoundsError> {
+        self.read_bytes(len).map(|_| ())
+    }
+}
+
+/// Cursor that writes integers in the byte order `B` into a mutable byte slice.
+///
+/// # Example
+///
+/// ```
+/// use intx::{ByteWriter, LittleEndian, I24, U48};
+///
+/// let mut buffer = [0x00_u8; 8];
+/// let mut writer = ByteWriter::<LittleEndian>::new(&mut buffer);
+/// writer.write(I24::from(-2_i8)).unwrap();
+/// writer.write(0xABCD_u16).unwrap();
+/// assert_eq!(writer.written(), [0xFE, 0xFF, 0xFF, 0xCD, 0xAB]);
+/// let error = writer.write(U48::MAX).unwrap_err();
+/// assert_eq!((error.offset(), error.required(), error.remaining()), (5, 6, 3));
+/// ```
+#[derive(Debug)]
+pub struct ByteWriter<'a, B> {
+    bytes: &'a mut [u8],
+    offset: usize,
+    marker: PhantomData<fn() -> B>,
+}
+
+impl<'a, B> ByteWriter<'a, B>
+where
+    B: ByteOrder,
+{
+    /// Creates a new writer at the start of `bytes`.
+    #[inline]
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self {
+            bytes,
+            offset: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the offset in bytes of the next write.
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the amount of bytes that remain to be written.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    /// Returns `true` if no bytes remain to be written.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Returns the bytes that have been written or skipped so far.
+    #[inline]
+    pub fn written(&self) -> &[u8] {
+        &self.bytes[..self.offset]
+    }
+
+    /// Returns the next `len` bytes for writing without advancing the writer.
+    fn get_mut(&mut self, len: usize) -> Result<&mut [u8], OutOfBoundsError> {
+        let (offset, remaining) = (self.offset, self.remaining());
+        self.bytes[offset..]
+            .get_mut(..len)
+            .ok_or(OutOfBoundsError::new(offset, len, remaining))
+    }
+
+    /// Writes the integer `value` and advances the writer past it.
+    ///
+    /// # Errors
+    ///
+    /// If less than `size_of::<T>()` bytes remain.
+    #[inline]
+    pub fn write<T>(&mut self, value: T) -> Result<(), OutOfBoundsError>
+    where
+        T: IntegerBytes,
+    {
+        self.write_bytes(B::to_bytes(value).as_ref())
+    }
+
+    /// Writes the `bytes` and advances the writer past them.
+    ///
+    /// # Errors
+    ///
+    /// If less than `bytes.len()` bytes remain.
+    #[inline]
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), OutOfBoundsError> {
+        self.get_mut(bytes.len())?.copy_from_slice(bytes);
+        self.offset += bytes.len();
+        Ok(())
+    }
+
+    /// Advances the writer by `len` bytes leaving them unchanged.
+    ///
+    /// # Errors
+    ///
+    /// If less than `len` bytes remain.
+    #[inline]
+    pub fn skip(&mut self, len: usize) -> Result<(), OutOfBoundsError> {
+        self.get_mut(len)?;
+        self.offset += len;
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+//! Module to define the byte order marker types used to select the endianness
+//! of reading and writing integers from and to bytes.
+
+use crate::traits::sealed::Sealed;
+use crate::UnalignedInteger;
+
+/// Trait implemented by all integer types that convert from and to bytes.
+///
+/// This includes all unaligned integer types implementing [`UnalignedInteger`]
+/// as well as the Rust integer primitives.
+/// The trait is sealed and cannot be implemented outside of this crate.
+pub trait IntegerBytes: Sealed + Copy {
+    /// The byte array type with the size of `Self`.
+    type Bytes: Copy + Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// Returns the memory representation of this integer as a byte array in little-endian byte order.
+    fn to_le_bytes(self) -> Self::Bytes;
+
+    /// Returns the memory representation of this integer as a byte array in big-endian byte order.
+    fn to_be_bytes(self) -> Self::Bytes;
+
+    /// Creates an integer value from its representation as a byte array in little endian.
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Creates an integer value from its representation as a byte array in big endian.
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+}
+
+impl<T> IntegerBytes for T
+where
+    T: UnalignedInteger,
+{
+    type Bytes = <T as UnalignedInteger>::Bytes;
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        <T as UnalignedInteger>::to_le_bytes(self)
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        <T as UnalignedInteger>::to_be_bytes(self)
+    }
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        <T as UnalignedInteger>::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        <T as UnalignedInteger>::from_be_bytes(bytes)
+    }
+}
+
+macro_rules! impl_integer_bytes_for {
+    ( $( $prim:ty ),* ) => {
+        $(
+            impl IntegerBytes for $prim {
+                type Bytes = [u8; ::core::mem::size_of::<$prim>()];
+
+                #[inline]
+                fn to_le_bytes(self) -> Self::Bytes {
+                    <$prim>::to_le_bytes(self)
+                }
+
+                #[inline]
+                fn to_be_bytes(self) -> Self::Bytes {
+                    <$prim>::to_be_bytes(self)
+                }
+
+                #[inline]
+                fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                    <$prim>::from_le_bytes(bytes)
+                }
+
+                #[inline]
+                fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                    <$prim>::from_be_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+impl_integer_bytes_for!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+/// Trait implemented by the byte order marker types [`BigEndian`] and [`LittleEndian`].
+///
+/// The trait is sealed and cannot be implemented outside of this crate.
+pub trait ByteOrder: Sealed {
+    /// Creates an integer from its representation as bytes in this byte order.
+    fn from_bytes<T>(bytes: T::Bytes) -> T
+    where
+        T: IntegerBytes;
+
+    /// Returns the representation of `value` as bytes in this byte order.
+    fn to_bytes<T>(value: T) -> T::Bytes
+    where
+        T: IntegerBytes;
+}
+
+/// Marker type for the big-endian byte order.
+///
+/// This type cannot be instantiated and is only used as type parameter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BigEndian {}
+
+/// Marker type for the little-endian byte order.
+///
+/// This type cannot be instantiated and is only used as type parameter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LittleEndian {}
+
+/// The byte order used by network protocols which is big-endian.
+pub type NetworkEndian = BigEndian;
+
+/// The native byte order of the target platform.
+#[cfg(target_endian = "little")]
+pub type NativeEndian = LittleEndian;
+
+/// The native byte order of the target platform.
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
+
+impl Sealed for BigEndian {}
+impl Sealed for LittleEndian {}
+
+impl ByteOrder for BigEndian {
+    #[inline]
+    fn from_bytes<T>(bytes: T::Bytes) -> T
+    where
+        T: IntegerBytes,
+    {
+        T::from_be_bytes(bytes)
+    }
+
+    #[inline]
+    fn to_bytes<T>(value: T) -> T::Bytes
+    where
+        T: IntegerBytes,
+    {
+        value.to_be_bytes()
+    }
+}
+
+impl ByteOrder for LittleEndian {
+    #[inline]
+    fn from_bytes<T>(bytes: T::Bytes) -> T
+    where
+        T: IntegerBytes,
+    {
+        T::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn to_bytes<T>(value: T) -> T::Bytes
+    where
+        T: IntegerBytes,
+    {
+        value.to_le_bytes()
+    }
+}
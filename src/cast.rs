@@ -0,0 +1,172 @@
+//! Module to implement the `as`-equivalent casts between all unaligned integer
+//! types provided by this crate and the Rust integer primitives.
+//!
+//! - [`CastFrom::wrapping_from`] has the exact bit-level behavior of an `as` cast:
+//!   it truncates the most significant bits when casting to a narrower type and
+//!   sign- or zero-extends depending on the source type when casting to a wider type.
+//! - [`CastFrom::saturating_from`] clamps values that are out of bounds to `MIN` or `MAX`.
+//! - `cast_signed` and `cast_unsigned` reinterpret the bits of an integer as the
+//!   integer type of the same bitwidth with the opposite signedness.
+
+use crate::traits::sealed::Sealed;
+use crate::{
+    I104, I112, I120, I128, I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, U104, U112,
+    U120, U128, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96,
+};
+
+/// Trait for casting between integer types with the semantics of `as`.
+///
+/// It is implemented for every pair of the unaligned integer types provided by this
+/// crate and the Rust integer primitives. The trait is sealed and cannot be implemented
+/// outside of this crate.
+///
+/// # Example
+///
+/// ```
+/// use intx::{CastFrom, I24, U16, U24};
+///
+/// assert_eq!(U16::wrapping_from(U24::new_const(0x12_3456)), U16::new_const(0x3456));
+/// assert_eq!(U24::wrapping_from(-1_i8), U24::MAX);
+/// assert_eq!(U16::saturating_from(U24::new_const(0x12_3456)), U16::MAX);
+/// assert_eq!(I24::saturating_from(u64::MAX), I24::MAX);
+/// ```
+pub trait CastFrom<T>: Sealed {
+    /// Casts `value` to `Self` with the exact semantics of `value as Self`.
+    ///
+    /// The most significant bits are truncated if `Self` is narrower than `T`.
+    /// Otherwise `value` is sign-extended if `T` is signed and zero-extended if it is unsigned.
+    fn wrapping_from(value: T) -> Self;
+
+    /// Casts `value` to `Self` saturating at `Self::MIN` or `Self::MAX` if it is out of bounds.
+    fn saturating_from(value: T) -> Self;
+}
+
+/// Extension trait to call the casts of [`CastFrom`] in method position.
+///
+/// It is implemented for all types.
+///
+/// # Example
+///
+/// ```
+/// use intx::{Cast, I24, U16, U24};
+///
+/// let value = U24::new_const(0x12_3456);
+/// assert_eq!(value.truncate::<U16>(), U16::new_const(0x3456));
+/// assert_eq!(value.truncate::<u8>(), 0x56);
+/// assert_eq!(value.saturate::<U16>(), U16::MAX);
+/// assert_eq!(I24::MIN.saturate::<u32>(), 0);
+/// ```
+pub trait Cast: Sized {
+    /// Casts `self` to `T` with the exact semantics of `self as T`.
+    ///
+    /// See [`CastFrom::wrapping_from`] for details.
+    #[inline]
+    fn truncate<T>(self) -> T
+    where
+        T: CastFrom<Self>,
+    {
+        T::wrapping_from(self)
+    }
+
+    /// Casts `self` to `T` saturating at `T::MIN` or `T::MAX` if it is out of bounds.
+    ///
+    /// See [`CastFrom::saturating_from`] for details.
+    #[inline]
+    fn saturate<T>(self) -> T
+    where
+        T: CastFrom<Self>,
+    {
+        T::saturating_from(self)
+    }
+}
+
+impl<T> Cast for T {}
+
+macro_rules! impl_cast_from {
+    ( $( $to:ty ),* $(,)? ) => {
+        $(
+            impl_cast_from!(
+                @impl $to:
+                u8 => u128, i8 => i128, u16 => u128, i16 => i128, u32 => u128, i32 => i128,
+                u64 => u128, i64 => i128, u128 => u128, i128 => i128, U16 => u128, I16 => i128,
+                U24 => u128, I24 => i128, U32 => u128, I32 => i128, U40 => u128, I40 => i128,
+                U48 => u128, I48 => i128, U56 => u128, I56 => i128, U64 => u128, I64 => i128,
+                U72 => u128, I72 => i128, U80 => u128, I80 => i128, U88 => u128, I88 => i128,
+                U96 => u128, I96 => i128, U104 => u128, I104 => i128, U112 => u128, I112 => i128,
+                U120 => u128, I120 => i128, U128 => u128, I128 => i128,
+            );
+        )*
+    };
+    ( @impl $to:ty: $( $from:ty => $wide:ty ),* $(,)? ) => {
+        $(
+            impl $crate::CastFrom<$from> for $to {
+                #[inline]
+                fn wrapping_from(value: $from) -> Self {
+                    let ext = <$from as $crate::Integer>::sign_ext_byte(value);
+                    <$to>::from_ne_bytes($crate::utils::resize_bytes(value.to_ne_bytes(), ext))
+                }
+
+                #[inline]
+                fn saturating_from(value: $from) -> Self {
+                    // Note: all integer types convert losslessly into either `i128` or `u128`
+                    //       from which all integer types implement a fallible conversion.
+                    let wide = <$wide as ::core::convert::From<$from>>::from(value);
+                    match <$to as ::core::convert::TryFrom<$wide>>::try_from(wide) {
+                        ::core::result::Result::Ok(result) => result,
+                        ::core::result::Result::Err(_) if <$from as $crate::Integer>::sign_ext_byte(value) != 0x00 => <$to>::MIN,
+                        ::core::result::Result::Err(_) => <$to>::MAX,
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_cast_from! {
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128,
+    U16, I16, U24, I24, U32, I32, U40, I40, U48, I48,
+    U56, I56, U64, I64, U72, I72, U80, I80, U88, I88,
+    U96, I96, U104, I104, U112, I112, U120, I120, U128, I128,
+}
+
+macro_rules! impl_cast_sign {
+    ( $( $unsigned:ident <=> $signed:ident );* $(;)? ) => {
+        $(
+            impl $unsigned {
+                /// Returns the bit pattern of `self` reinterpreted as signed integer of the same size.
+                ///
+                /// This has the same semantics as an `as` cast between integer primitives of the same size.
+                #[inline]
+                pub const fn cast_signed(self) -> $signed {
+                    <$signed>::from_ne_bytes(self.to_ne_bytes())
+                }
+            }
+
+            impl $signed {
+                /// Returns the bit pattern of `self` reinterpreted as unsigned integer of the same size.
+                ///
+                /// This has the same semantics as an `as` cast between integer primitives of the same size.
+                #[inline]
+                pub const fn cast_unsigned(self) -> $unsigned {
+                    <$unsigned>::from_ne_bytes(self.to_ne_bytes())
+                }
+            }
+        )*
+    };
+}
+impl_cast_sign! {
+    U16 <=> I16;
+    U24 <=> I24;
+    U32 <=> I32;
+    U40 <=> I40;
+    U48 <=> I48;
+    U56 <=> I56;
+    U64 <=> I64;
+    U72 <=> I72;
+    U80 <=> I80;
+    U88 <=> I88;
+    U96 <=> I96;
+    U104 <=> I104;
+    U112 <=> I112;
+    U120 <=> I120;
+    U128 <=> I128;
+}
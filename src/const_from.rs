@@ -0,0 +1,310 @@
+//! Module to implement `const` equivalents of all infallible `From` implementations
+//! from Rust primitive integers to the unaligned integer types provided by this crate.
+//!
+//! The fallible `TryFrom` implementations have `const` equivalents in the form of
+//! the `checked_from_*` methods that are available for all unaligned integer types.
+
+use crate::{
+    I104, I112, I120, I128, I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, U104, U112,
+    U120, U128, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96,
+};
+
+macro_rules! impl_from_const_for {
+    ( $( impl $to:ident { $( fn $method:ident($from:ident) as $signedness:ident );* $(;)? } )* ) => {
+        $(
+            impl $to {
+                $(
+                    impl_from_const_for!(@impl fn $method($from) as $signedness);
+                )*
+            }
+        )*
+    };
+    ( @impl fn $method:ident($from:ident) as $signedness:ident ) => {
+        #[doc = ::core::concat!("Creates an integer from the `", ::core::stringify!($from), "` value.")]
+        ///
+        #[doc = ::core::concat!("This is the `const` equivalent of `From<", ::core::stringify!($from), ">`.")]
+        #[inline]
+        pub const fn $method(value: ::core::primitive::$from) -> Self {
+            let ext = impl_from_const_for!(@sign_ext_byte value as $signedness);
+            Self::from_ne_bytes($crate::utils::resize_bytes(value.to_ne_bytes(), ext))
+        }
+    };
+    ( @sign_ext_byte $value:ident as unsigned ) => {
+        0x00_u8
+    };
+    ( @sign_ext_byte $value:ident as signed ) => {
+        $crate::utils::sign_ext_byte($value >= 0)
+    };
+}
+impl_from_const_for! {
+    impl U16 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+    }
+
+    impl I16 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+    }
+
+    impl U24 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+    }
+
+    impl I24 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+    }
+
+    impl U32 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+    }
+
+    impl I32 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+    }
+
+    impl U40 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+    }
+
+    impl I40 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+    }
+
+    impl U48 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+    }
+
+    impl I48 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+    }
+
+    impl U56 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+    }
+
+    impl I56 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+    }
+
+    impl U64 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+    }
+
+    impl I64 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+        fn from_i64_const(i64) as signed;
+        fn from_isize_const(isize) as signed;
+    }
+
+    impl U72 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+    }
+
+    impl I72 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+        fn from_i64_const(i64) as signed;
+        fn from_isize_const(isize) as signed;
+    }
+
+    impl U80 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+    }
+
+    impl I80 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+        fn from_i64_const(i64) as signed;
+        fn from_isize_const(isize) as signed;
+    }
+
+    impl U88 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+    }
+
+    impl I88 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+        fn from_i64_const(i64) as signed;
+        fn from_isize_const(isize) as signed;
+    }
+
+    impl U96 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+    }
+
+    impl I96 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+        fn from_i64_const(i64) as signed;
+        fn from_isize_const(isize) as signed;
+    }
+
+    impl U104 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+    }
+
+    impl I104 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+        fn from_i64_const(i64) as signed;
+        fn from_isize_const(isize) as signed;
+    }
+
+    impl U112 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+    }
+
+    impl I112 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+        fn from_i64_const(i64) as signed;
+        fn from_isize_const(isize) as signed;
+    }
+
+    impl U120 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+    }
+
+    impl I120 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+        fn from_i64_const(i64) as signed;
+        fn from_isize_const(isize) as signed;
+    }
+
+    impl U128 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_u128_const(u128) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+    }
+
+    impl I128 {
+        fn from_u8_const(u8) as unsigned;
+        fn from_u16_const(u16) as unsigned;
+        fn from_u32_const(u32) as unsigned;
+        fn from_u64_const(u64) as unsigned;
+        fn from_usize_const(usize) as unsigned;
+        fn from_i8_const(i8) as signed;
+        fn from_i16_const(i16) as signed;
+        fn from_i32_const(i32) as signed;
+        fn from_i64_const(i64) as signed;
+        fn from_i128_const(i128) as signed;
+        fn from_isize_const(isize) as signed;
+    }
+}
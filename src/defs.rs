@@ -14,6 +14,17 @@ macro_rules! unaligned_int {
                 ::core::cmp::PartialEq,
                 ::core::cmp::Eq,
             )]
+            #[cfg_attr(
+                feature = "zerocopy",
+                derive(
+                    ::zerocopy::FromBytes,
+                    ::zerocopy::IntoBytes,
+                    ::zerocopy::Unaligned,
+                    ::zerocopy::Immutable,
+                    ::zerocopy::KnownLayout,
+                )
+            )]
+            #[repr(transparent)]
             $vis struct $name([::core::primitive::u8; $num_bytes]);
 
             unaligned_int!(
@@ -37,8 +48,115 @@ macro_rules! unaligned_int {
                         $num_bytes - 1_usize
                     }
                 }
+
+                /// Returns the integer value converted to its `Repr` primitive type.
+                ///
+                /// # Example
+                ///
+                /// For `U24` the `Repr` primitive type is `u32`.
+                #[inline]
+                pub const fn to_repr(self) -> $repr {
+                    <$repr>::from_ne_bytes($crate::utils::resize_bytes(self.0, self.sign_ext_byte()))
+                }
+
+                /// Creates an integer from the `Repr` primitive `value`.
+                ///
+                /// This is meant to be used in `const` contexts where out of bounds
+                /// values result in a compilation error instead of a runtime panic.
+                ///
+                /// # Panics
+                ///
+                /// If `value` is out of bounds for `Self`.
+                #[inline]
+                pub const fn new_const(value: $repr) -> Self {
+                    let result = Self::from_repr_truncating(value);
+                    if result.to_repr() != value {
+                        ::core::panic!(::core::concat!(
+                            "value is out of bounds for ",
+                            ::core::stringify!($name),
+                        ))
+                    }
+                    result
+                }
+
+                /// Creates an integer from the `Repr` primitive truncating its most significant bits.
+                #[inline]
+                pub(crate) const fn from_repr_truncating(repr: $repr) -> Self {
+                    Self($crate::utils::resize_bytes(repr.to_ne_bytes(), 0x00_u8))
+                }
+
+                /// The integer value `0`.
+                const ZERO: Self = Self([0x00_u8; $num_bytes]);
+
+                /// Returns `true` if `self` and `other` are equal.
+                ///
+                /// This is the `const` equivalent of `PartialEq::eq`.
+                #[inline]
+                pub const fn const_eq(self, other: Self) -> ::core::primitive::bool {
+                    let mut i = 0;
+                    while i < $num_bytes {
+                        if self.0[i] != other.0[i] {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+
+                /// Returns the [`Ordering`](::core::cmp::Ordering) between `self` and `other`.
+                ///
+                /// This is the `const` equivalent of `Ord::cmp`.
+                #[inline]
+                pub const fn const_cmp(self, other: Self) -> ::core::cmp::Ordering {
+                    let is_signed = <Self as $crate::UnalignedInteger>::IS_SIGNED;
+                    let is_big_endian = ::core::cfg!(target_endian = "big");
+                    $crate::utils::cmp_bytes(&self.0, &other.0, is_signed, is_big_endian)
+                }
+
+                /// Creates an integer from the `u128` value if it is within bounds.
+                ///
+                /// This is the `const` equivalent of `TryFrom<u128>`.
+                #[inline]
+                pub const fn checked_from_u128(
+                    value: ::core::primitive::u128,
+                ) -> ::core::option::Option<Self> {
+                    if value > Self::MAX.to_repr() as ::core::primitive::u128 {
+                        return ::core::option::Option::None;
+                    }
+                    ::core::option::Option::Some(Self($crate::utils::resize_bytes(value.to_ne_bytes(), 0x00_u8)))
+                }
+
+                /// Creates an integer from the `i128` value if it is within bounds.
+                ///
+                /// This is the `const` equivalent of `TryFrom<i128>`.
+                #[inline]
+                pub const fn checked_from_i128(
+                    value: ::core::primitive::i128,
+                ) -> ::core::option::Option<Self> {
+                    if value >= 0 {
+                        return Self::checked_from_u128(value as ::core::primitive::u128);
+                    }
+                    if value < Self::MIN.to_repr() as ::core::primitive::i128 {
+                        return ::core::option::Option::None;
+                    }
+                    ::core::option::Option::Some(Self($crate::utils::resize_bytes(value.to_ne_bytes(), 0xFF_u8)))
+                }
             }
 
+            unaligned_int!(
+                @checked_from $name,
+                checked_from_u8(u8) as checked_from_u128(u128);
+                checked_from_u16(u16) as checked_from_u128(u128);
+                checked_from_u32(u32) as checked_from_u128(u128);
+                checked_from_u64(u64) as checked_from_u128(u128);
+                checked_from_usize(usize) as checked_from_u128(u128);
+                checked_from_i8(i8) as checked_from_i128(i128);
+                checked_from_i16(i16) as checked_from_i128(i128);
+                checked_from_i32(i32) as checked_from_i128(i128);
+                checked_from_i64(i64) as checked_from_i128(i128);
+                checked_from_isize(isize) as checked_from_i128(i128);
+            );
+
             impl $name {
                 /// Returns the integer value as a byte array in native-endian order.
                 #[inline]
@@ -50,13 +168,13 @@ macro_rules! unaligned_int {
 
                 /// Returns the integer value as a byte array in little-endian order.
                 #[inline]
-                pub fn to_le_bytes(self) -> [::core::primitive::u8; ::core::mem::size_of::<Self>()] {
+                pub const fn to_le_bytes(self) -> [::core::primitive::u8; ::core::mem::size_of::<Self>()] {
                     $crate::utils::ne_bytes_to_le(self.to_ne_bytes())
                 }
 
                 /// Returns the integer value as a byte array in big-endian order.
                 #[inline]
-                pub fn to_be_bytes(self) -> [::core::primitive::u8; ::core::mem::size_of::<Self>()] {
+                pub const fn to_be_bytes(self) -> [::core::primitive::u8; ::core::mem::size_of::<Self>()] {
                     $crate::utils::ne_bytes_to_be(self.to_ne_bytes())
                 }
 
@@ -70,7 +188,7 @@ macro_rules! unaligned_int {
 
                 /// Creates an unaligned signed integer from the given bytes in little-endian order.
                 #[inline]
-                pub fn from_le_bytes(
+                pub const fn from_le_bytes(
                     bytes: [::core::primitive::u8; ::core::mem::size_of::<Self>()],
                 ) -> Self {
                     Self::from_ne_bytes($crate::utils::le_bytes_to_ne(bytes))
@@ -78,79 +196,66 @@ macro_rules! unaligned_int {
 
                 /// Creates an unaligned signed integer from the given bytes in big-endian order.
                 #[inline]
-                pub fn from_be_bytes(
+                pub const fn from_be_bytes(
                     bytes: [::core::primitive::u8; ::core::mem::size_of::<Self>()],
                 ) -> Self {
                     Self::from_ne_bytes($crate::utils::be_bytes_to_ne(bytes))
                 }
             }
 
+            $crate::arith::impl_arith_methods!($name, $repr);
+            $crate::ops::impl_arith_ops!($name);
+            $crate::ops::impl_bit_ops!($name);
+            $crate::ops::impl_shift_ops!($name);
+            $crate::bits::impl_bit_methods!($name, $repr);
+            $crate::parse::impl_from_str!($name, $repr);
+            $crate::traits::impl_unaligned_integer!($name, $repr, $signedness);
+            #[cfg(feature = "serde")]
+            $crate::serde::impl_serde!($name, $signedness);
+            #[cfg(feature = "num-traits")]
+            $crate::num_traits::impl_num_traits!($name, $repr, $signedness);
+
+            // SAFETY: `$name` is a `#[repr(transparent)]` wrapper around a byte array
+            //         for which the all-zero bit pattern is a valid value.
+            #[cfg(feature = "bytemuck")]
+            unsafe impl ::bytemuck::Zeroable for $name {}
+
+            // SAFETY: `$name` is a `#[repr(transparent)]` wrapper around a byte array
+            //         which has no padding bytes and for which all bit patterns are valid.
+            #[cfg(feature = "bytemuck")]
+            unsafe impl ::bytemuck::Pod for $name {}
+
             impl ::core::default::Default for $name {
                 #[inline]
                 fn default() -> Self {
-                    Self([0x00_u8; ::core::mem::size_of::<Self>()])
+                    Self::ZERO
                 }
             }
 
             impl ::core::cmp::PartialOrd for $name {
                 #[inline]
                 fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
-                    <$repr as ::core::cmp::PartialOrd>::partial_cmp(
-                        &<$repr as ::core::convert::From<Self>>::from(*self),
-                        &<$repr as ::core::convert::From<Self>>::from(*other),
-                    )
-                }
-
-                #[inline]
-                fn lt(&self, other: &Self) -> ::core::primitive::bool {
-                    <$repr as ::core::cmp::PartialOrd>::lt(
-                        &<$repr as ::core::convert::From<Self>>::from(*self),
-                        &<$repr as ::core::convert::From<Self>>::from(*other),
-                    )
-                }
-
-                #[inline]
-                fn le(&self, other: &Self) -> ::core::primitive::bool {
-                    <$repr as ::core::cmp::PartialOrd>::le(
-                        &<$repr as ::core::convert::From<Self>>::from(*self),
-                        &<$repr as ::core::convert::From<Self>>::from(*other),
-                    )
-                }
-
-                #[inline]
-                fn gt(&self, other: &Self) -> ::core::primitive::bool {
-                    <$repr as ::core::cmp::PartialOrd>::gt(
-                        &<$repr as ::core::convert::From<Self>>::from(*self),
-                        &<$repr as ::core::convert::From<Self>>::from(*other),
-                    )
-                }
-
-                #[inline]
-                fn ge(&self, other: &Self) -> ::core::primitive::bool {
-                    <$repr as ::core::cmp::PartialOrd>::ge(
-                        &<$repr as ::core::convert::From<Self>>::from(*self),
-                        &<$repr as ::core::convert::From<Self>>::from(*other),
-                    )
+                    ::core::option::Option::Some(<Self as ::core::cmp::Ord>::cmp(self, other))
                 }
             }
 
             impl ::core::cmp::Ord for $name {
                 #[inline]
                 fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
-                    <$repr as ::core::cmp::Ord>::cmp(
-                        &<$repr as ::core::convert::From<Self>>::from(*self),
-                        &<$repr as ::core::convert::From<Self>>::from(*other),
-                    )
+                    // Note: only signed integer types that do not fill their `Repr` primitive such as `I24`
+                    //       compare faster byte-wise than sign-extended to `Repr`, see `benches/ord.rs`.
+                    let is_signed = <Self as $crate::UnalignedInteger>::IS_SIGNED;
+                    match is_signed && ::core::mem::size_of::<$repr>() != $num_bytes {
+                        true => Self::const_cmp(*self, *other),
+                        false => <$repr as ::core::cmp::Ord>::cmp(&self.to_repr(), &other.to_repr()),
+                    }
                 }
             }
 
             impl ::core::hash::Hash for $name {
                 #[inline]
                 fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
-                    <$repr as ::core::hash::Hash>::hash(
-                        &<$repr as ::core::convert::From<Self>>::from(*self),
-                        state,
-                    )
+                    state.write(&self.0)
                 }
             }
 
@@ -227,6 +332,22 @@ macro_rules! unaligned_int {
             }
         )*
     };
+    (
+        @checked_from $name:ident,
+        $( $method:ident($prim:ident) as $delegate:ident($wide:ident) );* $(;)?
+    ) => {
+        impl $name {
+            $(
+                #[doc = ::core::concat!("Creates an integer from the `", ::core::stringify!($prim), "` value if it is within bounds.")]
+                ///
+                #[doc = ::core::concat!("This is the `const` equivalent of `TryFrom<", ::core::stringify!($prim), ">`.")]
+                #[inline]
+                pub const fn $method(value: ::core::primitive::$prim) -> ::core::option::Option<Self> {
+                    Self::$delegate(value as ::core::primitive::$wide)
+                }
+            )*
+        }
+    };
     (
         @impl
         $( #[$docs:meta] )*
@@ -241,12 +362,24 @@ macro_rules! unaligned_int {
             pub const MAX: Self = Self::from_ne_bytes([0xFF_u8; $num_bytes]);
         }
 
-        impl $crate::UnalignedInteger for $name {
+        impl $name {
+            /// Returns the sign extension byte which is always `0x00` for unsigned integers.
+            #[inline]
+            const fn sign_ext_byte(self) -> ::core::primitive::u8 {
+                0x00_u8
+            }
+        }
+
+        $crate::arith::impl_unsigned_arith_methods!($name, $repr);
+        $crate::float::impl_float_methods!($name, $repr, unsigned);
+        $crate::bits::impl_unsigned_bit_methods!($name);
+
+        impl $crate::Integer for $name {
             type Repr = $repr;
 
             #[inline]
             fn sign_ext_byte(self) -> ::core::primitive::u8 {
-                0x00_u8
+                Self::sign_ext_byte(self)
             }
         }
     };
@@ -276,14 +409,24 @@ macro_rules! unaligned_int {
             pub(crate) const fn is_positive(self) -> ::core::primitive::bool {
                 (self.0[Self::msb_pos()] & 0x80_u8) == 0x00_u8
             }
+
+            /// Returns the sign extension byte of `self`.
+            #[inline]
+            const fn sign_ext_byte(self) -> ::core::primitive::u8 {
+                $crate::utils::sign_ext_byte(self.is_positive())
+            }
         }
 
-        impl $crate::UnalignedInteger for $name {
+        $crate::arith::impl_signed_arith_methods!($name, $repr);
+        $crate::float::impl_float_methods!($name, $repr, signed);
+        $crate::ops::impl_neg_op!($name);
+
+        impl $crate::Integer for $name {
             type Repr = $repr;
 
             #[inline]
             fn sign_ext_byte(self) -> ::core::primitive::u8 {
-                $crate::utils::sign_ext_byte(self.is_positive())
+                Self::sign_ext_byte(self)
             }
         }
     }
@@ -0,0 +1,579 @@
+//! Module to define integer types with explicit little- and big-endian storage.
+//!
+//! The integer types defined in `defs.rs` store their bytes in native byte order
+//! which is not portable across architectures. The types defined here, e.g. `U24Le`
+//! and `U24Be`, store their bytes in a fixed byte order and therefore can be used
+//! to declare on-disk or on-wire formats directly as Rust structs.
+//!
+//! Endian-fixed integers implement the same operator, comparison, formatting and parsing traits
+//! as well as `From` and `TryFrom` with Rust primitives as their native counterparts. Operators
+//! convert to the native integer type and back. They also implement [`UnalignedInteger`] with
+//! the `Repr` of their native counterparts so that they work with generic code such as
+//! [`ByteOrder`] or [`ByteReader`].
+//!
+//! Conversions between endian-fixed integers and other integer types of this crate are not
+//! provided, e.g. there is neither `From<U16Le> for U24Le` nor `From<U24Le> for U32`. Use `get`
+//! and `new` or the infallible `From` conversions with the native counterparts instead, e.g.
+//! `U32::from(value.get())`. The same applies to the arithmetic methods such as `checked_add`.
+//!
+//! [`UnalignedInteger`]: crate::UnalignedInteger
+//! [`ByteOrder`]: crate::ByteOrder
+//! [`ByteReader`]: crate::ByteReader
+
+use crate::{
+    I104, I112, I120, I128, I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, U104, U112,
+    U120, U128, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96,
+};
+
+macro_rules! endian_int {
+    (
+        $(
+            $native:ident => $le:ident, $be:ident as $signedness:ident {
+                From<$( $from:ty ),*>,
+                TryFrom<$( $try_from:ty ),*>,
+                Into<$( $into:ty ),*>,
+                TryInto<$( $try_into:ty ),*> $(,)?
+            }
+        );* $(;)?
+    ) => {
+        $(
+            endian_int!(@impl $native => $le, "little", to_le_bytes, from_le_bytes, false);
+            endian_int!(@impl $native => $be, "big", to_be_bytes, from_be_bytes, true);
+            endian_int!(@ops $le, $native, $signedness);
+            endian_int!(@ops $be, $native, $signedness);
+            endian_int!(@traits $le, $native, $signedness);
+            endian_int!(@traits $be, $native, $signedness);
+            endian_int!(@convert $le, $native {
+                From<$( $from ),*>, TryFrom<$( $try_from ),*>, Into<$( $into ),*>, TryInto<$( $try_into ),*>
+            });
+            endian_int!(@convert $be, $native {
+                From<$( $from ),*>, TryFrom<$( $try_from ),*>, Into<$( $into ),*>, TryInto<$( $try_into ),*>
+            });
+        )*
+    };
+    ( @impl $native:ident => $name:ident, $order:literal, $to_bytes:ident, $from_bytes:ident, $is_big_endian:literal ) => {
+        #[doc = ::core::concat!(
+            "[`", ::core::stringify!($native), "`] stored in ", $order, "-endian byte order with alignment of 1."
+        )]
+        #[derive(
+            ::core::marker::Copy,
+            ::core::clone::Clone,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+        )]
+        #[cfg_attr(
+            feature = "zerocopy",
+            derive(
+                ::zerocopy::FromBytes,
+                ::zerocopy::IntoBytes,
+                ::zerocopy::Unaligned,
+                ::zerocopy::Immutable,
+                ::zerocopy::KnownLayout,
+            )
+        )]
+        #[repr(transparent)]
+        pub struct $name([::core::primitive::u8; ::core::mem::size_of::<$native>()]);
+
+        impl $name {
+            /// The amount of bits required by this integer type.
+            pub const BITS: ::core::primitive::u32 = <$native>::BITS;
+
+            /// The smallest value that can be represented by this integer type.
+            pub const MIN: Self = Self::new(<$native>::MIN);
+
+            /// The largest value that can be represented by this integer type.
+            pub const MAX: Self = Self::new(<$native>::MAX);
+
+            #[doc = ::core::concat!("Creates a new integer from the native [`", ::core::stringify!($native), "`] `value`.")]
+            #[inline]
+            pub const fn new(value: $native) -> Self {
+                Self(value.$to_bytes())
+            }
+
+            #[doc = ::core::concat!("Returns the value as native [`", ::core::stringify!($native), "`].")]
+            #[inline]
+            pub const fn get(self) -> $native {
+                <$native>::$from_bytes(self.0)
+            }
+
+            /// Sets the value to `value`.
+            #[inline]
+            pub fn set(&mut self, value: $native) {
+                *self = Self::new(value);
+            }
+
+            #[doc = ::core::concat!("Creates a new integer from its ", $order, "-endian `bytes`.")]
+            #[inline]
+            pub const fn from_bytes(
+                bytes: [::core::primitive::u8; ::core::mem::size_of::<Self>()],
+            ) -> Self {
+                Self(bytes)
+            }
+
+            #[doc = ::core::concat!("Returns the ", $order, "-endian bytes of the integer.")]
+            #[inline]
+            pub const fn to_bytes(self) -> [::core::primitive::u8; ::core::mem::size_of::<Self>()] {
+                self.0
+            }
+
+            /// Returns the [`Ordering`](::core::cmp::Ordering) between `self` and `other`.
+            ///
+            /// This is the `const` equivalent of `Ord::cmp` and compares the bytes without swapping them.
+            #[inline]
+            pub const fn const_cmp(self, other: Self) -> ::core::cmp::Ordering {
+                let is_signed = <$native as $crate::UnalignedInteger>::IS_SIGNED;
+                $crate::utils::cmp_bytes(&self.0, &other.0, is_signed, $is_big_endian)
+            }
+        }
+
+        impl ::core::default::Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self([0x00_u8; ::core::mem::size_of::<Self>()])
+            }
+        }
+
+        impl ::core::cmp::PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(<Self as ::core::cmp::Ord>::cmp(self, other))
+            }
+        }
+
+        impl ::core::cmp::Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                Self::const_cmp(*self, *other)
+            }
+        }
+
+        impl ::core::hash::Hash for $name {
+            #[inline]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                state.write(&self.0)
+            }
+        }
+
+        impl ::core::convert::From<$native> for $name {
+            #[inline]
+            fn from(value: $native) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $native {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::ParseIntError;
+
+            #[inline]
+            fn from_str(src: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                <$native as ::core::str::FromStr>::from_str(src).map(Self::new)
+            }
+        }
+
+        endian_int!(@fmt $name: Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp);
+
+        // SAFETY: `$name` is a `#[repr(transparent)]` wrapper around a byte array
+        //         for which the all-zero bit pattern is a valid value.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::Zeroable for $name {}
+
+        // SAFETY: `$name` is a `#[repr(transparent)]` wrapper around a byte array
+        //         which has no padding bytes and for which all bit patterns are valid.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::Pod for $name {}
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $name {
+            #[inline]
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                <$native as ::serde::Serialize>::serialize(&self.get(), serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            #[inline]
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                <$native as ::serde::Deserialize<'de>>::deserialize(deserializer).map(Self::new)
+            }
+        }
+    };
+    ( @ops $name:ident, $native:ident, $signedness:ident ) => {
+        endian_int!(@binop $name, $native, Add::add, AddAssign::add_assign);
+        endian_int!(@binop $name, $native, Sub::sub, SubAssign::sub_assign);
+        endian_int!(@binop $name, $native, Mul::mul, MulAssign::mul_assign);
+        endian_int!(@binop $name, $native, Div::div, DivAssign::div_assign);
+        endian_int!(@binop $name, $native, Rem::rem, RemAssign::rem_assign);
+        $crate::ops::impl_bit_ops!($name);
+        endian_int!(@shift $name, $native for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        endian_int!(@neg $name, $native, $signedness);
+    };
+    ( @binop $name:ident, $native:ident, $trait:ident::$method:ident, $assign_trait:ident::$assign_method:ident ) => {
+        impl ::core::ops::$trait for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self::new(<$native as ::core::ops::$trait>::$method(self.get(), rhs.get()))
+            }
+        }
+
+        $crate::ops::forward_ref_binop!(impl $trait::$method, $assign_trait::$assign_method for $name);
+    };
+    ( @shift $name:ident, $native:ident for $( $rhs:ty ),* ) => {
+        $(
+            impl ::core::ops::Shl<$rhs> for $name {
+                type Output = Self;
+
+                #[inline]
+                fn shl(self, rhs: $rhs) -> Self::Output {
+                    Self::new(<$native as ::core::ops::Shl<$rhs>>::shl(self.get(), rhs))
+                }
+            }
+
+            impl ::core::ops::Shr<$rhs> for $name {
+                type Output = Self;
+
+                #[inline]
+                fn shr(self, rhs: $rhs) -> Self::Output {
+                    Self::new(<$native as ::core::ops::Shr<$rhs>>::shr(self.get(), rhs))
+                }
+            }
+
+            $crate::ops::forward_ref_binop!(impl Shl::shl, ShlAssign::shl_assign for $name, $rhs);
+            $crate::ops::forward_ref_binop!(impl Shr::shr, ShrAssign::shr_assign for $name, $rhs);
+        )*
+    };
+    ( @neg $name:ident, $native:ident, unsigned ) => {};
+    ( @neg $name:ident, $native:ident, signed ) => {
+        impl ::core::ops::Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self::new(<$native as ::core::ops::Neg>::neg(self.get()))
+            }
+        }
+
+        impl<'a> ::core::ops::Neg for &'a $name {
+            type Output = $name;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                <$name as ::core::ops::Neg>::neg(*self)
+            }
+        }
+    };
+    ( @traits $name:ident, $native:ident, $signedness:ident ) => {
+        impl $crate::traits::sealed::Sealed for $name {}
+
+        impl $crate::UnalignedInteger for $name {
+            type Repr = <$native as $crate::UnalignedInteger>::Repr;
+            type Bytes = <$native as $crate::UnalignedInteger>::Bytes;
+
+            const BITS: ::core::primitive::u32 = Self::BITS;
+            const BYTES: ::core::primitive::usize = ::core::mem::size_of::<Self>();
+            const MIN: Self = Self::MIN;
+            const MAX: Self = Self::MAX;
+            const IS_SIGNED: ::core::primitive::bool = <$native as $crate::UnalignedInteger>::IS_SIGNED;
+
+            #[inline]
+            fn to_repr(self) -> Self::Repr {
+                <$native as $crate::UnalignedInteger>::to_repr(self.get())
+            }
+
+            #[inline]
+            fn from_repr_truncating(repr: Self::Repr) -> Self {
+                Self::new(<$native as $crate::UnalignedInteger>::from_repr_truncating(repr))
+            }
+
+            #[inline]
+            fn to_le_bytes(self) -> Self::Bytes {
+                <$native as $crate::UnalignedInteger>::to_le_bytes(self.get())
+            }
+
+            #[inline]
+            fn to_be_bytes(self) -> Self::Bytes {
+                <$native as $crate::UnalignedInteger>::to_be_bytes(self.get())
+            }
+
+            #[inline]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                Self::new(<$native as $crate::UnalignedInteger>::from_le_bytes(bytes))
+            }
+
+            #[inline]
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                Self::new(<$native as $crate::UnalignedInteger>::from_be_bytes(bytes))
+            }
+        }
+
+        endian_int!(@traits $name, $native as $signedness);
+    };
+    ( @traits $name:ident, $native:ident as unsigned ) => {
+        impl $crate::Unsigned for $name {
+            #[inline]
+            fn is_power_of_two(self) -> ::core::primitive::bool {
+                <$native as $crate::Unsigned>::is_power_of_two(self.get())
+            }
+        }
+    };
+    ( @traits $name:ident, $native:ident as signed ) => {
+        impl $crate::Signed for $name {
+            #[inline]
+            fn is_negative(self) -> ::core::primitive::bool {
+                <$native as $crate::Signed>::is_negative(self.get())
+            }
+        }
+    };
+    (
+        @convert $name:ident, $native:ident {
+            From<$( $from:ty ),*>,
+            TryFrom<$( $try_from:ty ),*>,
+            Into<$( $into:ty ),*>,
+            TryInto<$( $try_into:ty ),*>
+        }
+    ) => {
+        $(
+            impl ::core::convert::From<$from> for $name {
+                #[inline]
+                fn from(value: $from) -> Self {
+                    Self::new(<$native as ::core::convert::From<$from>>::from(value))
+                }
+            }
+        )*
+        $(
+            impl ::core::convert::TryFrom<$try_from> for $name {
+                type Error = <$native as ::core::convert::TryFrom<$try_from>>::Error;
+
+                #[inline]
+                fn try_from(value: $try_from) -> ::core::result::Result<Self, Self::Error> {
+                    <$native as ::core::convert::TryFrom<$try_from>>::try_from(value).map(Self::new)
+                }
+            }
+        )*
+        $(
+            impl ::core::convert::From<$name> for $into {
+                #[inline]
+                fn from(value: $name) -> Self {
+                    <$into as ::core::convert::From<$native>>::from(value.get())
+                }
+            }
+        )*
+        $(
+            impl ::core::convert::TryFrom<$name> for $try_into {
+                type Error = <$try_into as ::core::convert::TryFrom<$native>>::Error;
+
+                #[inline]
+                fn try_from(value: $name) -> ::core::result::Result<Self, Self::Error> {
+                    <$try_into as ::core::convert::TryFrom<$native>>::try_from(value.get())
+                }
+            }
+        )*
+    };
+    ( @fmt $name:ident: $( $fmt_trait:ident ),* ) => {
+        $(
+            impl ::core::fmt::$fmt_trait for $name {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::$fmt_trait::fmt(&self.get(), f)
+                }
+            }
+        )*
+    };
+}
+endian_int! {
+    U16 => U16Le, U16Be as unsigned {
+        From<u8, u16>,
+        TryFrom<u32, u64, u128, i8, i16, i32, i64, i128>,
+        Into<u16, u32, u64, u128, i32, i64, i128>,
+        TryInto<i16>,
+    };
+    I16 => I16Le, I16Be as signed {
+        From<u8, i8, i16>,
+        TryFrom<u16, u32, u64, u128, i32, i64, i128>,
+        Into<i16, i32, i64, i128>,
+        TryInto<u16, u32, u64, u128>,
+    };
+    U24 => U24Le, U24Be as unsigned {
+        From<u8, u16>,
+        TryFrom<u32, u64, u128, i8, i16, i32, i64, i128>,
+        Into<u32, u64, u128>,
+        TryInto<u16, i16>,
+    };
+    I24 => I24Le, I24Be as signed {
+        From<u8, u16, i8, i16>,
+        TryFrom<u32, u64, u128, i32, i64, i128>,
+        Into<i32, i64, i128>,
+        TryInto<u16, u32, u64, u128, i16>,
+    };
+    U32 => U32Le, U32Be as unsigned {
+        From<u8, u16, u32>,
+        TryFrom<u64, u128, i8, i16, i32, i64, i128>,
+        Into<u32, u64, u128, i64, i128>,
+        TryInto<u16, i16, i32>,
+    };
+    I32 => I32Le, I32Be as signed {
+        From<u8, u16, i8, i16, i32>,
+        TryFrom<u32, u64, u128, i64, i128>,
+        Into<i32, i64, i128>,
+        TryInto<u16, u32, u64, u128, i16>,
+    };
+    U40 => U40Le, U40Be as unsigned {
+        From<u8, u16, u32>,
+        TryFrom<u64, u128, i8, i16, i32, i64, i128>,
+        Into<u64, u128>,
+        TryInto<u16, u32, i16, i32>,
+    };
+    I40 => I40Le, I40Be as signed {
+        From<u8, u16, u32, i8, i16, i32>,
+        TryFrom<u64, u128, i64, i128>,
+        Into<i64, i128>,
+        TryInto<u16, u32, u64, u128, i16, i32>,
+    };
+    U48 => U48Le, U48Be as unsigned {
+        From<u8, u16, u32>,
+        TryFrom<u64, u128, i8, i16, i32, i64, i128>,
+        Into<u64, u128>,
+        TryInto<u16, u32, i16, i32>,
+    };
+    I48 => I48Le, I48Be as signed {
+        From<u8, u16, u32, i8, i16, i32>,
+        TryFrom<u64, u128, i64, i128>,
+        Into<i64, i128>,
+        TryInto<u16, u32, u64, u128, i16, i32>,
+    };
+    U56 => U56Le, U56Be as unsigned {
+        From<u8, u16, u32>,
+        TryFrom<u64, u128, i8, i16, i32, i64, i128>,
+        Into<u64, u128>,
+        TryInto<u16, u32, i16, i32>,
+    };
+    I56 => I56Le, I56Be as signed {
+        From<u8, u16, u32, i8, i16, i32>,
+        TryFrom<u64, u128, i64, i128>,
+        Into<i64, i128>,
+        TryInto<u16, u32, u64, u128, i16, i32>,
+    };
+    U64 => U64Le, U64Be as unsigned {
+        From<u8, u16, u32, u64>,
+        TryFrom<u128, i8, i16, i32, i64, i128>,
+        Into<u64, u128, i128>,
+        TryInto<u16, u32, i16, i32, i64>,
+    };
+    I64 => I64Le, I64Be as signed {
+        From<u8, u16, u32, i8, i16, i32, i64>,
+        TryFrom<u64, u128, i128>,
+        Into<i64, i128>,
+        TryInto<u16, u32, u64, u128, i16, i32>,
+    };
+    U72 => U72Le, U72Be as unsigned {
+        From<u8, u16, u32, u64>,
+        TryFrom<u128, i8, i16, i32, i64, i128>,
+        Into<u128>,
+        TryInto<u16, u32, u64, i16, i32, i64>,
+    };
+    I72 => I72Le, I72Be as signed {
+        From<u8, u16, u32, u64, i8, i16, i32, i64>,
+        TryFrom<u128, i128>,
+        Into<i128>,
+        TryInto<u16, u32, u64, u128, i16, i32, i64>,
+    };
+    U80 => U80Le, U80Be as unsigned {
+        From<u16, u32, u64>,
+        TryFrom<u128, i8, i16, i32, i64, i128>,
+        Into<u128>,
+        TryInto<u16, u32, u64, i16, i32, i64>,
+    };
+    I80 => I80Le, I80Be as signed {
+        From<u8, u16, u32, u64, i8, i16, i32, i64>,
+        TryFrom<u128, i128>,
+        Into<i128>,
+        TryInto<u16, u32, u64, u128, i16, i32, i64>,
+    };
+    U88 => U88Le, U88Be as unsigned {
+        From<u16, u32, u64>,
+        TryFrom<u128, i8, i16, i32, i64, i128>,
+        Into<u128>,
+        TryInto<u16, u32, u64, i16, i32, i64>,
+    };
+    I88 => I88Le, I88Be as signed {
+        From<u8, u16, u32, u64, i8, i16, i32, i64>,
+        TryFrom<u128, i128>,
+        Into<i128>,
+        TryInto<u16, u32, u64, u128, i16, i32, i64>,
+    };
+    U96 => U96Le, U96Be as unsigned {
+        From<u16, u32, u64>,
+        TryFrom<u128, i8, i16, i32, i64, i128>,
+        Into<u128>,
+        TryInto<u16, u32, u64, i16, i32, i64>,
+    };
+    I96 => I96Le, I96Be as signed {
+        From<u8, u16, u32, u64, i8, i16, i32, i64>,
+        TryFrom<u128, i128>,
+        Into<i128>,
+        TryInto<u16, u32, u64, u128, i16, i32, i64>,
+    };
+    U104 => U104Le, U104Be as unsigned {
+        From<u16, u32, u64>,
+        TryFrom<u128, i8, i16, i32, i64, i128>,
+        Into<u128>,
+        TryInto<u16, u32, u64, i16, i32, i64>,
+    };
+    I104 => I104Le, I104Be as signed {
+        From<u8, u16, u32, u64, i8, i16, i32, i64>,
+        TryFrom<u128, i128>,
+        Into<i128>,
+        TryInto<u16, u32, u64, u128, i16, i32, i64>,
+    };
+    U112 => U112Le, U112Be as unsigned {
+        From<u16, u32, u64>,
+        TryFrom<u128, i8, i16, i32, i64, i128>,
+        Into<u128>,
+        TryInto<u16, u32, u64, i16, i32, i64>,
+    };
+    I112 => I112Le, I112Be as signed {
+        From<u8, u16, u32, u64, i8, i16, i32, i64>,
+        TryFrom<u128, i128>,
+        Into<i128>,
+        TryInto<u16, u32, u64, u128, i16, i32, i64>,
+    };
+    U120 => U120Le, U120Be as unsigned {
+        From<u16, u32, u64>,
+        TryFrom<u128, i8, i16, i32, i64, i128>,
+        Into<u128>,
+        TryInto<u16, u32, u64, i16, i32, i64>,
+    };
+    I120 => I120Le, I120Be as signed {
+        From<u8, u16, u32, u64, i8, i16, i32, i64>,
+        TryFrom<u128, i128>,
+        Into<i128>,
+        TryInto<u16, u32, u64, u128, i16, i32, i64>,
+    };
+    U128 => U128Le, U128Be as unsigned {
+        From<u8, u16, u32, u64, u128>,
+        TryFrom<i8, i16, i32, i64, i128>,
+        Into<u128>,
+        TryInto<u16, u32, u64, i16, i32, i64, i128>,
+    };
+    I128 => I128Le, I128Be as signed {
+        From<u8, u16, u32, u64, i8, i16, i32, i64, i128>,
+        TryFrom<u128>,
+        Into<i128>,
+        TryInto<u16, u32, u64, u128, i16, i32, i64>,
+    };
+}
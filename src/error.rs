@@ -1,10 +1,297 @@
 /// Error that may occur for fallible conversions between integers.
+///
+/// It reports the [kind](TryFromIntErrorKind) of the failure and the bitwidth of the target integer type.
+///
+/// # Example
+///
+/// ```
+/// use intx::{TryFromIntErrorKind, U24};
+///
+/// let error = U24::try_from(-1_i32).unwrap_err();
+/// assert_eq!(error.kind(), &TryFromIntErrorKind::SignMismatch);
+/// assert_eq!(error.target_bits(), 24);
+/// assert_eq!(
+///     error.to_string(),
+///     "out of range integral type conversion attempted: negative value for 24-bit unsigned integer type",
+/// );
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct TryFromIntError(pub(crate) ());
+pub struct TryFromIntError {
+    kind: TryFromIntErrorKind,
+    target_bits: u32,
+}
+
+/// Enum to store the various kinds of errors that can cause a fallible integer conversion to fail.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TryFromIntErrorKind {
+    /// Integer is too large to store in target integer type.
+    PosOverflow,
+    /// Integer is too small to store in signed target integer type.
+    NegOverflow,
+    /// Integer is negative and the target integer type is unsigned.
+    SignMismatch,
+    /// Integer is zero and the target integer type is non-zero.
+    Zero,
+    /// Integer is the value reserved by the target integer type such as `U32Except<VALUE>`.
+    Reserved,
+    /// Conversion between Rust primitives failed as reported by [`core::num::TryFromIntError`].
+    ///
+    /// The core error reports neither the cause nor the target integer type,
+    /// so the [`target_bits`](TryFromIntError::target_bits) of this kind are always `0`.
+    Primitive,
+}
+
+impl TryFromIntError {
+    /// Creates a new [`TryFromIntError`] of the given `kind` for a target type with `target_bits`.
+    #[inline]
+    pub(crate) const fn new(kind: TryFromIntErrorKind, target_bits: u32) -> Self {
+        Self { kind, target_bits }
+    }
+
+    /// Creates a new [`TryFromIntError`] for a value out of bounds of the target type.
+    ///
+    /// The kind is derived from the sign of the value and the signedness of the target type.
+    #[cold]
+    pub(crate) const fn out_of_bounds(
+        is_negative: bool,
+        is_signed: bool,
+        target_bits: u32,
+    ) -> Self {
+        let kind = match (is_negative, is_signed) {
+            (true, true) => TryFromIntErrorKind::NegOverflow,
+            (true, false) => TryFromIntErrorKind::SignMismatch,
+            (false, _) => TryFromIntErrorKind::PosOverflow,
+        };
+        Self::new(kind, target_bits)
+    }
+
+    /// Outputs the detailed cause of the conversion failing.
+    #[inline]
+    pub const fn kind(&self) -> &TryFromIntErrorKind {
+        &self.kind
+    }
+
+    /// Returns the bitwidth of the target integer type of the failed conversion.
+    #[inline]
+    pub const fn target_bits(&self) -> u32 {
+        self.target_bits
+    }
+}
+
+impl core::fmt::Display for TryFromIntError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let bits = self.target_bits;
+        f.write_str("out of range integral type conversion attempted")?;
+        match self.kind {
+            TryFromIntErrorKind::PosOverflow => {
+                write!(f, ": value too large for {bits}-bit integer type")
+            }
+            TryFromIntErrorKind::NegOverflow => {
+                write!(f, ": value too small for {bits}-bit integer type")
+            }
+            TryFromIntErrorKind::SignMismatch => {
+                write!(f, ": negative value for {bits}-bit unsigned integer type")
+            }
+            TryFromIntErrorKind::Zero => {
+                write!(f, ": zero value for {bits}-bit non-zero integer type")
+            }
+            TryFromIntErrorKind::Reserved => {
+                write!(f, ": reserved value for {bits}-bit integer type")
+            }
+            TryFromIntErrorKind::Primitive => Ok(()),
+        }
+    }
+}
+
+impl core::error::Error for TryFromIntError {}
 
 impl From<core::num::TryFromIntError> for TryFromIntError {
+    /// Converts the error of a conversion between Rust primitives.
+    ///
+    /// The resulting error is of kind [`TryFromIntErrorKind::Primitive`] with `0` target bits.
     #[inline]
     fn from(_: core::num::TryFromIntError) -> Self {
-        Self(())
+        Self::new(TryFromIntErrorKind::Primitive, 0)
+    }
+}
+
+impl From<core::convert::Infallible> for TryFromIntError {
+    #[inline]
+    fn from(never: core::convert::Infallible) -> Self {
+        match never {}
     }
 }
+
+/// Returns the [`TryFromIntError`] for a value that is out of bounds of the integer type `$to`.
+///
+/// The `$is_negative` expression tells whether the value is negative.
+macro_rules! try_from_int_error {
+    ( $to:ty, $is_negative:expr ) => {
+        $crate::TryFromIntError::out_of_bounds(
+            $is_negative,
+            <$to>::MIN != <$to as ::core::default::Default>::default(),
+            <$to>::BITS,
+        )
+    };
+}
+pub(crate) use try_from_int_error;
+
+/// Error that may occur for fallible bulk conversions between slices of integers.
+///
+/// It reports the index of the first value that is out of bounds for the target integer type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TryFromIntSliceError {
+    index: usize,
+}
+
+impl TryFromIntSliceError {
+    /// Creates a new [`TryFromIntSliceError`] for the value at `index`.
+    #[inline]
+    pub(crate) const fn new(index: usize) -> Self {
+        Self { index }
+    }
+
+    /// Returns the index of the first value that failed to convert.
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl core::fmt::Display for TryFromIntSliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "out of range integral type conversion attempted at index {}",
+            self.index
+        )
+    }
+}
+
+impl core::error::Error for TryFromIntSliceError {}
+
+/// Error that may occur when reading or writing past the end of a [`ByteReader`] or [`ByteWriter`].
+///
+/// It reports the offset at which the access failed, the bytes it required and the bytes that remained.
+///
+/// [`ByteReader`]: crate::ByteReader
+/// [`ByteWriter`]: crate::ByteWriter
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OutOfBoundsError {
+    offset: usize,
+    required: usize,
+    remaining: usize,
+}
+
+impl OutOfBoundsError {
+    /// Creates a new [`OutOfBoundsError`] for the access of `required` bytes at `offset`.
+    #[inline]
+    pub(crate) const fn new(offset: usize, required: usize, remaining: usize) -> Self {
+        Self {
+            offset,
+            required,
+            remaining,
+        }
+    }
+
+    /// Returns the offset in bytes at which the failed access started.
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the amount of bytes that the failed access required.
+    #[inline]
+    pub const fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Returns the amount of bytes that remained at the offset of the failed access.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl core::fmt::Display for OutOfBoundsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "out of bounds access of {} bytes at offset {} with {} bytes remaining",
+            self.required, self.offset, self.remaining,
+        )
+    }
+}
+
+impl core::error::Error for OutOfBoundsError {}
+
+/// Error that may occur when parsing an integer from a string.
+///
+/// This error is returned by `from_str_radix` and the `FromStr` implementations
+/// of the integer types provided by this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIntError {
+    pub(crate) kind: IntErrorKind,
+}
+
+/// Enum to store the various kinds of errors that can cause parsing an integer to fail.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IntErrorKind {
+    /// Value being parsed is empty.
+    Empty,
+    /// Contains an invalid digit in its context.
+    InvalidDigit,
+    /// Integer is too large to store in target integer type.
+    PosOverflow,
+    /// Integer is too small to store in target integer type.
+    NegOverflow,
+}
+
+impl ParseIntError {
+    /// Creates a new [`ParseIntError`] of the given `kind`.
+    #[inline]
+    pub(crate) const fn new(kind: IntErrorKind) -> Self {
+        Self { kind }
+    }
+
+    /// Creates a new [`ParseIntError`] from the `core` parsing `error`.
+    #[inline]
+    pub(crate) const fn from_core(error: &core::num::ParseIntError) -> Self {
+        let kind = match error.kind() {
+            core::num::IntErrorKind::Empty => IntErrorKind::Empty,
+            core::num::IntErrorKind::PosOverflow => IntErrorKind::PosOverflow,
+            core::num::IntErrorKind::NegOverflow => IntErrorKind::NegOverflow,
+            _ => IntErrorKind::InvalidDigit,
+        };
+        Self::new(kind)
+    }
+
+    /// Outputs the detailed cause of parsing an integer failing.
+    #[inline]
+    pub const fn kind(&self) -> &IntErrorKind {
+        &self.kind
+    }
+}
+
+impl From<core::num::ParseIntError> for ParseIntError {
+    #[inline]
+    fn from(error: core::num::ParseIntError) -> Self {
+        Self::from_core(&error)
+    }
+}
+
+impl core::fmt::Display for ParseIntError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self.kind {
+            IntErrorKind::Empty => "cannot parse integer from empty string",
+            IntErrorKind::InvalidDigit => "invalid digit found in string",
+            IntErrorKind::PosOverflow => "number too large to fit in target type",
+            IntErrorKind::NegOverflow => "number too small to fit in target type",
+        };
+        f.write_str(message)
+    }
+}
+
+impl core::error::Error for ParseIntError {}
@@ -0,0 +1,191 @@
+//! Module to implement conversions between all unaligned integer types and the
+//! floating-point primitives `f32` and `f64`.
+//!
+//! - `From` is only implemented where the conversion is lossless, e.g. `From<I24> for f32`
+//!   since `f32` has a 24-bit significand or `From<U48> for f64`.
+//! - `to_f32` and `to_f64` are implemented for all integer types and round to the nearest
+//!   representable value just like `as` casts between Rust primitives.
+//! - `saturating_from_f32` and `saturating_from_f64` mirror the semantics of `as` casts from
+//!   floating-point to integer primitives: the fractional part is truncated, values out of
+//!   bounds saturate at `MIN` or `MAX` and `NaN` converts to `0`.
+//! - `checked_from_f32` and `checked_from_f64` also truncate the fractional part but return
+//!   `None` for `NaN`, infinities and values that are out of bounds after truncation.
+
+use crate::{I16, I24, I32, I40, I48, U16, U24, U32, U40, U48};
+
+/// Returns `2^exp` as `f64`.
+///
+/// # Note
+///
+/// Returns infinity if the result is too large for `f64`.
+#[inline]
+pub(crate) const fn exp2_f64(exp: u32) -> f64 {
+    let mut result = 1.0_f64;
+    let mut i = 0;
+    while i < exp {
+        result *= 2.0;
+        i += 1;
+    }
+    result
+}
+
+/// Returns `2^exp` as `f32`.
+///
+/// # Note
+///
+/// Returns infinity if the result is too large for `f32`.
+#[inline]
+pub(crate) const fn exp2_f32(exp: u32) -> f32 {
+    let mut result = 1.0_f32;
+    let mut i = 0;
+    while i < exp {
+        result *= 2.0;
+        i += 1;
+    }
+    result
+}
+
+/// Implements the floating-point conversion methods for the unaligned integer `$name`.
+///
+/// The `$signedness` is either `signed` or `unsigned`.
+macro_rules! impl_float_methods {
+    ( $name:ident, $repr:ty, $signedness:ident ) => {
+        impl $name {
+            /// Converts `self` to the nearest `f32` value.
+            ///
+            /// This rounds the same way as an `as` cast from an integer primitive to `f32`.
+            #[inline]
+            pub const fn to_f32(self) -> ::core::primitive::f32 {
+                self.to_repr() as ::core::primitive::f32
+            }
+
+            /// Converts `self` to the nearest `f64` value.
+            ///
+            /// This rounds the same way as an `as` cast from an integer primitive to `f64`.
+            #[inline]
+            pub const fn to_f64(self) -> ::core::primitive::f64 {
+                self.to_repr() as ::core::primitive::f64
+            }
+
+            /// Converts `value` to `Self` with the semantics of an `as` cast to an integer primitive.
+            ///
+            /// The fractional part is truncated, values out of bounds saturate at `MIN`
+            /// or `MAX` and `NaN` converts to `0`.
+            #[inline]
+            pub const fn saturating_from_f32(value: ::core::primitive::f32) -> Self {
+                Self::saturating_from_repr(value as $repr)
+            }
+
+            /// Converts `value` to `Self` with the semantics of an `as` cast to an integer primitive.
+            ///
+            /// The fractional part is truncated, values out of bounds saturate at `MIN`
+            /// or `MAX` and `NaN` converts to `0`.
+            #[inline]
+            pub const fn saturating_from_f64(value: ::core::primitive::f64) -> Self {
+                Self::saturating_from_repr(value as $repr)
+            }
+
+            /// Converts `value` to `Self` truncating its fractional part.
+            ///
+            /// Returns `None` if `value` is `NaN`, infinite or out of bounds after truncation.
+            #[inline]
+            pub const fn checked_from_f32(value: ::core::primitive::f32) -> ::core::option::Option<Self> {
+                if value.is_nan() {
+                    return ::core::option::Option::None;
+                }
+                const BOUND: ::core::primitive::f32 =
+                    $crate::float::exp2_f32($crate::float::impl_float_methods!(@bound_exp $signedness, $name));
+                if $crate::float::impl_float_methods!(@is_out_of_bounds $signedness, value, BOUND) {
+                    return ::core::option::Option::None;
+                }
+                ::core::option::Option::Some(Self::saturating_from_f32(value))
+            }
+
+            /// Converts `value` to `Self` truncating its fractional part.
+            ///
+            /// Returns `None` if `value` is `NaN`, infinite or out of bounds after truncation.
+            #[inline]
+            pub const fn checked_from_f64(value: ::core::primitive::f64) -> ::core::option::Option<Self> {
+                if value.is_nan() {
+                    return ::core::option::Option::None;
+                }
+                const BOUND: ::core::primitive::f64 =
+                    $crate::float::exp2_f64($crate::float::impl_float_methods!(@bound_exp $signedness, $name));
+                if $crate::float::impl_float_methods!(@is_out_of_bounds $signedness, value, BOUND) {
+                    return ::core::option::Option::None;
+                }
+                ::core::option::Option::Some(Self::saturating_from_f64(value))
+            }
+
+            /// Converts the `repr` to `Self` saturating at `MIN` and `MAX`.
+            #[inline]
+            const fn saturating_from_repr(repr: $repr) -> Self {
+                if repr < Self::MIN.to_repr() {
+                    return Self::MIN;
+                }
+                if repr > Self::MAX.to_repr() {
+                    return Self::MAX;
+                }
+                Self::from_repr_truncating(repr)
+            }
+        }
+    };
+    ( @bound_exp unsigned, $name:ident ) => {
+        <$name>::BITS
+    };
+    ( @bound_exp signed, $name:ident ) => {
+        <$name>::BITS - 1
+    };
+    ( @is_out_of_bounds unsigned, $value:ident, $bound:ident ) => {
+        // The valid values are all within `-1 < value < 2^BITS`.
+        $value <= -1.0 || $value >= $bound
+    };
+    ( @is_out_of_bounds signed, $value:ident, $bound:ident ) => {
+        // The valid values are all within `-2^(BITS-1) - 1 < value < 2^(BITS-1)`.
+        //
+        // Note: `-2^(BITS-1) - 1` might not be representable as floating-point value.
+        //       Instead `-bound - value` is computed which is exact for values close
+        //       to `-bound` and at least `1` for all other values below `-bound`.
+        $value >= $bound || ($value < -$bound && -$bound - $value >= 1.0)
+    };
+}
+pub(crate) use impl_float_methods;
+
+macro_rules! impl_from_for_float {
+    ( $( impl From<$from:ty> for $to:ident );* $(;)? ) => {
+        $(
+            impl ::core::convert::From<$from> for ::core::primitive::$to {
+                #[inline]
+                fn from(value: $from) -> Self {
+                    $crate::float::impl_from_for_float!(@convert $to, value)
+                }
+            }
+        )*
+    };
+    ( @convert f32, $value:ident ) => {
+        $value.to_f32()
+    };
+    ( @convert f64, $value:ident ) => {
+        $value.to_f64()
+    };
+}
+use impl_from_for_float;
+
+// Note: `From` is only implemented if all values are exactly representable
+//       which requires at most 24 bits for `f32` and 53 bits for `f64`.
+impl_from_for_float! {
+    impl From<U16> for f32;
+    impl From<I16> for f32;
+    impl From<U24> for f32;
+    impl From<I24> for f32;
+    impl From<U16> for f64;
+    impl From<I16> for f64;
+    impl From<U24> for f64;
+    impl From<I24> for f64;
+    impl From<U32> for f64;
+    impl From<I32> for f64;
+    impl From<U40> for f64;
+    impl From<I40> for f64;
+    impl From<U48> for f64;
+    impl From<I48> for f64;
+}
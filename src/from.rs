@@ -14,8 +14,8 @@ macro_rules! impl_for {
         impl ::core::convert::From<$from> for $to {
             #[inline]
             fn from(value: $from) -> Self {
-                <Self as ::core::convert::From<<Self as $crate::UnalignedInteger>::Repr>>::from(
-                    <<Self as $crate::UnalignedInteger>::Repr as ::core::convert::From<$from>>::from(value)
+                <Self as ::core::convert::From<<Self as $crate::Integer>::Repr>>::from(
+                    <<Self as $crate::Integer>::Repr as ::core::convert::From<$from>>::from(value)
                 )
             }
         }
@@ -24,8 +24,8 @@ macro_rules! impl_for {
         impl ::core::convert::From<$from> for $to {
             #[inline]
             fn from(value: $from) -> Self {
-                <Self as ::core::convert::From<<$from as $crate::UnalignedInteger>::Repr>>::from(
-                    <<$from as $crate::UnalignedInteger>::Repr as ::core::convert::From<$from>>::from(value)
+                <Self as ::core::convert::From<<$from as $crate::Integer>::Repr>>::from(
+                    <<$from as $crate::Integer>::Repr as ::core::convert::From<$from>>::from(value)
                 )
             }
         }
@@ -43,7 +43,7 @@ macro_rules! impl_for {
             #[inline]
             fn from(value: $from) -> Self {
                 let mut result = [
-                    <$from as $crate::UnalignedInteger>::sign_ext_byte(value);
+                    <$from as $crate::Integer>::sign_ext_byte(value);
                     ::core::mem::size_of::<$to>()
                 ];
                 $crate::utils::extend_bytes(&mut result, &value.to_ne_bytes());
@@ -0,0 +1,378 @@
+//! Module to define the const-generic `UInt<N>` and `Int<N>` integer types.
+//!
+//! Both types are `N` bytes wide with alignment of 1 and store their bytes in
+//! native byte order just like the concrete integer types, e.g. `UInt<3>` has
+//! the same layout as `U24`. This allows to write code that is generic over
+//! the width of the integer such as width-generic serializers.
+//!
+//! The supported widths are 1 to 16 bytes. Using any other width results in a
+//! compilation error when an integer value is constructed, compared or accessed.
+//! All values are processed via `u128` or `i128` respectively, which includes
+//! the fallible conversions from and into the Rust primitive integer types.
+
+use crate::{
+    I104, I112, I120, I128, I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, U104, U112,
+    U120, U128, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96,
+};
+
+/// Const-generic unsigned integer with `N` bytes and alignment of 1.
+///
+/// # Example
+///
+/// ```
+/// use intx::{UInt, U24};
+///
+/// fn read<const N: usize>(bytes: &[u8]) -> UInt<N> {
+///     UInt::from_le_bytes(bytes[..N].try_into().unwrap())
+/// }
+///
+/// let value = read::<3>(&[0x01, 0x02, 0x03, 0x04]);
+/// assert_eq!(value.to_u128(), 0x03_0201);
+/// assert_eq!(U24::from(value), U24::new_const(0x03_0201));
+/// ```
+///
+/// Widths larger than 16 bytes are rejected at compile time:
+///
+/// ```compile_fail
+/// let value = intx::UInt::<17>::MAX.to_u128();
+/// ```
+///
+/// The same holds for integers without any bytes:
+///
+/// ```compile_fail
+/// let value = intx::UInt::<0>::from_le_bytes([]);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Unaligned,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+    )
+)]
+#[repr(transparent)]
+pub struct UInt<const N: usize>([u8; N]);
+
+/// Const-generic signed integer with `N` bytes and alignment of 1.
+///
+/// # Example
+///
+/// ```
+/// use intx::{Int, I48};
+///
+/// fn write<const N: usize>(value: Int<N>, buffer: &mut [u8]) {
+///     buffer[..N].copy_from_slice(&value.to_le_bytes());
+/// }
+///
+/// let mut buffer = [0x00_u8; 8];
+/// write(Int::<6>::from(I48::from(-2_i8)), &mut buffer);
+/// assert_eq!(buffer, [0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00]);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Unaligned,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+    )
+)]
+#[repr(transparent)]
+pub struct Int<const N: usize>([u8; N]);
+
+macro_rules! generic_int {
+    ( $name:ident, $wide:ident, $to_wide:ident, $checked_from_wide:ident, $is_signed:literal ) => {
+        impl<const N: usize> $name<N> {
+            /// The amount of bits required by this integer type.
+            pub const BITS: u32 = N as u32 * 8;
+
+            /// The amount of bytes required by this integer type.
+            pub const BYTES: usize = N;
+
+            /// Asserts that `N` is a supported width.
+            const ASSERT_WIDTH: () = ::core::assert!(0 < N && N <= 16, "unsupported width of integer type");
+
+            /// Returns the index position of the most significant byte.
+            #[inline]
+            const fn msb_pos() -> usize {
+                match ::core::cfg!(target_endian = "big") {
+                    true => 0,
+                    false => N - 1,
+                }
+            }
+
+            /// Creates an integer value from its memory representation as a byte array in native endianness.
+            #[inline]
+            pub const fn from_ne_bytes(bytes: [u8; N]) -> Self {
+                let () = Self::ASSERT_WIDTH;
+                Self(bytes)
+            }
+
+            /// Creates an integer value from its representation as a byte array in little endian.
+            #[inline]
+            pub const fn from_le_bytes(bytes: [u8; N]) -> Self {
+                match ::core::cfg!(target_endian = "big") {
+                    true => Self::from_ne_bytes($crate::utils::reverse_bytes(bytes)),
+                    false => Self::from_ne_bytes(bytes),
+                }
+            }
+
+            /// Creates an integer value from its representation as a byte array in big endian.
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; N]) -> Self {
+                match ::core::cfg!(target_endian = "big") {
+                    true => Self::from_ne_bytes(bytes),
+                    false => Self::from_ne_bytes($crate::utils::reverse_bytes(bytes)),
+                }
+            }
+
+            /// Returns the memory representation of this integer as a byte array in native byte order.
+            #[inline]
+            pub const fn to_ne_bytes(self) -> [u8; N] {
+                self.0
+            }
+
+            /// Returns the memory representation of this integer as a byte array in little-endian byte order.
+            #[inline]
+            pub const fn to_le_bytes(self) -> [u8; N] {
+                match ::core::cfg!(target_endian = "big") {
+                    true => $crate::utils::reverse_bytes(self.0),
+                    false => self.0,
+                }
+            }
+
+            /// Returns the memory representation of this integer as a byte array in big-endian byte order.
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; N] {
+                match ::core::cfg!(target_endian = "big") {
+                    true => self.0,
+                    false => $crate::utils::reverse_bytes(self.0),
+                }
+            }
+
+            #[doc = ::core::concat!("Returns the integer value converted to `", ::core::stringify!($wide), "`.")]
+            #[inline]
+            pub const fn $to_wide(self) -> $wide {
+                let () = Self::ASSERT_WIDTH;
+                let is_positive = !$is_signed || (self.0[Self::msb_pos()] & 0x80_u8) == 0x00_u8;
+                let ext = $crate::utils::sign_ext_byte(is_positive);
+                $wide::from_ne_bytes($crate::utils::resize_bytes(self.0, ext))
+            }
+
+            #[doc = ::core::concat!("Creates an integer from the `", ::core::stringify!($wide), "` value if it is within bounds.")]
+            #[inline]
+            pub const fn $checked_from_wide(value: $wide) -> ::core::option::Option<Self> {
+                let result = Self::from_ne_bytes($crate::utils::resize_bytes(value.to_ne_bytes(), 0x00_u8));
+                if result.$to_wide() != value {
+                    return ::core::option::Option::None;
+                }
+                ::core::option::Option::Some(result)
+            }
+
+            /// Returns the [`Ordering`](core::cmp::Ordering) between `self` and `other`.
+            ///
+            /// This is the `const` equivalent of `Ord::cmp`.
+            #[inline]
+            pub const fn const_cmp(self, other: Self) -> ::core::cmp::Ordering {
+                let () = Self::ASSERT_WIDTH;
+                let is_big_endian = ::core::cfg!(target_endian = "big");
+                $crate::utils::cmp_bytes(&self.0, &other.0, $is_signed, is_big_endian)
+            }
+        }
+
+        impl<const N: usize> ::core::default::Default for $name<N> {
+            #[inline]
+            fn default() -> Self {
+                Self::from_ne_bytes([0x00_u8; N])
+            }
+        }
+
+        impl<const N: usize> ::core::cmp::PartialOrd for $name<N> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(<Self as ::core::cmp::Ord>::cmp(self, other))
+            }
+        }
+
+        impl<const N: usize> ::core::cmp::Ord for $name<N> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                Self::const_cmp(*self, *other)
+            }
+        }
+
+        impl<const N: usize> ::core::hash::Hash for $name<N> {
+            #[inline]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                state.write(&self.0)
+            }
+        }
+
+        impl<const N: usize> ::core::convert::From<$name<N>> for $wide {
+            #[inline]
+            fn from(value: $name<N>) -> Self {
+                value.$to_wide()
+            }
+        }
+
+        impl<const N: usize> ::core::convert::TryFrom<$wide> for $name<N> {
+            type Error = $crate::TryFromIntError;
+
+            #[inline]
+            fn try_from(value: $wide) -> ::core::result::Result<Self, Self::Error> {
+                Self::$checked_from_wide(value)
+                    .ok_or_else(|| $crate::error::try_from_int_error!(Self, value < 0 as $wide))
+            }
+        }
+
+        impl<const N: usize> ::core::str::FromStr for $name<N> {
+            type Err = $crate::ParseIntError;
+
+            #[inline]
+            fn from_str(src: &str) -> ::core::result::Result<Self, Self::Err> {
+                let value = src.parse::<$wide>()?;
+                Self::$checked_from_wide(value).ok_or_else(|| {
+                    let kind = match value < 0 as $wide {
+                        true => $crate::IntErrorKind::NegOverflow,
+                        false => $crate::IntErrorKind::PosOverflow,
+                    };
+                    $crate::ParseIntError::new(kind)
+                })
+            }
+        }
+
+        generic_int!(@fmt $name, $to_wide: Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp);
+
+        // SAFETY: `$name<N>` is a `#[repr(transparent)]` wrapper around a byte array
+        //         for which the all-zero bit pattern is a valid value.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<const N: usize> ::bytemuck::Zeroable for $name<N> {}
+
+        // SAFETY: `$name<N>` is a `#[repr(transparent)]` wrapper around a byte array
+        //         which has no padding bytes and for which all bit patterns are valid.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<const N: usize> ::bytemuck::Pod for $name<N> {}
+    };
+    ( @fmt $name:ident, $to_wide:ident: $( $fmt_trait:ident ),* ) => {
+        $(
+            impl<const N: usize> ::core::fmt::$fmt_trait for $name<N> {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::$fmt_trait::fmt(&self.$to_wide(), f)
+                }
+            }
+        )*
+    };
+    ( @prim $name:ident, $wide:ident, $to_wide:ident, $checked_from_wide:ident: $( $prim:ident ),* $(,)? ) => {
+        $(
+            impl<const N: usize> ::core::convert::TryFrom<$prim> for $name<N> {
+                type Error = $crate::TryFromIntError;
+
+                #[inline]
+                fn try_from(value: $prim) -> ::core::result::Result<Self, Self::Error> {
+                    <$wide as ::core::convert::TryFrom<$prim>>::try_from(value)
+                        .ok()
+                        .and_then(Self::$checked_from_wide)
+                        .ok_or_else(|| $crate::error::try_from_int_error!(Self, value < 0 as $prim))
+                }
+            }
+
+            impl<const N: usize> ::core::convert::TryFrom<$name<N>> for $prim {
+                type Error = $crate::TryFromIntError;
+
+                #[inline]
+                fn try_from(value: $name<N>) -> ::core::result::Result<Self, Self::Error> {
+                    let value = value.$to_wide();
+                    <$prim as ::core::convert::TryFrom<$wide>>::try_from(value)
+                        .map_err(|_| $crate::error::try_from_int_error!($prim, value < 0 as $wide))
+                }
+            }
+        )*
+    };
+}
+generic_int!(UInt, u128, to_u128, checked_from_u128, false);
+generic_int!(Int, i128, to_i128, checked_from_i128, true);
+generic_int!(@prim UInt, u128, to_u128, checked_from_u128: u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+generic_int!(@prim Int, i128, to_i128, checked_from_i128: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+
+impl<const N: usize> UInt<N> {
+    /// The smallest value that can be represented by this integer type.
+    pub const MIN: Self = Self::from_ne_bytes([0x00_u8; N]);
+
+    /// The largest value that can be represented by this integer type.
+    pub const MAX: Self = Self::from_ne_bytes([0xFF_u8; N]);
+}
+
+impl<const N: usize> Int<N> {
+    /// The smallest value that can be represented by this integer type.
+    pub const MIN: Self = {
+        let mut bytes = [0x00_u8; N];
+        bytes[Self::msb_pos()] = 0x80_u8;
+        Self::from_ne_bytes(bytes)
+    };
+
+    /// The largest value that can be represented by this integer type.
+    pub const MAX: Self = {
+        let mut bytes = [0xFF_u8; N];
+        bytes[Self::msb_pos()] = 0x7F_u8;
+        Self::from_ne_bytes(bytes)
+    };
+}
+
+/// Implements lossless conversions between the concrete and the const-generic integer types.
+macro_rules! impl_generic_conversions {
+    ( $( impl $name:ident <=> $generic:ident<$num_bytes:literal> );* $(;)? ) => {
+        $(
+            impl ::core::convert::From<$name> for $generic<$num_bytes> {
+                #[inline]
+                fn from(value: $name) -> Self {
+                    Self::from_ne_bytes(value.to_ne_bytes())
+                }
+            }
+
+            impl ::core::convert::From<$generic<$num_bytes>> for $name {
+                #[inline]
+                fn from(value: $generic<$num_bytes>) -> Self {
+                    Self::from_ne_bytes(value.to_ne_bytes())
+                }
+            }
+        )*
+    };
+}
+impl_generic_conversions! {
+    impl U16 <=> UInt<2>;
+    impl I16 <=> Int<2>;
+    impl U24 <=> UInt<3>;
+    impl I24 <=> Int<3>;
+    impl U32 <=> UInt<4>;
+    impl I32 <=> Int<4>;
+    impl U40 <=> UInt<5>;
+    impl I40 <=> Int<5>;
+    impl U48 <=> UInt<6>;
+    impl I48 <=> Int<6>;
+    impl U56 <=> UInt<7>;
+    impl I56 <=> Int<7>;
+    impl U64 <=> UInt<8>;
+    impl I64 <=> Int<8>;
+    impl U72 <=> UInt<9>;
+    impl I72 <=> Int<9>;
+    impl U80 <=> UInt<10>;
+    impl I80 <=> Int<10>;
+    impl U88 <=> UInt<11>;
+    impl I88 <=> Int<11>;
+    impl U96 <=> UInt<12>;
+    impl I96 <=> Int<12>;
+    impl U104 <=> UInt<13>;
+    impl I104 <=> Int<13>;
+    impl U112 <=> UInt<14>;
+    impl I112 <=> Int<14>;
+    impl U120 <=> UInt<15>;
+    impl I120 <=> Int<15>;
+    impl U128 <=> UInt<16>;
+    impl I128 <=> Int<16>;
+}
@@ -0,0 +1,205 @@
+//! Module to define the `std::io` extension traits to read and write unaligned integers.
+//!
+//! This module is only available with the `std` crate feature.
+//!
+//! The extension traits provide named methods such as `read_u24_le` only for the integer types
+//! whose bitwidth is not covered by a Rust primitive. Their names would otherwise collide with
+//! the methods of other extension traits such as `byteorder::ReadBytesExt::read_u32` that read
+//! Rust primitives. Use `read_unaligned` and `write_unaligned` for types such as `U32` instead.
+
+use crate::{
+    BigEndian, ByteOrder, LittleEndian, UnalignedInteger, I104, I112, I120, I24, I40, I48, I56,
+    I72, I80, I88, I96, U104, U112, U120, U24, U40, U48, U56, U72, U80, U88, U96,
+};
+use std::io;
+
+macro_rules! read_methods {
+    ( $( $ty:ident => $read:ident, $read_le:ident, $read_be:ident; )* ) => {
+        $(
+            #[doc = concat!("Reads a [`", stringify!($ty), "`] in the byte order `B`.")]
+            ///
+            /// # Errors
+            ///
+            /// If the underlying reader fails or reaches its end before all bytes are read.
+            #[inline]
+            fn $read<B: ByteOrder>(&mut self) -> io::Result<$ty> {
+                self.read_unaligned::<$ty, B>()
+            }
+
+            #[doc = concat!("Reads a [`", stringify!($ty), "`] in little-endian byte order.")]
+            ///
+            /// # Errors
+            ///
+            /// If the underlying reader fails or reaches its end before all bytes are read.
+            #[inline]
+            fn $read_le(&mut self) -> io::Result<$ty> {
+                self.read_unaligned::<$ty, LittleEndian>()
+            }
+
+            #[doc = concat!("Reads a [`", stringify!($ty), "`] in big-endian byte order.")]
+            ///
+            /// # Errors
+            ///
+            /// If the underlying reader fails or reaches its end before all bytes are read.
+            #[inline]
+            fn $read_be(&mut self) -> io::Result<$ty> {
+                self.read_unaligned::<$ty, BigEndian>()
+            }
+        )*
+    };
+}
+
+macro_rules! write_methods {
+    ( $( $ty:ident => $write:ident, $write_le:ident, $write_be:ident; )* ) => {
+        $(
+            #[doc = concat!("Writes a [`", stringify!($ty), "`] in the byte order `B`.")]
+            ///
+            /// # Errors
+            ///
+            /// If the underlying writer fails.
+            #[inline]
+            fn $write<B: ByteOrder>(&mut self, value: $ty) -> io::Result<()> {
+                self.write_unaligned::<$ty, B>(value)
+            }
+
+            #[doc = concat!("Writes a [`", stringify!($ty), "`] in little-endian byte order.")]
+            ///
+            /// # Errors
+            ///
+            /// If the underlying writer fails.
+            #[inline]
+            fn $write_le(&mut self, value: $ty) -> io::Result<()> {
+                self.write_unaligned::<$ty, LittleEndian>(value)
+            }
+
+            #[doc = concat!("Writes a [`", stringify!($ty), "`] in big-endian byte order.")]
+            ///
+            /// # Errors
+            ///
+            /// If the underlying writer fails.
+            #[inline]
+            fn $write_be(&mut self, value: $ty) -> io::Result<()> {
+                self.write_unaligned::<$ty, BigEndian>(value)
+            }
+        )*
+    };
+}
+
+/// Extension trait for [`io::Read`] to read unaligned integers.
+///
+/// It is implemented for all types that implement [`io::Read`].
+///
+/// # Example
+///
+/// ```
+/// use intx::{BigEndian, ReadIntxExt, I24, U40};
+///
+/// let mut reader: &[u8] = &[0xFF, 0xFF, 0xFF, 0x01, 0x02, 0x03, 0x04, 0x05];
+/// assert_eq!(reader.read_i24_le().unwrap(), I24::from(-1_i8));
+/// assert_eq!(
+///     reader.read_u40::<BigEndian>().unwrap(),
+///     U40::try_from(0x01_0203_0405_u64).unwrap(),
+/// );
+/// assert!(reader.read_u24_le().is_err());
+/// ```
+pub trait ReadIntxExt: io::Read {
+    /// Reads an unaligned integer of type `T` in the byte order `B`.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying reader fails or reaches its end before all bytes are read.
+    #[inline]
+    fn read_unaligned<T, B>(&mut self) -> io::Result<T>
+    where
+        T: UnalignedInteger,
+        B: ByteOrder,
+    {
+        let mut bytes = T::Bytes::default();
+        self.read_exact(bytes.as_mut())?;
+        Ok(B::from_bytes(bytes))
+    }
+
+    read_methods! {
+        U24 => read_u24, read_u24_le, read_u24_be;
+        I24 => read_i24, read_i24_le, read_i24_be;
+        U40 => read_u40, read_u40_le, read_u40_be;
+        I40 => read_i40, read_i40_le, read_i40_be;
+        U48 => read_u48, read_u48_le, read_u48_be;
+        I48 => read_i48, read_i48_le, read_i48_be;
+        U56 => read_u56, read_u56_le, read_u56_be;
+        I56 => read_i56, read_i56_le, read_i56_be;
+        U72 => read_u72, read_u72_le, read_u72_be;
+        I72 => read_i72, read_i72_le, read_i72_be;
+        U80 => read_u80, read_u80_le, read_u80_be;
+        I80 => read_i80, read_i80_le, read_i80_be;
+        U88 => read_u88, read_u88_le, read_u88_be;
+        I88 => read_i88, read_i88_le, read_i88_be;
+        U96 => read_u96, read_u96_le, read_u96_be;
+        I96 => read_i96, read_i96_le, read_i96_be;
+        U104 => read_u104, read_u104_le, read_u104_be;
+        I104 => read_i104, read_i104_le, read_i104_be;
+        U112 => read_u112, read_u112_le, read_u112_be;
+        I112 => read_i112, read_i112_le, read_i112_be;
+        U120 => read_u120, read_u120_le, read_u120_be;
+        I120 => read_i120, read_i120_le, read_i120_be;
+    }
+}
+
+impl<R> ReadIntxExt for R where R: io::Read + ?Sized {}
+
+/// Extension trait for [`io::Write`] to write unaligned integers.
+///
+/// It is implemented for all types that implement [`io::Write`].
+///
+/// # Example
+///
+/// ```
+/// use intx::{LittleEndian, WriteIntxExt, I48, U24};
+///
+/// let mut buffer = Vec::new();
+/// buffer.write_u24_be(U24::new_const(0x01_0203)).unwrap();
+/// buffer.write_i48::<LittleEndian>(I48::from(-2_i8)).unwrap();
+/// assert_eq!(buffer, [0x01, 0x02, 0x03, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+/// ```
+pub trait WriteIntxExt: io::Write {
+    /// Writes the unaligned integer `value` in the byte order `B`.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying writer fails.
+    #[inline]
+    fn write_unaligned<T, B>(&mut self, value: T) -> io::Result<()>
+    where
+        T: UnalignedInteger,
+        B: ByteOrder,
+    {
+        self.write_all(B::to_bytes(value).as_ref())
+    }
+
+    write_methods! {
+        U24 => write_u24, write_u24_le, write_u24_be;
+        I24 => write_i24, write_i24_le, write_i24_be;
+        U40 => write_u40, write_u40_le, write_u40_be;
+        I40 => write_i40, write_i40_le, write_i40_be;
+        U48 => write_u48, write_u48_le, write_u48_be;
+        I48 => write_i48, write_i48_le, write_i48_be;
+        U56 => write_u56, write_u56_le, write_u56_be;
+        I56 => write_i56, write_i56_le, write_i56_be;
+        U72 => write_u72, write_u72_le, write_u72_be;
+        I72 => write_i72, write_i72_le, write_i72_be;
+        U80 => write_u80, write_u80_le, write_u80_be;
+        I80 => write_i80, write_i80_le, write_i80_be;
+        U88 => write_u88, write_u88_le, write_u88_be;
+        I88 => write_i88, write_i88_le, write_i88_be;
+        U96 => write_u96, write_u96_le, write_u96_be;
+        I96 => write_i96, write_i96_le, write_i96_be;
+        U104 => write_u104, write_u104_le, write_u104_be;
+        I104 => write_i104, write_i104_le, write_i104_be;
+        U112 => write_u112, write_u112_le, write_u112_be;
+        I112 => write_i112, write_i112_le, write_i112_be;
+        U120 => write_u120, write_u120_le, write_u120_be;
+        I120 => write_i120, write_i120_le, write_i120_be;
+    }
+}
+
+impl<W> WriteIntxExt for W where W: io::Write + ?Sized {}
@@ -6,9 +6,10 @@
 //! - The alignment of all integer types provided by this crate is always 1. If another
 //!   alignment is required it is recommended to wrap the integer type in a newtype and
 //!   enforce an alignment via `#[align(N)]`.
-//! - As of now the provided integers do not have a rich set of arithmetic methods defined on them.
-//!   It is instead expected to convert them to Rust primitive integers, apply the computation and
-//!   eventually convert the result back. This might be supported in the future if requested.
+//! - The provided integers support the arithmetic operators of `core::ops` with the same overflow
+//!   semantics as Rust primitives. Overflow is always detected at the bitwidth of the integer type.
+//!   For more involved computations it is still possible to convert them to Rust primitive integers,
+//!   apply the computation and eventually convert the result back.
 //! - The binary representation of integer types provided by this crate is in twos-complement just
 //!   like Rust's built-in integer types.
 //!
@@ -29,16 +30,144 @@
 //!   - `Debug`, `Display`, `Binary`, `Octal`, `LowerHex`, `UpperHex`, `LowerExp`, `UpperExp`
 //!     - Integer types mimick the display representation of the next larger Rust built-in integer type.
 //!
+//! - Const-generic `UInt<N>` and `Int<N>` integer types with `N` bytes for `N` from 1 to 16:
+//!
+//!   - They have the same layout as their concrete counterparts, e.g. `UInt<3>` and `U24`, and convert losslessly into them.
+//!   - This allows to write code that is generic over the width of the integer, e.g. `fn read<const N: usize>() -> UInt<N>`.
+//!
+//! - Integer types wider than 128 bits from `U136` and `I136` up to `U256` and `I256`:
+//!
+//!   - They have no `Repr` primitive and instead compute with a software multi-limb representation.
+//!   - They support the arithmetic, comparison and formatting traits as well as `From` and `TryFrom`
+//!     with all other integer types of this crate and Rust primitives.
+//!
+//! - Bit-granular integer types such as `U12`, `U20` or `I7` for all bitwidths from 1 to 63 bits that are not a multiple of 8:
+//!
+//!   - Their values are stored in the smallest Rust primitive, e.g. `u16` for `U12`, and are always within bounds.
+//!   - They support the same arithmetic, parsing and formatting API and convert from and to the byte-width integer types such as `U16` or `U24`.
+//!   - `BitPacked<T, BYTES>` stores them contiguously at the bit level, e.g. `BitPacked<U12, 6>` stores 4 values of `U12` in 6 bytes.
+//!
+//! - Non-zero variants `NonZeroU16`, `NonZeroU32`, `NonZeroU64` and `NonZeroU128` and their signed counterparts:
+//!
+//!   - They provide `new`, `new_unchecked` and `get` as well as `From` and `TryFrom` with their plain and `core::num::NonZero*` counterparts.
+//!   - They have a niche with an alignment of 1 so that `Option<NonZeroU32>` requires 4 bytes.
+//!   - Other widths such as `NonZeroU24` are not provided since stable Rust cannot express their niche.
+//!
+//! - Variants such as `U32Except<VALUE>` for 16, 32, 64 and 128 bits that cannot be the configurable `VALUE`:
+//!
+//!   - They are meant for indices and identifiers that use a sentinel value while zero remains a valid value.
+//!   - Aliases such as `U32Max` or `I64Max` reserve the maximum value of their type.
+//!   - They provide `new`, `new_unchecked`, `get`, the formatting traits and `TryFrom` from all Rust primitive and unaligned integer types.
+//!   - They have a niche with an alignment of 1 so that `Option<U32Max>` requires 4 bytes.
+//!
+//! - Endian-fixed storage variants such as `U24Le`, `U24Be` or `I48Be` for every integer type:
+//!
+//!   - They store their bytes in a fixed byte order so that on-disk formats can be declared as Rust structs.
+//!   - Infallible conversions from and to their native counterparts via `new`, `get` and `From`.
+//!   - Comparisons work directly on the stored bytes without swapping them.
+//!   - Operators as well as `From` and `TryFrom` with Rust primitives are forwarded to their native counterparts.
+//!   - They implement `UnalignedInteger` so that generic code such as `ByteReader` and `PackedVec` accepts them.
+//!   - Conversions to other integer types of this crate, e.g. `U24Le` into `U32`, and the arithmetic methods require `get` first.
+//!
 //! - Endian-aware conversion routines are also implemented:
 //!
 //!   - `from_ne_bytes`, `to_ne_bytes`: Convert from and to native-endian bytes. (always efficient)
 //!   - `from_le_bytes`, `to_le_bytes`: Convert from and to little-endian bytes.
 //!   - `from_be_bytes`, `to_be_bytes`: Convert from and to big-endian bytes.
 //!
+//! - `const fn` API:
+//!
+//!   - All inherent methods are `const fn` which allows to build lookup tables and constants.
+//!   - `to_repr`: Converts to the next larger Rust built-in integer type, e.g. `u32` for `U24`.
+//!   - `from_u32_const` and friends: `const` equivalents of infallible `From` conversions from Rust primitives.
+//!   - `checked_from_u64` and friends: `const` equivalents of fallible `TryFrom` conversions from Rust primitives.
+//!   - `const_eq`, `const_cmp`: `const` equivalents of `PartialEq::eq` and `Ord::cmp`.
+//!   - `new_const`: Creates an integer from its `Repr` primitive and panics during `const` evaluation if out of bounds.
+//!   - `u24!`, `i48!` and friends: Construct integers from literals, e.g. `intx::u24!(0xFF_FFFF)`, rejecting out of bounds literals at compile time.
+//!
+//! - Bit inspection and manipulation methods:
+//!
+//!   - `count_ones`, `count_zeros`, `leading_zeros`, `trailing_zeros`, `leading_ones`, `trailing_ones`
+//!   - `rotate_left`, `rotate_right`, `swap_bytes`, `reverse_bits`
+//!   - `is_power_of_two`, `next_power_of_two`, `checked_next_power_of_two` (unsigned only)
+//!     - All methods respect the bitwidth of the integer type, e.g. `U40::leading_zeros` counts at most 40 bits.
+//!
+//! - Arithmetic operators:
+//!
+//!   - `Add`, `Sub`, `Mul`, `Div`, `Rem` and `Neg` (signed only) as well as their `*Assign` variants.
+//!     - Just like with Rust primitives overflow panics if `debug_assertions` are enabled and wraps otherwise.
+//!   - `checked_*`, `wrapping_*`, `saturating_*` and `overflowing_*` method families known from Rust primitives.
+//!     - Overflow is detected at the bitwidth of the integer type, e.g. 24 bits for `U24`.
+//!   - `BitAnd`, `BitOr`, `BitXor`, `Not`, `Shl` and `Shr` as well as their `*Assign` variants.
+//!     - Bitwise operators work directly on the underlying bytes. Shifts respect the bitwidth and signedness.
+//!
+//! - Generic programming:
+//!
+//!   - The sealed `UnalignedInteger` trait exposes `Repr`, `Bytes`, `BITS`, `BYTES`, `MIN`, `MAX`, `IS_SIGNED`
+//!     and the `Repr` and byte conversions to be generic over all integer types up to 128 bits.
+//!   - The `Signed` and `Unsigned` sub-traits are implemented by signed and unsigned integer types respectively.
+//!
+//! - String parsing:
+//!
+//!   - `from_str_radix` and `FromStr` return a `ParseIntError` whose `IntErrorKind` tells apart
+//!     empty input, invalid digits and values that are out of bounds for the bitwidth of the integer type.
+//!
+//! - Byte cursors without the `std` crate feature:
+//!
+//!   - `ByteReader` and `ByteWriter` read and write any integer type with a `ByteOrder` such as `BigEndian` from and
+//!     into byte slices via `read::<U24>()`, `peek`, `skip` or `write`, failing with an `OutOfBoundsError` at the offset.
+//!
+//! - Optional `std::io` support behind the `std` crate feature:
+//!
+//!   - `ReadIntxExt` and `WriteIntxExt` extend `std::io::Read` and `std::io::Write` with methods such as `read_u24_le`, `write_i48_be` or `read_u40::<BigEndian>()` for every integer type whose bitwidth is not covered by a Rust primitive and `read_unaligned` and `write_unaligned` for all of them.
+//!
+//! - Optional `serde` support behind the `serde` crate feature:
+//!
+//!   - Human-readable formats such as JSON use the numeric value and decimal strings for integers wider than 64 bits.
+//!   - Binary formats such as bincode or postcard use exactly `size_of::<T>()` little-endian bytes.
+//!   - Deserialization rejects values that are out of bounds for the integer type.
+//!
+//! - Optional `num-traits` support behind the `num-traits` crate feature:
+//!
+//!   - All integer types implement `PrimInt` and `NumCast` as well as `Zero`, `One`, `Bounded`, `Num`, `ToPrimitive`, `FromPrimitive`
+//!     and the `Checked*`, `Wrapping*`, `Saturating*` and `Overflowing*` operator traits. Signed types also implement `Signed`
+//!     and unsigned types `Unsigned`.
+//!
+//! - Optional zero-copy casting behind the `bytemuck` and `zerocopy` crate features:
+//!
+//!   - All integer types are `#[repr(transparent)]` byte arrays and implement `bytemuck::Pod`
+//!     as well as `zerocopy::{FromBytes, IntoBytes, Unaligned}` to cast from and to byte slices without `unsafe`.
+//!   - Bit-granular integer types only implement `bytemuck::{Zeroable, NoUninit}` and `zerocopy::IntoBytes`
+//!     since not all bit patterns of their `Repr` primitive are valid values.
+//!
+//! - `as`-equivalent casts between all unaligned integer types and Rust primitives:
+//!
+//!   - `CastFrom::wrapping_from`, `Cast::truncate`: Cast with the exact bit-level semantics of `as`, e.g. `U16::wrapping_from(U24::MAX)`.
+//!   - `CastFrom::saturating_from`, `Cast::saturate`: Cast saturating at `MIN` or `MAX` if out of bounds.
+//!   - `cast_signed`, `cast_unsigned`: Reinterpret the bits as integer of the same bitwidth with opposite signedness, e.g. `U24` to `I24`.
+//!
+//! - Floating-point conversions:
+//!
+//!   - `From` for `f32` and `f64` where lossless, e.g. `f32::from(I24::MIN)` or `f64::from(U48::MAX)`.
+//!   - `to_f32`, `to_f64`: Convert to the nearest floating-point value just like `as` casts.
+//!   - `saturating_from_f32`, `saturating_from_f64`: Convert with the semantics of `as` casts, i.e. truncating, saturating and mapping `NaN` to `0`.
+//!   - `checked_from_f32`, `checked_from_f64`: Convert truncating and return `None` for `NaN`, infinities and out of bounds values.
+//!
+//! - Bulk slice conversions:
+//!
+//!   - `U24::slice_from_u32`, `U24::slice_to_u32` and friends convert whole slices from and to their `Repr` primitives.
+//!     Fallible conversions report the index of the first out of bounds value via `TryFromIntSliceError`.
+//!   - `slice_swap_bytes`, `slice_from_le`, `slice_to_be` and friends convert the endianness of whole slices in place.
+//!   - The growable `PackedVec<T>` is available behind the `alloc` crate feature.
+//!
 //! - Rich `From` and `TryFrom` implementations:
 //!
 //!   - All provided integer types have a very rich set of `From` and `TryFrom` trait implementations
 //!     to efficiently convert between different integer types and Rust built-in integers.
+//!   - Conversions from and to `usize` and `isize` are infallible only if they are lossless
+//!     on all supported pointer widths, namely 16, 32 and 64 bits.
+//!   - Failed conversions return a `TryFromIntError` that implements `Display` and `Error` and reports
+//!     whether the value was too large, too small or negative as well as the bitwidth of the target type.
 //!
 //!
 //! # Example: Packed
@@ -83,24 +212,104 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+mod arith;
+mod bit_int;
+mod bit_int_from;
+mod bit_packed;
+mod bits;
+mod byte_cursor;
+mod byte_order;
+mod cast;
+mod const_from;
 mod defs;
+mod endian;
 mod error;
+mod float;
 mod from;
+mod generic;
+#[cfg(feature = "std")]
+mod io;
+mod limbs;
+mod literal;
+mod non_max;
+mod non_zero;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+mod ops;
+#[cfg(feature = "alloc")]
+mod packed_vec;
+mod parse;
+#[cfg(feature = "serde")]
+mod serde;
+mod size;
+mod slice;
+mod traits;
 mod try_from;
 mod utils;
+mod wide;
+mod wide_from;
 mod within_bounds;
 
 #[cfg(test)]
 mod tests;
 
+pub use self::bit_int::{
+    I1, I10, I11, I12, I13, I14, I15, I17, I18, I19, I2, I20, I21, I22, I23, I25, I26, I27, I28,
+    I29, I3, I30, I31, I33, I34, I35, I36, I37, I38, I39, I4, I41, I42, I43, I44, I45, I46, I47,
+    I49, I5, I50, I51, I52, I53, I54, I55, I57, I58, I59, I6, I60, I61, I62, I63, I7, I9, U1, U10,
+    U11, U12, U13, U14, U15, U17, U18, U19, U2, U20, U21, U22, U23, U25, U26, U27, U28, U29, U3,
+    U30, U31, U33, U34, U35, U36, U37, U38, U39, U4, U41, U42, U43, U44, U45, U46, U47, U49, U5,
+    U50, U51, U52, U53, U54, U55, U57, U58, U59, U6, U60, U61, U62, U63, U7, U9,
+};
+pub use self::bit_packed::{BitPackable, BitPacked};
+pub use self::byte_cursor::{ByteReader, ByteWriter};
+pub use self::byte_order::{
+    BigEndian, ByteOrder, IntegerBytes, LittleEndian, NativeEndian, NetworkEndian,
+};
+pub use self::cast::{Cast, CastFrom};
 pub use self::defs::{
     I104, I112, I120, I128, I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, U104, U112,
     U120, U128, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96,
 };
-pub use self::error::TryFromIntError;
+pub use self::endian::{
+    I104Be, I104Le, I112Be, I112Le, I120Be, I120Le, I128Be, I128Le, I16Be, I16Le, I24Be, I24Le,
+    I32Be, I32Le, I40Be, I40Le, I48Be, I48Le, I56Be, I56Le, I64Be, I64Le, I72Be, I72Le, I80Be,
+    I80Le, I88Be, I88Le, I96Be, I96Le, U104Be, U104Le, U112Be, U112Le, U120Be, U120Le, U128Be,
+    U128Le, U16Be, U16Le, U24Be, U24Le, U32Be, U32Le, U40Be, U40Le, U48Be, U48Le, U56Be, U56Le,
+    U64Be, U64Le, U72Be, U72Le, U80Be, U80Le, U88Be, U88Le, U96Be, U96Le,
+};
+pub use self::error::{
+    IntErrorKind, OutOfBoundsError, ParseIntError, TryFromIntError, TryFromIntErrorKind,
+    TryFromIntSliceError,
+};
+pub use self::generic::{Int, UInt};
+#[cfg(feature = "std")]
+pub use self::io::{ReadIntxExt, WriteIntxExt};
+pub use self::non_max::{
+    I128Except, I128Max, I16Except, I16Max, I32Except, I32Max, I64Except, I64Max, U128Except,
+    U128Max, U16Except, U16Max, U32Except, U32Max, U64Except, U64Max,
+};
+pub use self::non_zero::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroU128, NonZeroU16, NonZeroU32,
+    NonZeroU64,
+};
+#[cfg(feature = "alloc")]
+pub use self::packed_vec::PackedVec;
+pub use self::traits::{Signed, UnalignedInteger, Unsigned};
+pub use self::wide::{
+    I136, I144, I152, I160, I168, I176, I184, I192, I200, I208, I216, I224, I232, I240, I248, I256,
+    U136, U144, U152, U160, U168, U176, U184, U192, U200, U208, U216, U224, U232, U240, U248, U256,
+};
 pub(crate) use self::within_bounds::IsWithinBoundsOf;
 
 /// Trait implemented by Rust integer primitives to communicate their bounds.
+#[allow(dead_code)] // Note: bounds are not queried generically atm
 trait BoundedInteger: Sized {
     /// The minimum value representable by `Self`.
     const MIN: Self;
@@ -119,8 +328,9 @@ macro_rules! impl_bounded_integer_for {
 }
 impl_bounded_integer_for!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
 
-/// Trait implemented by unaligned integers provided by this crate.
-trait UnalignedInteger: Sized {
+/// Trait implemented by Rust integer primitives and the unaligned integers
+/// provided by this crate to generically implement conversions between them.
+trait Integer: Sized {
     /// The smallest integer primitive type that is larger than `Self`.
     ///
     /// # Example
@@ -140,7 +350,7 @@ trait UnalignedInteger: Sized {
 macro_rules! impl_unaligned_uint_for {
     ( $( $ty:ty ),* ) => {
         $(
-            impl $crate::UnalignedInteger for $ty {
+            impl $crate::Integer for $ty {
                 type Repr = Self;
 
                 #[inline]
@@ -156,12 +366,12 @@ impl_unaligned_uint_for!(u8, u16, u32, u64, u128);
 macro_rules! impl_unaligned_int_for {
     ( $( $ty:ty ),* ) => {
         $(
-            impl $crate::UnalignedInteger for $ty {
+            impl $crate::Integer for $ty {
                 type Repr = Self;
 
                 #[inline]
                 fn sign_ext_byte(self) -> u8 {
-                    $crate::utils::sign_ext_byte(self.is_positive())
+                    $crate::utils::sign_ext_byte(!self.is_negative())
                 }
             }
         )*
@@ -0,0 +1,400 @@
+//! Module to implement multi-limb arithmetic for integers wider than 128 bits.
+//!
+//! Integer types wider than 128 bits have no `Repr` primitive. Instead they are
+//! converted to 256-bit two's-complement values made of 4 `u64` limbs stored in
+//! little-endian limb order on which arithmetic is performed. Results are then
+//! truncated back to the bitwidth of the integer type just like with `Repr` primitives.
+
+use crate::IntErrorKind;
+use core::cmp::Ordering;
+
+/// The number of `u64` limbs of a multi-limb integer.
+pub const LIMBS: usize = 4;
+
+/// The number of bits of a multi-limb integer.
+pub const BITS: u32 = LIMBS as u32 * 64;
+
+/// A 256-bit two's-complement integer made of `u64` limbs in little-endian limb order.
+pub type Limbs = [u64; LIMBS];
+
+/// The multi-limb integer with value `0`.
+pub const ZERO: Limbs = [0; LIMBS];
+
+/// Returns the `bytes` in native byte order resized to 256 bits using the `ext` byte.
+#[inline]
+pub const fn from_ne_bytes<const N: usize>(bytes: [u8; N], ext: u8) -> Limbs {
+    let mut le: [u8; 32] = crate::utils::resize_bytes(bytes, ext);
+    if cfg!(target_endian = "big") {
+        le = crate::utils::reverse_bytes(le);
+    }
+    let mut limbs = ZERO;
+    let mut i = 0;
+    while i < LIMBS {
+        let mut limb = [0x00_u8; 8];
+        let mut j = 0;
+        while j < 8 {
+            limb[j] = le[i * 8 + j];
+            j += 1;
+        }
+        limbs[i] = u64::from_le_bytes(limb);
+        i += 1;
+    }
+    limbs
+}
+
+/// Returns the `limbs` as bytes in native byte order truncated to `N` bytes.
+#[inline]
+pub const fn to_ne_bytes<const N: usize>(limbs: Limbs) -> [u8; N] {
+    let mut le = [0x00_u8; 32];
+    let mut i = 0;
+    while i < LIMBS {
+        let limb = limbs[i].to_le_bytes();
+        let mut j = 0;
+        while j < 8 {
+            le[i * 8 + j] = limb[j];
+            j += 1;
+        }
+        i += 1;
+    }
+    if cfg!(target_endian = "big") {
+        le = crate::utils::reverse_bytes(le);
+    }
+    crate::utils::resize_bytes(le, 0x00_u8)
+}
+
+/// Returns the multi-limb integer for the `u128` `value`.
+#[inline]
+pub const fn from_u128(value: u128) -> Limbs {
+    [value as u64, (value >> 64) as u64, 0, 0]
+}
+
+/// Returns the multi-limb integer for the `i128` `value`.
+#[inline]
+pub const fn from_i128(value: i128) -> Limbs {
+    let ext = match value < 0 {
+        true => u64::MAX,
+        false => 0,
+    };
+    [value as u64, (value >> 64) as u64, ext, ext]
+}
+
+/// Returns the `limbs` as `u128` if they represent a non-negative value within bounds.
+#[inline]
+pub const fn to_u128(limbs: Limbs) -> Option<u128> {
+    if limbs[2] != 0 || limbs[3] != 0 {
+        return None;
+    }
+    Some((limbs[0] as u128) | ((limbs[1] as u128) << 64))
+}
+
+/// Returns the `limbs` as `i128` if they represent a signed value within bounds.
+#[inline]
+pub const fn to_i128(limbs: Limbs) -> Option<i128> {
+    let value = ((limbs[0] as u128) | ((limbs[1] as u128) << 64)) as i128;
+    if from_i128(value)[2] != limbs[2] || from_i128(value)[3] != limbs[3] {
+        return None;
+    }
+    Some(value)
+}
+
+/// Returns `true` if `lhs` and `rhs` are equal.
+#[inline]
+pub const fn eq(lhs: Limbs, rhs: Limbs) -> bool {
+    let mut i = 0;
+    while i < LIMBS {
+        if lhs[i] != rhs[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the unsigned [`Ordering`] between `lhs` and `rhs`.
+#[inline]
+pub const fn cmp(lhs: Limbs, rhs: Limbs) -> Ordering {
+    let mut i = LIMBS;
+    while i > 0 {
+        i -= 1;
+        if lhs[i] < rhs[i] {
+            return Ordering::Less;
+        }
+        if lhs[i] > rhs[i] {
+            return Ordering::Greater;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Returns `true` if the most significant bit of `limbs` is set.
+#[inline]
+pub const fn is_negative(limbs: Limbs) -> bool {
+    (limbs[LIMBS - 1] >> 63) != 0
+}
+
+/// Returns the sum of `lhs` and `rhs` and whether an unsigned overflow occurred.
+#[inline]
+pub const fn overflowing_add(lhs: Limbs, rhs: Limbs) -> (Limbs, bool) {
+    let mut result = ZERO;
+    let mut carry = false;
+    let mut i = 0;
+    while i < LIMBS {
+        let (sum, c0) = lhs[i].overflowing_add(rhs[i]);
+        let (sum, c1) = sum.overflowing_add(carry as u64);
+        result[i] = sum;
+        carry = c0 || c1;
+        i += 1;
+    }
+    (result, carry)
+}
+
+/// Returns the difference of `lhs` and `rhs` and whether an unsigned overflow occurred.
+#[inline]
+pub const fn overflowing_sub(lhs: Limbs, rhs: Limbs) -> (Limbs, bool) {
+    let mut result = ZERO;
+    let mut borrow = false;
+    let mut i = 0;
+    while i < LIMBS {
+        let (diff, b0) = lhs[i].overflowing_sub(rhs[i]);
+        let (diff, b1) = diff.overflowing_sub(borrow as u64);
+        result[i] = diff;
+        borrow = b0 || b1;
+        i += 1;
+    }
+    (result, borrow)
+}
+
+/// Returns the two's-complement negation of `limbs`.
+#[inline]
+pub const fn neg(limbs: Limbs) -> Limbs {
+    overflowing_sub(ZERO, limbs).0
+}
+
+/// Returns the absolute value of `limbs` interpreted as signed integer.
+///
+/// # Note
+///
+/// The result is interpreted as unsigned integer so that the absolute
+/// value of the smallest signed value is representable as well.
+#[inline]
+pub const fn unsigned_abs(limbs: Limbs) -> Limbs {
+    match is_negative(limbs) {
+        true => neg(limbs),
+        false => limbs,
+    }
+}
+
+/// Returns the product of `lhs` and `rhs` and whether an unsigned overflow occurred.
+#[inline]
+pub const fn overflowing_mul(lhs: Limbs, rhs: Limbs) -> (Limbs, bool) {
+    let mut result = ZERO;
+    let mut overflow = false;
+    let mut i = 0;
+    while i < LIMBS {
+        let mut carry = 0_u128;
+        let mut j = 0;
+        while j < LIMBS {
+            let product = (lhs[i] as u128) * (rhs[j] as u128) + carry;
+            if i + j < LIMBS {
+                let sum = (result[i + j] as u128) + (product as u64 as u128);
+                result[i + j] = sum as u64;
+                carry = (product >> 64) + (sum >> 64);
+            } else {
+                overflow |= product != 0;
+                carry = 0;
+            }
+            j += 1;
+        }
+        overflow |= carry != 0;
+        i += 1;
+    }
+    (result, overflow)
+}
+
+/// Returns `limbs` shifted to the left by `n` bits filling up with zeros.
+///
+/// # Note
+///
+/// The shift amount `n` must be smaller than 256.
+#[inline]
+pub const fn shl(limbs: Limbs, n: u32) -> Limbs {
+    debug_assert!(n < BITS);
+    let limb_shift = (n / 64) as usize;
+    let bit_shift = n % 64;
+    let mut result = ZERO;
+    let mut i = LIMBS;
+    while i > limb_shift {
+        i -= 1;
+        let src = i - limb_shift;
+        result[i] = limbs[src] << bit_shift;
+        if bit_shift != 0 && src > 0 {
+            result[i] |= limbs[src - 1] >> (64 - bit_shift);
+        }
+    }
+    result
+}
+
+/// Returns `limbs` shifted to the right by `n` bits filling up with zeros.
+///
+/// # Note
+///
+/// The shift amount `n` must be smaller than 256.
+#[inline]
+pub const fn shr(limbs: Limbs, n: u32) -> Limbs {
+    debug_assert!(n < BITS);
+    let limb_shift = (n / 64) as usize;
+    let bit_shift = n % 64;
+    let mut result = ZERO;
+    let mut i = 0;
+    while i + limb_shift < LIMBS {
+        let src = i + limb_shift;
+        result[i] = limbs[src] >> bit_shift;
+        if bit_shift != 0 && src + 1 < LIMBS {
+            result[i] |= limbs[src + 1] << (64 - bit_shift);
+        }
+        i += 1;
+    }
+    result
+}
+
+/// Returns `limbs` shifted to the right by `n` bits filling up with the sign bit.
+///
+/// # Note
+///
+/// The shift amount `n` must be smaller than 256.
+#[inline]
+pub const fn sar(limbs: Limbs, n: u32) -> Limbs {
+    match is_negative(limbs) {
+        true => not(shr(not(limbs), n)),
+        false => shr(limbs, n),
+    }
+}
+
+/// Returns the bitwise complement of `limbs`.
+#[inline]
+const fn not(limbs: Limbs) -> Limbs {
+    let mut result = ZERO;
+    let mut i = 0;
+    while i < LIMBS {
+        result[i] = !limbs[i];
+        i += 1;
+    }
+    result
+}
+
+/// Returns the quotient and remainder of the unsigned division of `lhs` by `rhs`.
+///
+/// # Note
+///
+/// The divisor `rhs` must not be zero.
+#[inline]
+pub const fn div_rem(lhs: Limbs, rhs: Limbs) -> (Limbs, Limbs) {
+    debug_assert!(!eq(rhs, ZERO));
+    let mut quotient = ZERO;
+    let mut remainder = ZERO;
+    let mut i = BITS;
+    while i > 0 {
+        i -= 1;
+        remainder = shl(remainder, 1);
+        remainder[0] |= (lhs[(i / 64) as usize] >> (i % 64)) & 1;
+        if !matches!(cmp(remainder, rhs), Ordering::Less) {
+            remainder = overflowing_sub(remainder, rhs).0;
+            quotient[(i / 64) as usize] |= 1 << (i % 64);
+        }
+    }
+    (quotient, remainder)
+}
+
+/// Returns the quotient and remainder of dividing `limbs` by the small `divisor`.
+#[inline]
+const fn div_rem_small(limbs: Limbs, divisor: u64) -> (Limbs, u64) {
+    let mut quotient = ZERO;
+    let mut remainder = 0_u128;
+    let mut i = LIMBS;
+    while i > 0 {
+        i -= 1;
+        let dividend = (remainder << 64) | (limbs[i] as u128);
+        quotient[i] = (dividend / divisor as u128) as u64;
+        remainder = dividend % divisor as u128;
+    }
+    (quotient, remainder as u64)
+}
+
+/// The maximum number of digits of a multi-limb integer, reached in binary.
+pub const MAX_DIGITS: usize = BITS as usize;
+
+/// Writes the unsigned `limbs` in the given `radix` into the end of `buffer`.
+///
+/// Returns the index of the first written digit.
+#[inline]
+pub fn write_digits(limbs: Limbs, radix: u64, upper: bool, buffer: &mut [u8; MAX_DIGITS]) -> usize {
+    let digits = match upper {
+        true => b"0123456789ABCDEF",
+        false => b"0123456789abcdef",
+    };
+    let mut value = limbs;
+    let mut pos = MAX_DIGITS;
+    loop {
+        let (quotient, digit) = div_rem_small(value, radix);
+        pos -= 1;
+        buffer[pos] = digits[digit as usize];
+        value = quotient;
+        if eq(value, ZERO) {
+            return pos;
+        }
+    }
+}
+
+/// Parses the digits of `src` in the given `radix` into a multi-limb integer.
+///
+/// Returns the parsed magnitude and whether the input was prefixed with `-`.
+/// The magnitude is guaranteed to fit into 256 bits.
+///
+/// # Errors
+///
+/// - If `src` is empty or contains invalid digits.
+/// - If `src` is prefixed with `-` but `is_signed` is `false`.
+/// - If the magnitude of the parsed value does not fit into 256 bits.
+///
+/// # Panics
+///
+/// If `radix` is not in the range from 2 to 36.
+#[inline]
+pub const fn parse(src: &str, radix: u32, is_signed: bool) -> Result<(Limbs, bool), IntErrorKind> {
+    assert!(
+        2 <= radix && radix <= 36,
+        "from_str_radix_int: must lie in the range `[2, 36]`"
+    );
+    let bytes = src.as_bytes();
+    if bytes.is_empty() {
+        return Err(IntErrorKind::Empty);
+    }
+    let (is_negative, start) = match bytes[0] {
+        b'-' if is_signed => (true, 1),
+        b'+' => (false, 1),
+        _ => (false, 0),
+    };
+    if start == bytes.len() {
+        return Err(IntErrorKind::InvalidDigit);
+    }
+    let overflow_kind = match is_negative {
+        true => IntErrorKind::NegOverflow,
+        false => IntErrorKind::PosOverflow,
+    };
+    let mut magnitude = ZERO;
+    let mut i = start;
+    while i < bytes.len() {
+        let digit = match (bytes[i] as char).to_digit(radix) {
+            Some(digit) => digit,
+            None => return Err(IntErrorKind::InvalidDigit),
+        };
+        let (shifted, mul_overflow) = overflowing_mul(magnitude, from_u128(radix as u128));
+        let (sum, add_overflow) = overflowing_add(shifted, from_u128(digit as u128));
+        if mul_overflow || add_overflow {
+            return Err(overflow_kind);
+        }
+        magnitude = sum;
+        i += 1;
+    }
+    Ok((magnitude, is_negative))
+}
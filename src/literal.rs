@@ -0,0 +1,390 @@
+//! Module to define macros to construct unaligned integers from literals.
+//!
+//! The value is checked to be within bounds during `const` evaluation
+//! so that out of bounds literals result in a compilation error.
+
+/// Creates a [`U16`](crate::U16) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U16`](crate::U16).
+#[macro_export]
+macro_rules! u16 {
+    ( $value:expr ) => {
+        const { $crate::U16::new_const($value) }
+    };
+}
+
+/// Creates a [`I16`](crate::I16) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I16`](crate::I16).
+#[macro_export]
+macro_rules! i16 {
+    ( $value:expr ) => {
+        const { $crate::I16::new_const($value) }
+    };
+}
+
+/// Creates a [`U24`](crate::U24) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U24`](crate::U24).
+///
+/// # Example
+///
+/// ```
+/// const OPCODE: intx::U24 = intx::u24!(0xFF_FFFF);
+/// assert_eq!(OPCODE, intx::U24::MAX);
+/// ```
+///
+/// Out of bounds literals are rejected at compile time:
+///
+/// ```compile_fail
+/// let opcode = intx::u24!(0x100_0000);
+/// ```
+#[macro_export]
+macro_rules! u24 {
+    ( $value:expr ) => {
+        const { $crate::U24::new_const($value) }
+    };
+}
+
+/// Creates a [`I24`](crate::I24) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I24`](crate::I24).
+#[macro_export]
+macro_rules! i24 {
+    ( $value:expr ) => {
+        const { $crate::I24::new_const($value) }
+    };
+}
+
+/// Creates a [`U32`](crate::U32) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U32`](crate::U32).
+#[macro_export]
+macro_rules! u32 {
+    ( $value:expr ) => {
+        const { $crate::U32::new_const($value) }
+    };
+}
+
+/// Creates a [`I32`](crate::I32) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I32`](crate::I32).
+#[macro_export]
+macro_rules! i32 {
+    ( $value:expr ) => {
+        const { $crate::I32::new_const($value) }
+    };
+}
+
+/// Creates a [`U40`](crate::U40) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U40`](crate::U40).
+#[macro_export]
+macro_rules! u40 {
+    ( $value:expr ) => {
+        const { $crate::U40::new_const($value) }
+    };
+}
+
+/// Creates a [`I40`](crate::I40) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I40`](crate::I40).
+#[macro_export]
+macro_rules! i40 {
+    ( $value:expr ) => {
+        const { $crate::I40::new_const($value) }
+    };
+}
+
+/// Creates a [`U48`](crate::U48) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U48`](crate::U48).
+#[macro_export]
+macro_rules! u48 {
+    ( $value:expr ) => {
+        const { $crate::U48::new_const($value) }
+    };
+}
+
+/// Creates a [`I48`](crate::I48) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I48`](crate::I48).
+///
+/// # Example
+///
+/// ```
+/// let value = intx::i48!(-5);
+/// assert_eq!(i64::from(value), -5);
+/// ```
+///
+/// Out of bounds literals are rejected at compile time:
+///
+/// ```compile_fail
+/// let value = intx::i48!(-0x8000_0000_0001);
+/// ```
+#[macro_export]
+macro_rules! i48 {
+    ( $value:expr ) => {
+        const { $crate::I48::new_const($value) }
+    };
+}
+
+/// Creates a [`U56`](crate::U56) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U56`](crate::U56).
+#[macro_export]
+macro_rules! u56 {
+    ( $value:expr ) => {
+        const { $crate::U56::new_const($value) }
+    };
+}
+
+/// Creates a [`I56`](crate::I56) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I56`](crate::I56).
+#[macro_export]
+macro_rules! i56 {
+    ( $value:expr ) => {
+        const { $crate::I56::new_const($value) }
+    };
+}
+
+/// Creates a [`U64`](crate::U64) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U64`](crate::U64).
+#[macro_export]
+macro_rules! u64 {
+    ( $value:expr ) => {
+        const { $crate::U64::new_const($value) }
+    };
+}
+
+/// Creates a [`I64`](crate::I64) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I64`](crate::I64).
+#[macro_export]
+macro_rules! i64 {
+    ( $value:expr ) => {
+        const { $crate::I64::new_const($value) }
+    };
+}
+
+/// Creates a [`U72`](crate::U72) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U72`](crate::U72).
+#[macro_export]
+macro_rules! u72 {
+    ( $value:expr ) => {
+        const { $crate::U72::new_const($value) }
+    };
+}
+
+/// Creates a [`I72`](crate::I72) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I72`](crate::I72).
+#[macro_export]
+macro_rules! i72 {
+    ( $value:expr ) => {
+        const { $crate::I72::new_const($value) }
+    };
+}
+
+/// Creates a [`U80`](crate::U80) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U80`](crate::U80).
+#[macro_export]
+macro_rules! u80 {
+    ( $value:expr ) => {
+        const { $crate::U80::new_const($value) }
+    };
+}
+
+/// Creates a [`I80`](crate::I80) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I80`](crate::I80).
+#[macro_export]
+macro_rules! i80 {
+    ( $value:expr ) => {
+        const { $crate::I80::new_const($value) }
+    };
+}
+
+/// Creates a [`U88`](crate::U88) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U88`](crate::U88).
+#[macro_export]
+macro_rules! u88 {
+    ( $value:expr ) => {
+        const { $crate::U88::new_const($value) }
+    };
+}
+
+/// Creates a [`I88`](crate::I88) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I88`](crate::I88).
+#[macro_export]
+macro_rules! i88 {
+    ( $value:expr ) => {
+        const { $crate::I88::new_const($value) }
+    };
+}
+
+/// Creates a [`U96`](crate::U96) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U96`](crate::U96).
+#[macro_export]
+macro_rules! u96 {
+    ( $value:expr ) => {
+        const { $crate::U96::new_const($value) }
+    };
+}
+
+/// Creates a [`I96`](crate::I96) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I96`](crate::I96).
+#[macro_export]
+macro_rules! i96 {
+    ( $value:expr ) => {
+        const { $crate::I96::new_const($value) }
+    };
+}
+
+/// Creates a [`U104`](crate::U104) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U104`](crate::U104).
+#[macro_export]
+macro_rules! u104 {
+    ( $value:expr ) => {
+        const { $crate::U104::new_const($value) }
+    };
+}
+
+/// Creates a [`I104`](crate::I104) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I104`](crate::I104).
+#[macro_export]
+macro_rules! i104 {
+    ( $value:expr ) => {
+        const { $crate::I104::new_const($value) }
+    };
+}
+
+/// Creates a [`U112`](crate::U112) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U112`](crate::U112).
+#[macro_export]
+macro_rules! u112 {
+    ( $value:expr ) => {
+        const { $crate::U112::new_const($value) }
+    };
+}
+
+/// Creates a [`I112`](crate::I112) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I112`](crate::I112).
+#[macro_export]
+macro_rules! i112 {
+    ( $value:expr ) => {
+        const { $crate::I112::new_const($value) }
+    };
+}
+
+/// Creates a [`U120`](crate::U120) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U120`](crate::U120).
+#[macro_export]
+macro_rules! u120 {
+    ( $value:expr ) => {
+        const { $crate::U120::new_const($value) }
+    };
+}
+
+/// Creates a [`I120`](crate::I120) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I120`](crate::I120).
+#[macro_export]
+macro_rules! i120 {
+    ( $value:expr ) => {
+        const { $crate::I120::new_const($value) }
+    };
+}
+
+/// Creates a [`U128`](crate::U128) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`U128`](crate::U128).
+#[macro_export]
+macro_rules! u128 {
+    ( $value:expr ) => {
+        const { $crate::U128::new_const($value) }
+    };
+}
+
+/// Creates a [`I128`](crate::I128) from an integer literal checked at compile time.
+///
+/// # Errors
+///
+/// Fails to compile if the literal is out of bounds for [`I128`](crate::I128).
+#[macro_export]
+macro_rules! i128 {
+    ( $value:expr ) => {
+        const { $crate::I128::new_const($value) }
+    };
+}
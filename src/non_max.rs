@@ -0,0 +1,316 @@
+//! Module to define the variants of the unaligned integer types that cannot be a single reserved value
+//! such as `U32Except<VALUE>` and their aliases that reserve the maximum value such as `U32Max`.
+//!
+//! This is useful for indices and identifiers that use the maximum value or another value
+//! as sentinel since zero remains a valid value.
+//!
+//! The types store their value XOR the reserved value in the respective `core::num::NonZero*`
+//! primitive wrapped in a `#[repr(packed)]` struct which preserves its niche.
+//! Therefore `Option<U32Max>` has the same size as `U32Max`.
+//!
+//! Only the bitwidths of 16, 32, 64 and 128 bits are provided for the same reason why
+//! there are no non-zero integer types such as `NonZeroU24`.
+
+use crate::{
+    I104, I112, I120, I128, I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, U104, U112,
+    U120, U128, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96,
+};
+
+macro_rules! non_max_int {
+    (
+        $(
+            $( #[$docs:meta] )*
+            @[$core:ident]
+            $vis:vis struct $name:ident<const VALUE: $repr:ident>($inner:ident);
+
+            $( #[$alias_docs:meta] )*
+            $alias_vis:vis type $alias:ident;
+        )*
+    ) => {
+        $(
+            $( #[$docs] )*
+            #[derive(::core::marker::Copy, ::core::clone::Clone)]
+            #[repr(C, packed)]
+            $vis struct $name<const VALUE: ::core::primitive::$repr>(::core::num::$core);
+
+            $( #[$alias_docs] )*
+            $alias_vis type $alias = $name<{ ::core::primitive::$repr::MAX }>;
+
+            impl<const VALUE: ::core::primitive::$repr> $name<VALUE> {
+                /// The amount of bits required by this integer type.
+                pub const BITS: ::core::primitive::u32 = <$inner>::BITS;
+
+                /// The smallest value that can be represented by this integer type.
+                // SAFETY: the value is the minimum value of the integer type or one more
+                //         if the minimum value is the reserved value.
+                pub const MIN: Self = unsafe {
+                    Self::new_unchecked(<$inner>::from_repr_truncating(
+                        match VALUE == <$inner>::MIN.to_repr() {
+                            true => VALUE + 1,
+                            false => <$inner>::MIN.to_repr(),
+                        },
+                    ))
+                };
+
+                /// The largest value that can be represented by this integer type.
+                // SAFETY: the value is the maximum value of the integer type or one less
+                //         if the maximum value is the reserved value.
+                pub const MAX: Self = unsafe {
+                    Self::new_unchecked(<$inner>::from_repr_truncating(
+                        match VALUE == <$inner>::MAX.to_repr() {
+                            true => VALUE - 1,
+                            false => <$inner>::MAX.to_repr(),
+                        },
+                    ))
+                };
+
+                /// The value that cannot be represented by this integer type.
+                pub const RESERVED: $inner = <$inner>::from_repr_truncating(VALUE);
+
+                /// Creates an integer if `value` is not the reserved value of its type.
+                #[inline]
+                pub const fn new(value: $inner) -> ::core::option::Option<Self> {
+                    if value.const_eq(Self::RESERVED) {
+                        return ::core::option::Option::None;
+                    }
+                    // SAFETY: `value` has just been checked to not be the reserved value.
+                    ::core::option::Option::Some(unsafe { Self::new_unchecked(value) })
+                }
+
+                /// Creates an integer without checking whether `value` is the reserved value of its type.
+                ///
+                /// # Safety
+                ///
+                /// The `value` must not be the reserved value of its type.
+                #[inline]
+                pub const unsafe fn new_unchecked(value: $inner) -> Self {
+                    // SAFETY: the caller guarantees that `value` is not the reserved value
+                    //         and therefore `value ^ VALUE` is not zero.
+                    Self(unsafe { ::core::num::$core::new_unchecked(value.to_repr() ^ VALUE) })
+                }
+
+                /// Returns the contained value as an unaligned integer.
+                #[inline]
+                pub const fn get(self) -> $inner {
+                    let bits = self.0;
+                    <$inner>::from_repr_truncating(bits.get() ^ VALUE)
+                }
+
+                /// Returns the error of a conversion of the reserved value into this integer type.
+                ///
+                /// If the reserved value is zero or a bound of the integer type the error
+                /// reports the same kind as for the respective non-zero or smaller integer type.
+                #[cold]
+                const fn reserved_error() -> $crate::TryFromIntError {
+                    let kind = match VALUE {
+                        0 => $crate::TryFromIntErrorKind::Zero,
+                        _ if VALUE == <$inner>::MAX.to_repr() => $crate::TryFromIntErrorKind::PosOverflow,
+                        _ if VALUE == <$inner>::MIN.to_repr() => $crate::TryFromIntErrorKind::NegOverflow,
+                        _ => $crate::TryFromIntErrorKind::Reserved,
+                    };
+                    $crate::TryFromIntError::new(kind, Self::BITS)
+                }
+            }
+
+            impl ::core::default::Default for $alias {
+                #[inline]
+                fn default() -> Self {
+                    // SAFETY: zero is never the maximum value of an integer type.
+                    unsafe { Self::new_unchecked(<$inner as ::core::default::Default>::default()) }
+                }
+            }
+
+            impl<const VALUE: ::core::primitive::$repr> ::core::convert::From<$name<VALUE>> for $inner {
+                #[inline]
+                fn from(value: $name<VALUE>) -> Self {
+                    value.get()
+                }
+            }
+
+            impl<const VALUE: ::core::primitive::$repr> ::core::cmp::PartialEq for $name<VALUE> {
+                #[inline]
+                fn eq(&self, other: &Self) -> ::core::primitive::bool {
+                    self.get() == other.get()
+                }
+            }
+
+            impl<const VALUE: ::core::primitive::$repr> ::core::cmp::Eq for $name<VALUE> {}
+
+            impl<const VALUE: ::core::primitive::$repr> ::core::cmp::PartialOrd for $name<VALUE> {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(<Self as ::core::cmp::Ord>::cmp(self, other))
+                }
+            }
+
+            impl<const VALUE: ::core::primitive::$repr> ::core::cmp::Ord for $name<VALUE> {
+                #[inline]
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    <$inner as ::core::cmp::Ord>::cmp(&self.get(), &other.get())
+                }
+            }
+
+            impl<const VALUE: ::core::primitive::$repr> ::core::hash::Hash for $name<VALUE> {
+                #[inline]
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    <$inner as ::core::hash::Hash>::hash(&self.get(), state)
+                }
+            }
+
+            non_max_int!(@try_from $name<$repr>($inner):
+                u8 => u128,
+                i8 => i128,
+                u16 => u128,
+                i16 => i128,
+                u32 => u128,
+                i32 => i128,
+                u64 => u128,
+                i64 => i128,
+                u128 => u128,
+                i128 => i128,
+                U16 => u128,
+                I16 => i128,
+                U24 => u128,
+                I24 => i128,
+                U32 => u128,
+                I32 => i128,
+                U40 => u128,
+                I40 => i128,
+                U48 => u128,
+                I48 => i128,
+                U56 => u128,
+                I56 => i128,
+                U64 => u128,
+                I64 => i128,
+                U72 => u128,
+                I72 => i128,
+                U80 => u128,
+                I80 => i128,
+                U88 => u128,
+                I88 => i128,
+                U96 => u128,
+                I96 => i128,
+                U104 => u128,
+                I104 => i128,
+                U112 => u128,
+                I112 => i128,
+                U120 => u128,
+                I120 => i128,
+                U128 => u128,
+                I128 => i128
+            );
+            non_max_int!(@fmt $name<$repr>: Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp);
+        )*
+    };
+    ( @try_from $name:ident<$repr:ident>($inner:ident): $( $from:ty => $wide:ident ),* ) => {
+        $(
+            impl<const VALUE: ::core::primitive::$repr> ::core::convert::TryFrom<$from> for $name<VALUE> {
+                type Error = $crate::TryFromIntError;
+
+                #[inline]
+                fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
+                    // Note: not all integer types convert into each other directly
+                    //       so the conversion goes through the widest primitive.
+                    match non_max_int!(@checked_from $inner, $wide, value) {
+                        ::core::option::Option::Some(value) => Self::new(value).ok_or_else(Self::reserved_error),
+                        ::core::option::Option::None => ::core::result::Result::Err(
+                            $crate::error::try_from_int_error!(
+                                $inner,
+                                <$from as $crate::Integer>::sign_ext_byte(value) != 0x00_u8
+                            ),
+                        ),
+                    }
+                }
+            }
+        )*
+    };
+    ( @checked_from $inner:ident, u128, $value:ident ) => {
+        <$inner>::checked_from_u128(<::core::primitive::u128 as ::core::convert::From<_>>::from($value))
+    };
+    ( @checked_from $inner:ident, i128, $value:ident ) => {
+        <$inner>::checked_from_i128(<::core::primitive::i128 as ::core::convert::From<_>>::from($value))
+    };
+    ( @fmt $name:ident<$repr:ident>: $( $fmt_trait:ident ),* ) => {
+        $(
+            impl<const VALUE: ::core::primitive::$repr> ::core::fmt::$fmt_trait for $name<VALUE> {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::$fmt_trait::fmt(&self.get(), f)
+                }
+            }
+        )*
+    };
+}
+non_max_int! {
+    /// 16-bit unsigned integer with alignment of 1 that cannot be `VALUE`.
+    ///
+    /// `Option<U16Except<VALUE>>` has the same size as `U16Except<VALUE>`.
+    @[NonZeroU16]
+    pub struct U16Except<const VALUE: u16>(U16);
+
+    /// 16-bit unsigned integer with alignment of 1 that cannot be `U16::MAX`.
+    pub type U16Max;
+
+    /// 16-bit signed integer with alignment of 1 that cannot be `VALUE`.
+    ///
+    /// `Option<I16Except<VALUE>>` has the same size as `I16Except<VALUE>`.
+    @[NonZeroI16]
+    pub struct I16Except<const VALUE: i16>(I16);
+
+    /// 16-bit signed integer with alignment of 1 that cannot be `I16::MAX`.
+    pub type I16Max;
+
+    /// 32-bit unsigned integer with alignment of 1 that cannot be `VALUE`.
+    ///
+    /// `Option<U32Except<VALUE>>` has the same size as `U32Except<VALUE>`.
+    @[NonZeroU32]
+    pub struct U32Except<const VALUE: u32>(U32);
+
+    /// 32-bit unsigned integer with alignment of 1 that cannot be `U32::MAX`.
+    pub type U32Max;
+
+    /// 32-bit signed integer with alignment of 1 that cannot be `VALUE`.
+    ///
+    /// `Option<I32Except<VALUE>>` has the same size as `I32Except<VALUE>`.
+    @[NonZeroI32]
+    pub struct I32Except<const VALUE: i32>(I32);
+
+    /// 32-bit signed integer with alignment of 1 that cannot be `I32::MAX`.
+    pub type I32Max;
+
+    /// 64-bit unsigned integer with alignment of 1 that cannot be `VALUE`.
+    ///
+    /// `Option<U64Except<VALUE>>` has the same size as `U64Except<VALUE>`.
+    @[NonZeroU64]
+    pub struct U64Except<const VALUE: u64>(U64);
+
+    /// 64-bit unsigned integer with alignment of 1 that cannot be `U64::MAX`.
+    pub type U64Max;
+
+    /// 64-bit signed integer with alignment of 1 that cannot be `VALUE`.
+    ///
+    /// `Option<I64Except<VALUE>>` has the same size as `I64Except<VALUE>`.
+    @[NonZeroI64]
+    pub struct I64Except<const VALUE: i64>(I64);
+
+    /// 64-bit signed integer with alignment of 1 that cannot be `I64::MAX`.
+    pub type I64Max;
+
+    /// 128-bit unsigned integer with alignment of 1 that cannot be `VALUE`.
+    ///
+    /// `Option<U128Except<VALUE>>` has the same size as `U128Except<VALUE>`.
+    @[NonZeroU128]
+    pub struct U128Except<const VALUE: u128>(U128);
+
+    /// 128-bit unsigned integer with alignment of 1 that cannot be `U128::MAX`.
+    pub type U128Max;
+
+    /// 128-bit signed integer with alignment of 1 that cannot be `VALUE`.
+    ///
+    /// `Option<I128Except<VALUE>>` has the same size as `I128Except<VALUE>`.
+    @[NonZeroI128]
+    pub struct I128Except<const VALUE: i128>(I128);
+
+    /// 128-bit signed integer with alignment of 1 that cannot be `I128::MAX`.
+    pub type I128Max;
+}
@@ -0,0 +1,191 @@
+//! Module to define the non-zero variants of the unaligned integer types such as `NonZeroU32`.
+//!
+//! The non-zero integer types wrap the respective `core::num::NonZero*` primitive in a
+//! `#[repr(packed)]` struct which preserves its niche. Therefore `Option<NonZeroU32>`
+//! has the same size as `NonZeroU32`.
+//!
+//! Only the bitwidths of 16, 32, 64 and 128 bits are provided. Stable Rust only supports
+//! niches that exclude values of a single integer primitive field. With an alignment of 1
+//! such a field of a 24-bit type would have to be a `u8` or a `u16` which would exclude far
+//! more than just the zero value of all 24 bits, so `Option<NonZeroU24>` could not be 3 bytes.
+
+use crate::{I128, I16, I32, I64, U128, U16, U32, U64};
+
+macro_rules! non_zero_int {
+    (
+        $(
+            $( #[$docs:meta] )*
+            @[$core:ident]
+            $vis:vis struct $name:ident($inner:ident);
+        )*
+    ) => {
+        $(
+            non_zero_int!(@def $( #[$docs] )* $vis struct $name($inner) as $core);
+
+            impl $name {
+                /// The amount of bits required by this integer type.
+                pub const BITS: ::core::primitive::u32 = <$inner>::BITS;
+
+                /// Creates a non-zero integer if `value` is not zero.
+                #[inline]
+                pub const fn new(value: $inner) -> ::core::option::Option<Self> {
+                    match ::core::num::$core::new(value.to_repr()) {
+                        ::core::option::Option::Some(_) => {
+                            // SAFETY: `value` has just been checked to be non-zero.
+                            ::core::option::Option::Some(unsafe { Self::new_unchecked(value) })
+                        }
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    }
+                }
+            }
+
+            impl ::core::convert::From<$name> for $inner {
+                #[inline]
+                fn from(value: $name) -> Self {
+                    value.get()
+                }
+            }
+
+            impl ::core::convert::TryFrom<$inner> for $name {
+                type Error = $crate::TryFromIntError;
+
+                #[inline]
+                fn try_from(value: $inner) -> ::core::result::Result<Self, Self::Error> {
+                    Self::new(value).ok_or($crate::TryFromIntError::new(
+                        $crate::TryFromIntErrorKind::Zero,
+                        Self::BITS,
+                    ))
+                }
+            }
+
+            impl ::core::convert::From<$name> for ::core::num::$core {
+                #[inline]
+                fn from(value: $name) -> Self {
+                    value.to_core()
+                }
+            }
+
+            impl ::core::cmp::PartialEq for $name {
+                #[inline]
+                fn eq(&self, other: &Self) -> ::core::primitive::bool {
+                    self.get() == other.get()
+                }
+            }
+
+            impl ::core::cmp::Eq for $name {}
+
+            impl ::core::cmp::PartialOrd for $name {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(<Self as ::core::cmp::Ord>::cmp(self, other))
+                }
+            }
+
+            impl ::core::cmp::Ord for $name {
+                #[inline]
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    <$inner as ::core::cmp::Ord>::cmp(&self.get(), &other.get())
+                }
+            }
+
+            impl ::core::hash::Hash for $name {
+                #[inline]
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    <$inner as ::core::hash::Hash>::hash(&self.get(), state)
+                }
+            }
+
+            non_zero_int!(@fmt $name: Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp);
+        )*
+    };
+    ( @def $( #[$docs:meta] )* $vis:vis struct $name:ident($inner:ident) as $core:ident ) => {
+        $( #[$docs] )*
+        #[derive(::core::marker::Copy, ::core::clone::Clone)]
+        #[repr(C, packed)]
+        $vis struct $name(::core::num::$core);
+
+        impl $name {
+            /// Creates a non-zero integer without checking whether `value` is zero.
+            ///
+            /// # Safety
+            ///
+            /// The `value` must not be zero.
+            #[inline]
+            pub const unsafe fn new_unchecked(value: $inner) -> Self {
+                // SAFETY: the caller guarantees that `value` is not zero.
+                Self(unsafe { ::core::num::$core::new_unchecked(value.to_repr()) })
+            }
+
+            /// Returns the contained value as an unaligned integer.
+            #[inline]
+            pub const fn get(self) -> $inner {
+                <$inner>::from_repr_truncating(self.to_core().get())
+            }
+
+            /// Returns the contained value as the `core::num` non-zero primitive.
+            #[inline]
+            const fn to_core(self) -> ::core::num::$core {
+                self.0
+            }
+        }
+
+        impl ::core::convert::From<::core::num::$core> for $name {
+            #[inline]
+            fn from(value: ::core::num::$core) -> Self {
+                Self(value)
+            }
+        }
+    };
+    ( @fmt $name:ident: $( $fmt_trait:ident ),* ) => {
+        $(
+            impl ::core::fmt::$fmt_trait for $name {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::$fmt_trait::fmt(&self.get(), f)
+                }
+            }
+        )*
+    };
+}
+non_zero_int! {
+    /// Non-zero 16-bit unsigned integer with alignment of 1.
+    ///
+    /// `Option<NonZeroU16>` has the same size as `NonZeroU16`.
+    @[NonZeroU16]
+    pub struct NonZeroU16(U16);
+    /// Non-zero 16-bit signed integer with alignment of 1.
+    ///
+    /// `Option<NonZeroI16>` has the same size as `NonZeroI16`.
+    @[NonZeroI16]
+    pub struct NonZeroI16(I16);
+    /// Non-zero 32-bit unsigned integer with alignment of 1.
+    ///
+    /// `Option<NonZeroU32>` has the same size as `NonZeroU32`.
+    @[NonZeroU32]
+    pub struct NonZeroU32(U32);
+    /// Non-zero 32-bit signed integer with alignment of 1.
+    ///
+    /// `Option<NonZeroI32>` has the same size as `NonZeroI32`.
+    @[NonZeroI32]
+    pub struct NonZeroI32(I32);
+    /// Non-zero 64-bit unsigned integer with alignment of 1.
+    ///
+    /// `Option<NonZeroU64>` has the same size as `NonZeroU64`.
+    @[NonZeroU64]
+    pub struct NonZeroU64(U64);
+    /// Non-zero 64-bit signed integer with alignment of 1.
+    ///
+    /// `Option<NonZeroI64>` has the same size as `NonZeroI64`.
+    @[NonZeroI64]
+    pub struct NonZeroI64(I64);
+    /// Non-zero 128-bit unsigned integer with alignment of 1.
+    ///
+    /// `Option<NonZeroU128>` has the same size as `NonZeroU128`.
+    @[NonZeroU128]
+    pub struct NonZeroU128(U128);
+    /// Non-zero 128-bit signed integer with alignment of 1.
+    ///
+    /// `Option<NonZeroI128>` has the same size as `NonZeroI128`.
+    @[NonZeroI128]
+    pub struct NonZeroI128(I128);
+}
@@ -0,0 +1,341 @@
+//! Module to implement the `num-traits` traits for all unaligned integer types provided by this crate.
+//!
+//! All implementations forward to the inherent methods of the same name and therefore
+//! share their semantics, e.g. the `PrimInt::pow` of `U24` panics on overflow in debug
+//! builds and wraps at 24 bits otherwise just like `U24 * U24` does.
+//!
+//! Conversions via `NumCast` and `FromPrimitive` return `None` if the value is out of
+//! bounds for the unaligned integer type and truncate the fractional part of floats.
+
+/// Implements the `num-traits` traits for the unaligned integer `$name`.
+///
+/// The `$signedness` is either `signed` or `unsigned`.
+macro_rules! impl_num_traits {
+    ( $name:ident, $repr:ty, $signedness:ident ) => {
+        impl ::num_traits::Zero for $name {
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> ::core::primitive::bool {
+                self.const_eq(Self::ZERO)
+            }
+        }
+
+        impl ::num_traits::One for $name {
+            #[inline]
+            fn one() -> Self {
+                Self::from_repr_truncating(1)
+            }
+        }
+
+        impl ::num_traits::Bounded for $name {
+            #[inline]
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl ::num_traits::Num for $name {
+            type FromStrRadixErr = $crate::ParseIntError;
+
+            #[inline]
+            fn from_str_radix(
+                src: &::core::primitive::str,
+                radix: ::core::primitive::u32,
+            ) -> ::core::result::Result<Self, Self::FromStrRadixErr> {
+                Self::from_str_radix(src, radix)
+            }
+        }
+
+        impl ::num_traits::ToPrimitive for $name {
+            $crate::num_traits::impl_num_traits!(
+                @to_primitive $repr, to_i64 -> i64, to_u64 -> u64, to_i128 -> i128, to_u128 -> u128, to_f32 -> f32, to_f64 -> f64
+            );
+        }
+
+        impl ::num_traits::FromPrimitive for $name {
+            #[inline]
+            fn from_i64(n: ::core::primitive::i64) -> ::core::option::Option<Self> {
+                Self::checked_from_i128(::core::primitive::i128::from(n))
+            }
+
+            #[inline]
+            fn from_u64(n: ::core::primitive::u64) -> ::core::option::Option<Self> {
+                Self::checked_from_u128(::core::primitive::u128::from(n))
+            }
+
+            #[inline]
+            fn from_i128(n: ::core::primitive::i128) -> ::core::option::Option<Self> {
+                Self::checked_from_i128(n)
+            }
+
+            #[inline]
+            fn from_u128(n: ::core::primitive::u128) -> ::core::option::Option<Self> {
+                Self::checked_from_u128(n)
+            }
+
+            #[inline]
+            fn from_f32(n: ::core::primitive::f32) -> ::core::option::Option<Self> {
+                Self::checked_from_f32(n)
+            }
+
+            #[inline]
+            fn from_f64(n: ::core::primitive::f64) -> ::core::option::Option<Self> {
+                Self::checked_from_f64(n)
+            }
+        }
+
+        impl ::num_traits::NumCast for $name {
+            #[inline]
+            fn from<T: ::num_traits::ToPrimitive>(n: T) -> ::core::option::Option<Self> {
+                $crate::num_traits::impl_num_traits!(@num_cast $signedness, n)
+            }
+        }
+
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::CheckedAdd, checked_add -> ::core::option::Option<Self>);
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::CheckedSub, checked_sub -> ::core::option::Option<Self>);
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::CheckedMul, checked_mul -> ::core::option::Option<Self>);
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::CheckedDiv, checked_div -> ::core::option::Option<Self>);
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::CheckedRem, checked_rem -> ::core::option::Option<Self>);
+        $crate::num_traits::impl_num_traits!(@shift $name, ::num_traits::CheckedShl, checked_shl -> ::core::option::Option<Self>);
+        $crate::num_traits::impl_num_traits!(@shift $name, ::num_traits::CheckedShr, checked_shr -> ::core::option::Option<Self>);
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::WrappingAdd, wrapping_add -> Self);
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::WrappingSub, wrapping_sub -> Self);
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::WrappingMul, wrapping_mul -> Self);
+        $crate::num_traits::impl_num_traits!(@shift $name, ::num_traits::WrappingShl, wrapping_shl -> Self);
+        $crate::num_traits::impl_num_traits!(@shift $name, ::num_traits::WrappingShr, wrapping_shr -> Self);
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::SaturatingAdd, saturating_add -> Self);
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::SaturatingSub, saturating_sub -> Self);
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::SaturatingMul, saturating_mul -> Self);
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::ops::overflowing::OverflowingAdd, overflowing_add -> (Self, ::core::primitive::bool));
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::ops::overflowing::OverflowingSub, overflowing_sub -> (Self, ::core::primitive::bool));
+        $crate::num_traits::impl_num_traits!(@binary $name, ::num_traits::ops::overflowing::OverflowingMul, overflowing_mul -> (Self, ::core::primitive::bool));
+
+        impl ::num_traits::CheckedNeg for $name {
+            #[inline]
+            fn checked_neg(&self) -> ::core::option::Option<Self> {
+                Self::checked_neg(*self)
+            }
+        }
+
+        impl ::num_traits::WrappingNeg for $name {
+            #[inline]
+            fn wrapping_neg(&self) -> Self {
+                Self::wrapping_neg(*self)
+            }
+        }
+
+        impl ::num_traits::Saturating for $name {
+            #[inline]
+            fn saturating_add(self, v: Self) -> Self {
+                Self::saturating_add(self, v)
+            }
+
+            #[inline]
+            fn saturating_sub(self, v: Self) -> Self {
+                Self::saturating_sub(self, v)
+            }
+        }
+
+        impl ::num_traits::PrimInt for $name {
+            #[inline]
+            fn count_ones(self) -> ::core::primitive::u32 {
+                Self::count_ones(self)
+            }
+
+            #[inline]
+            fn count_zeros(self) -> ::core::primitive::u32 {
+                Self::count_zeros(self)
+            }
+
+            #[inline]
+            fn leading_ones(self) -> ::core::primitive::u32 {
+                Self::leading_ones(self)
+            }
+
+            #[inline]
+            fn leading_zeros(self) -> ::core::primitive::u32 {
+                Self::leading_zeros(self)
+            }
+
+            #[inline]
+            fn trailing_ones(self) -> ::core::primitive::u32 {
+                Self::trailing_ones(self)
+            }
+
+            #[inline]
+            fn trailing_zeros(self) -> ::core::primitive::u32 {
+                Self::trailing_zeros(self)
+            }
+
+            #[inline]
+            fn rotate_left(self, n: ::core::primitive::u32) -> Self {
+                Self::rotate_left(self, n)
+            }
+
+            #[inline]
+            fn rotate_right(self, n: ::core::primitive::u32) -> Self {
+                Self::rotate_right(self, n)
+            }
+
+            #[inline]
+            fn signed_shl(self, n: ::core::primitive::u32) -> Self {
+                self << n
+            }
+
+            #[inline]
+            fn signed_shr(self, n: ::core::primitive::u32) -> Self {
+                $crate::num_traits::impl_num_traits!(@signed_shr $signedness, self, n)
+            }
+
+            #[inline]
+            fn unsigned_shl(self, n: ::core::primitive::u32) -> Self {
+                self << n
+            }
+
+            #[inline]
+            fn unsigned_shr(self, n: ::core::primitive::u32) -> Self {
+                $crate::num_traits::impl_num_traits!(@unsigned_shr $signedness, self, n)
+            }
+
+            #[inline]
+            fn swap_bytes(self) -> Self {
+                Self::swap_bytes(self)
+            }
+
+            #[inline]
+            fn reverse_bits(self) -> Self {
+                Self::reverse_bits(self)
+            }
+
+            #[inline]
+            fn from_be(x: Self) -> Self {
+                match ::core::cfg!(target_endian = "big") {
+                    true => x,
+                    false => x.swap_bytes(),
+                }
+            }
+
+            #[inline]
+            fn from_le(x: Self) -> Self {
+                match ::core::cfg!(target_endian = "little") {
+                    true => x,
+                    false => x.swap_bytes(),
+                }
+            }
+
+            #[inline]
+            fn to_be(self) -> Self {
+                <Self as ::num_traits::PrimInt>::from_be(self)
+            }
+
+            #[inline]
+            fn to_le(self) -> Self {
+                <Self as ::num_traits::PrimInt>::from_le(self)
+            }
+
+            #[inline]
+            fn pow(self, exp: ::core::primitive::u32) -> Self {
+                let (result, overflow) = self.overflowing_pow(exp);
+                if ::core::cfg!(debug_assertions) && overflow {
+                    ::core::panic!("attempt to multiply with overflow")
+                }
+                result
+            }
+        }
+
+        $crate::num_traits::impl_num_traits!(@$signedness $name);
+    };
+    ( @unsigned $name:ident ) => {
+        impl ::num_traits::Unsigned for $name {}
+    };
+    ( @signed $name:ident ) => {
+        impl ::num_traits::Signed for $name {
+            #[inline]
+            fn abs(&self) -> Self {
+                match self.is_negative() {
+                    true => -*self,
+                    false => *self,
+                }
+            }
+
+            #[inline]
+            fn abs_sub(&self, other: &Self) -> Self {
+                match *self <= *other {
+                    true => Self::ZERO,
+                    false => *self - *other,
+                }
+            }
+
+            #[inline]
+            fn signum(&self) -> Self {
+                match *self {
+                    value if value.is_negative() => Self::from_repr_truncating(-1),
+                    value if value.const_eq(Self::ZERO) => Self::ZERO,
+                    _ => Self::from_repr_truncating(1),
+                }
+            }
+
+            #[inline]
+            fn is_positive(&self) -> ::core::primitive::bool {
+                !self.is_negative() && !self.const_eq(Self::ZERO)
+            }
+
+            #[inline]
+            fn is_negative(&self) -> ::core::primitive::bool {
+                Self::is_negative(*self)
+            }
+        }
+    };
+    ( @to_primitive $repr:ty, $( $method:ident -> $prim:ident ),* $(,)? ) => {
+        $(
+            #[inline]
+            fn $method(&self) -> ::core::option::Option<::core::primitive::$prim> {
+                <$repr as ::num_traits::ToPrimitive>::$method(&self.to_repr())
+            }
+        )*
+    };
+    ( @binary $name:ident, $trait:path, $method:ident -> $output:ty ) => {
+        impl $trait for $name {
+            #[inline]
+            fn $method(&self, v: &Self) -> $output {
+                Self::$method(*self, *v)
+            }
+        }
+    };
+    ( @shift $name:ident, $trait:path, $method:ident -> $output:ty ) => {
+        impl $trait for $name {
+            #[inline]
+            fn $method(&self, rhs: ::core::primitive::u32) -> $output {
+                Self::$method(*self, rhs)
+            }
+        }
+    };
+    ( @num_cast unsigned, $value:ident ) => {
+        ::num_traits::ToPrimitive::to_u128(&$value).and_then(Self::checked_from_u128)
+    };
+    ( @num_cast signed, $value:ident ) => {
+        ::num_traits::ToPrimitive::to_i128(&$value).and_then(Self::checked_from_i128)
+    };
+    ( @signed_shr unsigned, $value:ident, $n:ident ) => {
+        ($value.cast_signed() >> $n).cast_unsigned()
+    };
+    ( @signed_shr signed, $value:ident, $n:ident ) => {
+        $value >> $n
+    };
+    ( @unsigned_shr unsigned, $value:ident, $n:ident ) => {
+        $value >> $n
+    };
+    ( @unsigned_shr signed, $value:ident, $n:ident ) => {
+        ($value.cast_unsigned() >> $n).cast_signed()
+    };
+}
+pub(crate) use impl_num_traits;
@@ -0,0 +1,246 @@
+//! Module to implement the `core::ops` operator traits for
+//! all unaligned integer types provided by this crate.
+//!
+//! The operators are implemented in terms of the `overflowing_*` methods
+//! of the unaligned integers and therefore detect overflow at the bitwidth
+//! of the unaligned integer and not at the bitwidth of its `Repr` primitive.
+//!
+//! Overflow semantics mirror those of Rust primitives:
+//!
+//! - `+`, `-`, `*` and unary `-` panic upon overflow if `debug_assertions`
+//!   are enabled and wrap around otherwise.
+//! - `/` and `%` always panic upon overflow and upon division by zero.
+//! - `<<` and `>>` panic if the shift amount is negative or not smaller than
+//!   the bitwidth of the unaligned integer if `debug_assertions` are enabled.
+//!   Otherwise the shift amount is reduced modulo the bitwidth.
+//!
+//! The bitwise operators `&`, `|`, `^` and `!` operate on the underlying
+//! byte array directly without ever converting to the `Repr` primitive.
+
+/// Implements `Add`, `Sub`, `Mul`, `Div` and `Rem` as well as their
+/// `*Assign` and by-reference variants for the unaligned integer `$name`.
+macro_rules! impl_arith_ops {
+    ( $name:ident ) => {
+        $crate::ops::impl_arith_ops!(@wrapping $name, Add::add, AddAssign::add_assign, overflowing_add, "attempt to add with overflow");
+        $crate::ops::impl_arith_ops!(@wrapping $name, Sub::sub, SubAssign::sub_assign, overflowing_sub, "attempt to subtract with overflow");
+        $crate::ops::impl_arith_ops!(@wrapping $name, Mul::mul, MulAssign::mul_assign, overflowing_mul, "attempt to multiply with overflow");
+        $crate::ops::impl_arith_ops!(@checked $name, Div::div, DivAssign::div_assign, overflowing_div, "attempt to divide with overflow");
+        $crate::ops::impl_arith_ops!(@checked $name, Rem::rem, RemAssign::rem_assign, overflowing_rem, "attempt to calculate the remainder with overflow");
+    };
+    (
+        @wrapping $name:ident,
+        $trait:ident::$method:ident,
+        $assign_trait:ident::$assign_method:ident,
+        $overflowing:ident,
+        $msg:literal
+    ) => {
+        impl ::core::ops::$trait for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                let (result, overflow) = self.$overflowing(rhs);
+                if ::core::cfg!(debug_assertions) && overflow {
+                    ::core::panic!($msg)
+                }
+                result
+            }
+        }
+
+        $crate::ops::forward_ref_binop!(impl $trait::$method, $assign_trait::$assign_method for $name);
+    };
+    (
+        @checked $name:ident,
+        $trait:ident::$method:ident,
+        $assign_trait:ident::$assign_method:ident,
+        $overflowing:ident,
+        $msg:literal
+    ) => {
+        impl ::core::ops::$trait for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                let (result, overflow) = self.$overflowing(rhs);
+                if overflow {
+                    ::core::panic!($msg)
+                }
+                result
+            }
+        }
+
+        $crate::ops::forward_ref_binop!(impl $trait::$method, $assign_trait::$assign_method for $name);
+    };
+}
+pub(crate) use impl_arith_ops;
+
+/// Implements `Neg` for the signed unaligned integer `$name`.
+macro_rules! impl_neg_op {
+    ( $name:ident ) => {
+        impl ::core::ops::Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                let (result, overflow) = self.overflowing_neg();
+                if ::core::cfg!(debug_assertions) && overflow {
+                    ::core::panic!("attempt to negate with overflow")
+                }
+                result
+            }
+        }
+
+        impl<'a> ::core::ops::Neg for &'a $name {
+            type Output = $name;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                <$name as ::core::ops::Neg>::neg(*self)
+            }
+        }
+    };
+}
+pub(crate) use impl_neg_op;
+
+/// Implements `BitAnd`, `BitOr`, `BitXor` and `Not` as well as their
+/// `*Assign` and by-reference variants for the unaligned integer `$name`.
+macro_rules! impl_bit_ops {
+    ( $name:ident ) => {
+        $crate::ops::impl_bit_ops!(@bytewise $name, BitAnd::bitand, BitAndAssign::bitand_assign, &=);
+        $crate::ops::impl_bit_ops!(@bytewise $name, BitOr::bitor, BitOrAssign::bitor_assign, |=);
+        $crate::ops::impl_bit_ops!(@bytewise $name, BitXor::bitxor, BitXorAssign::bitxor_assign, ^=);
+
+        impl ::core::ops::Not for $name {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self::Output {
+                Self(self.0.map(|byte| !byte))
+            }
+        }
+
+        impl<'a> ::core::ops::Not for &'a $name {
+            type Output = $name;
+
+            #[inline]
+            fn not(self) -> Self::Output {
+                <$name as ::core::ops::Not>::not(*self)
+            }
+        }
+    };
+    (
+        @bytewise $name:ident,
+        $trait:ident::$method:ident,
+        $assign_trait:ident::$assign_method:ident,
+        $assign_op:tt
+    ) => {
+        impl ::core::ops::$trait for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                let mut bytes = self.0;
+                for (lhs, rhs) in bytes.iter_mut().zip(rhs.0) {
+                    *lhs $assign_op rhs;
+                }
+                Self(bytes)
+            }
+        }
+
+        $crate::ops::forward_ref_binop!(impl $trait::$method, $assign_trait::$assign_method for $name);
+    };
+}
+pub(crate) use impl_bit_ops;
+
+/// Implements `Shl` and `Shr` as well as their `*Assign` and by-reference
+/// variants for the unaligned integer `$name` for all primitive shift amount types.
+macro_rules! impl_shift_ops {
+    ( $name:ident ) => {
+        $crate::ops::impl_shift_ops!(
+            $name for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+        );
+    };
+    ( $name:ident for $( $rhs:ty ),* ) => {
+        $(
+            $crate::ops::impl_shift_ops!(@shift $name, $rhs, Shl::shl, ShlAssign::shl_assign, overflowing_shl, "attempt to shift left with overflow");
+            $crate::ops::impl_shift_ops!(@shift $name, $rhs, Shr::shr, ShrAssign::shr_assign, overflowing_shr, "attempt to shift right with overflow");
+        )*
+    };
+    (
+        @shift $name:ident, $rhs:ty,
+        $trait:ident::$method:ident,
+        $assign_trait:ident::$assign_method:ident,
+        $overflowing:ident,
+        $msg:literal
+    ) => {
+        impl ::core::ops::$trait<$rhs> for $name {
+            type Output = Self;
+
+            #[inline]
+            #[allow(clippy::unnecessary_cast)] // Note: `$rhs` might be `u32` already.
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                let (result, overflow) = self.$overflowing(rhs as ::core::primitive::u32);
+                let overflow = overflow
+                    || <::core::primitive::u32 as ::core::convert::TryFrom<$rhs>>::try_from(rhs).is_err();
+                if ::core::cfg!(debug_assertions) && overflow {
+                    ::core::panic!($msg)
+                }
+                result
+            }
+        }
+
+        $crate::ops::forward_ref_binop!(impl $trait::$method, $assign_trait::$assign_method for $name, $rhs);
+    };
+}
+pub(crate) use impl_shift_ops;
+
+/// Implements the by-reference and `*Assign` variants of a binary operator
+/// trait for `$name` given its by-value implementation.
+macro_rules! forward_ref_binop {
+    ( impl $trait:ident::$method:ident, $assign_trait:ident::$assign_method:ident for $name:ident ) => {
+        $crate::ops::forward_ref_binop!(impl $trait::$method, $assign_trait::$assign_method for $name, $name);
+    };
+    ( impl $trait:ident::$method:ident, $assign_trait:ident::$assign_method:ident for $name:ident, $rhs:ty ) => {
+        impl<'a> ::core::ops::$trait<&'a $rhs> for $name {
+            type Output = $name;
+
+            #[inline]
+            fn $method(self, rhs: &'a $rhs) -> Self::Output {
+                <$name as ::core::ops::$trait<$rhs>>::$method(self, *rhs)
+            }
+        }
+
+        impl<'a> ::core::ops::$trait<$rhs> for &'a $name {
+            type Output = $name;
+
+            #[inline]
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                <$name as ::core::ops::$trait<$rhs>>::$method(*self, rhs)
+            }
+        }
+
+        impl<'a, 'b> ::core::ops::$trait<&'a $rhs> for &'b $name {
+            type Output = $name;
+
+            #[inline]
+            fn $method(self, rhs: &'a $rhs) -> Self::Output {
+                <$name as ::core::ops::$trait<$rhs>>::$method(*self, *rhs)
+            }
+        }
+
+        impl ::core::ops::$assign_trait<$rhs> for $name {
+            #[inline]
+            fn $assign_method(&mut self, rhs: $rhs) {
+                *self = <$name as ::core::ops::$trait<$rhs>>::$method(*self, rhs);
+            }
+        }
+
+        impl<'a> ::core::ops::$assign_trait<&'a $rhs> for $name {
+            #[inline]
+            fn $assign_method(&mut self, rhs: &'a $rhs) {
+                *self = <$name as ::core::ops::$trait<$rhs>>::$method(*self, *rhs);
+            }
+        }
+    };
+}
+pub(crate) use forward_ref_binop;
@@ -0,0 +1,202 @@
+//! Module to define the growable `PackedVec` container of unaligned integers.
+//!
+//! This module is only available with the `alloc` crate feature.
+
+use crate::{TryFromIntSliceError, UnalignedInteger};
+use alloc::vec::Vec;
+
+/// Growable vector of unaligned integers with bulk conversions from and to their `Repr` primitives.
+///
+/// Since all unaligned integers have an alignment of 1 the values are stored without padding,
+/// e.g. a `PackedVec<U24>` requires 3 bytes per value instead of 4 bytes for a `Vec<u32>`.
+///
+/// # Example
+///
+/// ```
+/// use intx::{PackedVec, U24};
+///
+/// let mut values = PackedVec::<U24>::try_from_reprs(&[1, 2, 0xFF_FFFF]).unwrap();
+/// values.push(U24::new_const(4));
+/// assert_eq!(values.len(), 4);
+/// assert_eq!(values.to_reprs(), [1, 2, 0xFF_FFFF, 4]);
+///
+/// let error = values.try_extend_from_reprs(&[5, 0x100_0000]).unwrap_err();
+/// assert_eq!(error.index(), 1);
+/// assert_eq!(values.len(), 4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackedVec<T> {
+    values: Vec<T>,
+}
+
+impl<T> PackedVec<T>
+where
+    T: UnalignedInteger,
+{
+    /// Creates a new empty vector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    /// Creates a new empty vector with space for at least `capacity` values.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Creates a new vector from the `Repr` primitives of `src` checking that every value is within bounds.
+    ///
+    /// # Errors
+    ///
+    /// If a value of `src` is out of bounds for `T`. The error reports the index of the first such value.
+    #[inline]
+    pub fn try_from_reprs(src: &[T::Repr]) -> Result<Self, TryFromIntSliceError> {
+        let mut result = Self::with_capacity(src.len());
+        result.try_extend_from_reprs(src)?;
+        Ok(result)
+    }
+
+    /// Appends the `Repr` primitives of `src` checking that every value is within bounds.
+    ///
+    /// # Errors
+    ///
+    /// If a value of `src` is out of bounds for `T`. The error reports the index of the first
+    /// such value within `src`. In this case the vector is left unchanged.
+    #[inline]
+    pub fn try_extend_from_reprs(&mut self, src: &[T::Repr]) -> Result<(), TryFromIntSliceError> {
+        let len = self.values.len();
+        self.values.resize(len + src.len(), T::default());
+        T::slice_from_repr(src, &mut self.values[len..]).inspect_err(|_| {
+            self.values.truncate(len);
+        })
+    }
+
+    /// Returns the values converted to their `Repr` primitives.
+    #[inline]
+    pub fn to_reprs(&self) -> Vec<T::Repr> {
+        let mut reprs = alloc::vec![T::default().to_repr(); self.values.len()];
+        T::slice_to_repr(&self.values, &mut reprs);
+        reprs
+    }
+
+    /// Appends `value` to the back of the vector.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.values.push(value)
+    }
+
+    /// Removes the last value from the vector and returns it or `None` if it is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.values.pop()
+    }
+
+    /// Shortens the vector to `len` values or does nothing if it is already shorter.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.values.truncate(len)
+    }
+
+    /// Removes all values from the vector.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.values.clear()
+    }
+
+    /// Returns the values of the vector as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the values of the vector as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    /// Converts the vector into a `Vec` without reallocating.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.values
+    }
+}
+
+impl<T> Default for PackedVec<T>
+where
+    T: UnalignedInteger,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> core::ops::Deref for PackedVec<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.values
+    }
+}
+
+impl<T> core::ops::DerefMut for PackedVec<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.values
+    }
+}
+
+impl<T> From<Vec<T>> for PackedVec<T> {
+    #[inline]
+    fn from(values: Vec<T>) -> Self {
+        Self { values }
+    }
+}
+
+impl<T> From<PackedVec<T>> for Vec<T> {
+    #[inline]
+    fn from(vec: PackedVec<T>) -> Self {
+        vec.values
+    }
+}
+
+impl<T> FromIterator<T> for PackedVec<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            values: Vec::from_iter(iter),
+        }
+    }
+}
+
+impl<T> Extend<T> for PackedVec<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.values.extend(iter)
+    }
+}
+
+impl<T> IntoIterator for PackedVec<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PackedVec<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
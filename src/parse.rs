@@ -0,0 +1,69 @@
+//! Module to implement string parsing for all unaligned integer types provided by this crate.
+//!
+//! Parsing is performed by the `Repr` primitive of the unaligned integer after which
+//! the parsed value is checked to be within the bounds of the unaligned integer.
+//! This way the distinction between invalid input and out of bounds values is preserved.
+
+/// Implements `from_str_radix` and `core::str::FromStr` for the unaligned integer `$name`.
+macro_rules! impl_from_str {
+    ( $name:ident, $repr:ty ) => {
+        impl $name {
+            /// Converts a string slice in a given base to an integer.
+            ///
+            /// The string is expected to be an optional `+` or `-` sign followed by digits.
+            /// Leading and trailing whitespace represent an error. Digits are a subset of these
+            /// characters, depending on `radix`:
+            ///
+            /// - `0-9`
+            /// - `a-z`
+            /// - `A-Z`
+            ///
+            /// # Errors
+            ///
+            /// If the string is empty, contains invalid digits or
+            /// if the parsed value does not fit into `Self`.
+            ///
+            /// # Panics
+            ///
+            /// If `radix` is not in the range from 2 to 36.
+            #[inline]
+            pub const fn from_str_radix(
+                src: &::core::primitive::str,
+                radix: ::core::primitive::u32,
+            ) -> ::core::result::Result<Self, $crate::ParseIntError> {
+                let repr = match <$repr>::from_str_radix(src, radix) {
+                    ::core::result::Result::Ok(repr) => repr,
+                    ::core::result::Result::Err(error) => {
+                        return ::core::result::Result::Err($crate::ParseIntError::from_core(
+                            &error,
+                        ))
+                    }
+                };
+                let result = Self::from_repr_truncating(repr);
+                if result.to_repr() != repr {
+                    // Note: only negative values can be parsed from a `-` prefixed string.
+                    let is_negative = ::core::matches!(
+                        src.as_bytes().first(),
+                        ::core::option::Option::Some(b'-')
+                    );
+                    let kind = match is_negative {
+                        true => $crate::IntErrorKind::NegOverflow,
+                        false => $crate::IntErrorKind::PosOverflow,
+                    };
+                    return ::core::result::Result::Err($crate::ParseIntError::new(kind));
+                }
+                ::core::result::Result::Ok(result)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::ParseIntError;
+
+            #[inline]
+            fn from_str(src: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                Self::from_str_radix(src, 10)
+            }
+        }
+    };
+}
+pub(crate) use impl_from_str;
@@ -0,0 +1,154 @@
+//! Module to implement `serde::Serialize` and `serde::Deserialize` for
+//! all unaligned integer types provided by this crate.
+//!
+//! The encoding depends on whether the format is human-readable:
+//!
+//! - Human-readable formats such as JSON or TOML encode the numeric value.
+//!   Integers with more than 64 bits are encoded as decimal strings since
+//!   many human-readable formats do not support numbers of that size.
+//!   Upon deserialization both numbers and decimal strings are accepted
+//!   and values that are out of bounds for the integer type are rejected.
+//! - Binary formats such as bincode or postcard encode the integer as a
+//!   tuple of exactly `size_of::<T>()` bytes in little-endian byte order.
+
+/// Implements `Serialize` and `Deserialize` for the unaligned integer `$name`.
+///
+/// The `$signedness` is `wide` for integer types wider than 128 bits.
+macro_rules! impl_serde {
+    ( $name:ident, $signedness:ident ) => {
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                if !serializer.is_human_readable() {
+                    use ::serde::ser::SerializeTuple as _;
+                    let bytes = self.to_le_bytes();
+                    let mut tuple = serializer.serialize_tuple(bytes.len())?;
+                    for byte in &bytes {
+                        tuple.serialize_element(byte)?;
+                    }
+                    return tuple.end();
+                }
+                if Self::BITS > 64 {
+                    return serializer.collect_str(self);
+                }
+                $crate::serde::impl_serde!(@serialize_number $signedness, self, serializer)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                /// Visits the little-endian bytes of binary formats.
+                struct BytesVisitor;
+
+                impl<'de> ::serde::de::Visitor<'de> for BytesVisitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        ::core::write!(f, "{} little-endian bytes", ::core::mem::size_of::<$name>())
+                    }
+
+                    fn visit_seq<A>(self, mut seq: A) -> ::core::result::Result<Self::Value, A::Error>
+                    where
+                        A: ::serde::de::SeqAccess<'de>,
+                    {
+                        let mut bytes = [0x00_u8; ::core::mem::size_of::<$name>()];
+                        for (i, byte) in bytes.iter_mut().enumerate() {
+                            *byte = seq
+                                .next_element()?
+                                .ok_or_else(|| <A::Error as ::serde::de::Error>::invalid_length(i, &self))?;
+                        }
+                        ::core::result::Result::Ok(<$name>::from_le_bytes(bytes))
+                    }
+                }
+
+                /// Visits the numeric value or decimal string of human-readable formats.
+                struct NumberVisitor;
+
+                impl<'de> ::serde::de::Visitor<'de> for NumberVisitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        ::core::write!(
+                            f,
+                            "an integer between {} and {}",
+                            <$name>::MIN,
+                            <$name>::MAX,
+                        )
+                    }
+
+                    fn visit_u64<E>(self, value: ::core::primitive::u64) -> ::core::result::Result<Self::Value, E>
+                    where
+                        E: ::serde::de::Error,
+                    {
+                        <$name>::checked_from_u64(value)
+                            .ok_or_else(|| E::invalid_value(::serde::de::Unexpected::Unsigned(value), &self))
+                    }
+
+                    fn visit_i64<E>(self, value: ::core::primitive::i64) -> ::core::result::Result<Self::Value, E>
+                    where
+                        E: ::serde::de::Error,
+                    {
+                        <$name>::checked_from_i64(value)
+                            .ok_or_else(|| E::invalid_value(::serde::de::Unexpected::Signed(value), &self))
+                    }
+
+                    fn visit_u128<E>(self, value: ::core::primitive::u128) -> ::core::result::Result<Self::Value, E>
+                    where
+                        E: ::serde::de::Error,
+                    {
+                        <$name>::checked_from_u128(value)
+                            .ok_or_else(|| E::invalid_value(::serde::de::Unexpected::Other("integer"), &self))
+                    }
+
+                    fn visit_i128<E>(self, value: ::core::primitive::i128) -> ::core::result::Result<Self::Value, E>
+                    where
+                        E: ::serde::de::Error,
+                    {
+                        <$name>::checked_from_i128(value)
+                            .ok_or_else(|| E::invalid_value(::serde::de::Unexpected::Other("integer"), &self))
+                    }
+
+                    fn visit_str<E>(self, value: &::core::primitive::str) -> ::core::result::Result<Self::Value, E>
+                    where
+                        E: ::serde::de::Error,
+                    {
+                        <$name as ::core::str::FromStr>::from_str(value)
+                            .map_err(|_| E::invalid_value(::serde::de::Unexpected::Str(value), &self))
+                    }
+                }
+
+                if !deserializer.is_human_readable() {
+                    return deserializer.deserialize_tuple(::core::mem::size_of::<$name>(), BytesVisitor);
+                }
+                if Self::BITS > 64 {
+                    return deserializer.deserialize_any(NumberVisitor);
+                }
+                $crate::serde::impl_serde!(@deserialize_number $signedness, deserializer, NumberVisitor)
+            }
+        }
+    };
+    ( @serialize_number unsigned, $value:ident, $serializer:ident ) => {
+        $serializer.serialize_u64($value.to_repr() as ::core::primitive::u64)
+    };
+    ( @serialize_number signed, $value:ident, $serializer:ident ) => {
+        $serializer.serialize_i64($value.to_repr() as ::core::primitive::i64)
+    };
+    ( @serialize_number wide, $value:ident, $serializer:ident ) => {
+        $serializer.collect_str($value)
+    };
+    ( @deserialize_number unsigned, $deserializer:ident, $visitor:ident ) => {
+        $deserializer.deserialize_u64($visitor)
+    };
+    ( @deserialize_number signed, $deserializer:ident, $visitor:ident ) => {
+        $deserializer.deserialize_i64($visitor)
+    };
+    ( @deserialize_number wide, $deserializer:ident, $visitor:ident ) => {
+        $deserializer.deserialize_any($visitor)
+    };
+}
+pub(crate) use impl_serde;
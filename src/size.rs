@@ -0,0 +1,244 @@
+//! Module to implement all `From` and `TryFrom` implementations between
+//! all unaligned integer types provided by this crate and the pointer-sized
+//! Rust primitives `usize` and `isize`.
+//!
+//! Conversions are infallible if and only if they are lossless on all supported
+//! pointer widths which are 16, 32 and 64 bits. All other conversions are fallible.
+
+use crate::{
+    I104, I112, I120, I128, I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, U104, U112,
+    U120, U128, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96,
+};
+
+#[cfg(not(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+)))]
+compile_error!("intx only supports targets with a pointer width of 16, 32 or 64 bits");
+
+/// The fixed-size unsigned Rust primitive with the same bitwidth as `usize`.
+#[cfg(target_pointer_width = "16")]
+pub(crate) type Usize = u16;
+/// The fixed-size signed Rust primitive with the same bitwidth as `isize`.
+#[cfg(target_pointer_width = "16")]
+pub(crate) type Isize = i16;
+
+/// The fixed-size unsigned Rust primitive with the same bitwidth as `usize`.
+#[cfg(target_pointer_width = "32")]
+pub(crate) type Usize = u32;
+/// The fixed-size signed Rust primitive with the same bitwidth as `isize`.
+#[cfg(target_pointer_width = "32")]
+pub(crate) type Isize = i32;
+
+/// The fixed-size unsigned Rust primitive with the same bitwidth as `usize`.
+#[cfg(target_pointer_width = "64")]
+pub(crate) type Usize = u64;
+/// The fixed-size signed Rust primitive with the same bitwidth as `isize`.
+#[cfg(target_pointer_width = "64")]
+pub(crate) type Isize = i64;
+
+macro_rules! impl_size_conversions {
+    ( $( impl $trait:ident<$from:ident> for $to:ident );* $(;)? ) => {
+        $( impl_size_conversions!(@impl $trait<$from> for $to); )*
+    };
+    ( @impl From<$from:ident> for usize ) => {
+        impl_size_conversions!(@from_unaligned $from, ::core::primitive::usize);
+    };
+    ( @impl From<$from:ident> for isize ) => {
+        impl_size_conversions!(@from_unaligned $from, ::core::primitive::isize);
+    };
+    ( @impl TryFrom<$from:ident> for usize ) => {
+        impl_size_conversions!(@try_from_unaligned $from, ::core::primitive::usize);
+    };
+    ( @impl TryFrom<$from:ident> for isize ) => {
+        impl_size_conversions!(@try_from_unaligned $from, ::core::primitive::isize);
+    };
+    ( @from_unaligned $from:ty, $to:ty ) => {
+        impl ::core::convert::From<$from> for $to {
+            #[inline]
+            fn from(value: $from) -> Self {
+                <Self as ::core::convert::From<<$from as $crate::Integer>::Repr>>::from(
+                    <<$from as $crate::Integer>::Repr as ::core::convert::From<$from>>::from(value)
+                )
+            }
+        }
+    };
+    ( @try_from_unaligned $from:ty, $to:ty ) => {
+        impl ::core::convert::TryFrom<$from> for $to {
+            type Error = $crate::TryFromIntError;
+
+            #[inline]
+            fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
+                let repr = <<$from as $crate::Integer>::Repr
+                    as ::core::convert::From<$from>>::from(value);
+                <Self as ::core::convert::TryFrom<_>>::try_from(repr).map_err(|_| {
+                    $crate::error::try_from_int_error!(
+                        $to,
+                        <$from as $crate::Integer>::sign_ext_byte(value) != 0x00_u8
+                    )
+                })
+            }
+        }
+    };
+    ( @impl From<usize> for $to:ident ) => {
+        impl ::core::convert::From<::core::primitive::usize> for $to {
+            #[inline]
+            fn from(value: ::core::primitive::usize) -> Self {
+                // Note: lossless since `Usize` has the same bitwidth as `usize`.
+                <Self as ::core::convert::From<$crate::size::Usize>>::from(value as $crate::size::Usize)
+            }
+        }
+    };
+    ( @impl From<isize> for $to:ident ) => {
+        impl ::core::convert::From<::core::primitive::isize> for $to {
+            #[inline]
+            fn from(value: ::core::primitive::isize) -> Self {
+                // Note: lossless since `Isize` has the same bitwidth as `isize`.
+                <Self as ::core::convert::From<$crate::size::Isize>>::from(value as $crate::size::Isize)
+            }
+        }
+    };
+    ( @impl TryFrom<$from:ident> for $to:ident ) => {
+        impl ::core::convert::TryFrom<$from> for $to {
+            type Error = $crate::TryFromIntError;
+
+            #[inline]
+            #[allow(clippy::needless_question_mark)] // Note: converts the error type for some `Repr` types.
+            fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
+                let repr = <<Self as $crate::Integer>::Repr
+                    as ::core::convert::TryFrom<$from>>::try_from(value)
+                    .map_err(|_| $crate::error::try_from_int_error!(
+                        $to,
+                        value < <$from as ::core::default::Default>::default()
+                    ))?;
+                ::core::result::Result::Ok(<Self as ::core::convert::TryFrom<_>>::try_from(repr)?)
+            }
+        }
+    };
+}
+impl_size_conversions! {
+    impl From<U16> for usize;
+    impl TryFrom<I16> for usize;
+    impl TryFrom<U24> for usize;
+    impl TryFrom<I24> for usize;
+    impl TryFrom<U32> for usize;
+    impl TryFrom<I32> for usize;
+    impl TryFrom<U40> for usize;
+    impl TryFrom<I40> for usize;
+    impl TryFrom<U48> for usize;
+    impl TryFrom<I48> for usize;
+    impl TryFrom<U56> for usize;
+    impl TryFrom<I56> for usize;
+    impl TryFrom<U64> for usize;
+    impl TryFrom<I64> for usize;
+    impl TryFrom<U72> for usize;
+    impl TryFrom<I72> for usize;
+    impl TryFrom<U80> for usize;
+    impl TryFrom<I80> for usize;
+    impl TryFrom<U88> for usize;
+    impl TryFrom<I88> for usize;
+    impl TryFrom<U96> for usize;
+    impl TryFrom<I96> for usize;
+    impl TryFrom<U104> for usize;
+    impl TryFrom<I104> for usize;
+    impl TryFrom<U112> for usize;
+    impl TryFrom<I112> for usize;
+    impl TryFrom<U120> for usize;
+    impl TryFrom<I120> for usize;
+    impl TryFrom<U128> for usize;
+    impl TryFrom<I128> for usize;
+
+    impl TryFrom<U16> for isize;
+    impl From<I16> for isize;
+    impl TryFrom<U24> for isize;
+    impl TryFrom<I24> for isize;
+    impl TryFrom<U32> for isize;
+    impl TryFrom<I32> for isize;
+    impl TryFrom<U40> for isize;
+    impl TryFrom<I40> for isize;
+    impl TryFrom<U48> for isize;
+    impl TryFrom<I48> for isize;
+    impl TryFrom<U56> for isize;
+    impl TryFrom<I56> for isize;
+    impl TryFrom<U64> for isize;
+    impl TryFrom<I64> for isize;
+    impl TryFrom<U72> for isize;
+    impl TryFrom<I72> for isize;
+    impl TryFrom<U80> for isize;
+    impl TryFrom<I80> for isize;
+    impl TryFrom<U88> for isize;
+    impl TryFrom<I88> for isize;
+    impl TryFrom<U96> for isize;
+    impl TryFrom<I96> for isize;
+    impl TryFrom<U104> for isize;
+    impl TryFrom<I104> for isize;
+    impl TryFrom<U112> for isize;
+    impl TryFrom<I112> for isize;
+    impl TryFrom<U120> for isize;
+    impl TryFrom<I120> for isize;
+    impl TryFrom<U128> for isize;
+    impl TryFrom<I128> for isize;
+
+    impl TryFrom<usize> for U16;
+    impl TryFrom<usize> for I16;
+    impl TryFrom<usize> for U24;
+    impl TryFrom<usize> for I24;
+    impl TryFrom<usize> for U32;
+    impl TryFrom<usize> for I32;
+    impl TryFrom<usize> for U40;
+    impl TryFrom<usize> for I40;
+    impl TryFrom<usize> for U48;
+    impl TryFrom<usize> for I48;
+    impl TryFrom<usize> for U56;
+    impl TryFrom<usize> for I56;
+    impl From<usize> for U64;
+    impl TryFrom<usize> for I64;
+    impl From<usize> for U72;
+    impl From<usize> for I72;
+    impl From<usize> for U80;
+    impl From<usize> for I80;
+    impl From<usize> for U88;
+    impl From<usize> for I88;
+    impl From<usize> for U96;
+    impl From<usize> for I96;
+    impl From<usize> for U104;
+    impl From<usize> for I104;
+    impl From<usize> for U112;
+    impl From<usize> for I112;
+    impl From<usize> for U120;
+    impl From<usize> for I120;
+    impl From<usize> for U128;
+    impl From<usize> for I128;
+
+    impl TryFrom<isize> for U16;
+    impl TryFrom<isize> for I16;
+    impl TryFrom<isize> for U24;
+    impl TryFrom<isize> for I24;
+    impl TryFrom<isize> for U32;
+    impl TryFrom<isize> for I32;
+    impl TryFrom<isize> for U40;
+    impl TryFrom<isize> for I40;
+    impl TryFrom<isize> for U48;
+    impl TryFrom<isize> for I48;
+    impl TryFrom<isize> for U56;
+    impl TryFrom<isize> for I56;
+    impl TryFrom<isize> for U64;
+    impl From<isize> for I64;
+    impl TryFrom<isize> for U72;
+    impl From<isize> for I72;
+    impl TryFrom<isize> for U80;
+    impl From<isize> for I80;
+    impl TryFrom<isize> for U88;
+    impl From<isize> for I88;
+    impl TryFrom<isize> for U96;
+    impl From<isize> for I96;
+    impl TryFrom<isize> for U104;
+    impl From<isize> for I104;
+    impl TryFrom<isize> for U112;
+    impl From<isize> for I112;
+    impl TryFrom<isize> for U120;
+    impl From<isize> for I120;
+    impl TryFrom<isize> for U128;
+    impl From<isize> for I128;
+}
@@ -0,0 +1,202 @@
+//! Module to implement bulk conversions between slices of unaligned integers
+//! and slices of their `Repr` primitives as well as in-place endian conversions.
+//!
+//! The conversions process values in fixed-size chunks without branching on
+//! individual values so that the compiler is able to vectorize the loops.
+//! Bounds violations are only inspected once per chunk to find the first failing index.
+
+use crate::{TryFromIntSliceError, UnalignedInteger};
+use crate::{
+    I104, I112, I120, I128, I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, U104, U112,
+    U120, U128, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96,
+};
+
+/// The number of values per chunk processed without branching on individual values.
+const CHUNK_LEN: usize = 16;
+
+/// Panics if the lengths of the source and destination slices differ.
+#[inline]
+#[track_caller]
+fn assert_same_len(src_len: usize, dst_len: usize) {
+    assert_eq!(
+        src_len, dst_len,
+        "source slice length ({src_len}) does not match destination slice length ({dst_len})",
+    );
+}
+
+/// Converts the `Repr` primitives of `src` into the unaligned integers of `dst`.
+///
+/// # Errors
+///
+/// If a value of `src` is out of bounds for `T`. The error reports the index of
+/// the first such value. All values of `dst` before that index are converted.
+///
+/// # Panics
+///
+/// If `src` and `dst` have different lengths.
+#[inline]
+#[track_caller]
+pub(crate) fn from_repr_slice<T>(src: &[T::Repr], dst: &mut [T]) -> Result<(), TryFromIntSliceError>
+where
+    T: UnalignedInteger,
+{
+    assert_same_len(src.len(), dst.len());
+    let (min, max) = (T::MIN.to_repr(), T::MAX.to_repr());
+    let is_within_bounds = |repr: &T::Repr| min <= *repr && *repr <= max;
+    let chunks = src.chunks(CHUNK_LEN).zip(dst.chunks_mut(CHUNK_LEN));
+    for (n, (src, dst)) in chunks.enumerate() {
+        let mut all_within_bounds = true;
+        for (src, dst) in src.iter().zip(dst) {
+            all_within_bounds &= is_within_bounds(src);
+            *dst = T::from_repr_truncating(*src);
+        }
+        if !all_within_bounds {
+            let offset = src
+                .iter()
+                .position(|repr| !is_within_bounds(repr))
+                .unwrap_or(0);
+            return Err(TryFromIntSliceError::new(n * CHUNK_LEN + offset));
+        }
+    }
+    Ok(())
+}
+
+/// Converts the unaligned integers of `src` into the `Repr` primitives of `dst`.
+///
+/// # Panics
+///
+/// If `src` and `dst` have different lengths.
+#[inline]
+#[track_caller]
+pub(crate) fn to_repr_slice<T>(src: &[T], dst: &mut [T::Repr])
+where
+    T: UnalignedInteger,
+{
+    assert_same_len(src.len(), dst.len());
+    for (src, dst) in src.iter().zip(dst) {
+        *dst = src.to_repr();
+    }
+}
+
+macro_rules! impl_slice_methods {
+    ( $( impl $name:ident => $from_repr:ident, $to_repr:ident; )* ) => {
+        $(
+            impl $name {
+                #[doc = ::core::concat!(
+                    "Converts the `", ::core::stringify!($from_repr), "` values of `src` ",
+                    "into `dst` checking that every value is within bounds.",
+                )]
+                ///
+                /// This is the bulk equivalent of `TryFrom` and a lot more efficient than
+                /// converting the values one by one.
+                ///
+                /// # Errors
+                ///
+                /// If a value of `src` is out of bounds for `Self`. The error reports the index
+                /// of the first such value. All values of `dst` before that index are converted.
+                ///
+                /// # Panics
+                ///
+                /// If `src` and `dst` have different lengths.
+                #[inline]
+                #[track_caller]
+                pub fn $from_repr(
+                    src: &[<Self as $crate::UnalignedInteger>::Repr],
+                    dst: &mut [Self],
+                ) -> ::core::result::Result<(), $crate::TryFromIntSliceError> {
+                    $crate::slice::from_repr_slice(src, dst)
+                }
+
+                /// Converts the values of `src` into their `Repr` primitives in `dst`.
+                ///
+                /// This is the bulk equivalent of `From` and a lot more efficient than
+                /// converting the values one by one.
+                ///
+                /// # Panics
+                ///
+                /// If `src` and `dst` have different lengths.
+                #[inline]
+                #[track_caller]
+                pub fn $to_repr(src: &[Self], dst: &mut [<Self as $crate::UnalignedInteger>::Repr]) {
+                    $crate::slice::to_repr_slice(src, dst)
+                }
+
+                /// Reverses the byte order of all `values` in place.
+                #[inline]
+                pub fn slice_swap_bytes(values: &mut [Self]) {
+                    for value in values {
+                        *value = value.swap_bytes();
+                    }
+                }
+
+                /// Converts all `values` from little endian to native endianness in place.
+                ///
+                /// This is a no-op on little-endian platforms.
+                #[inline]
+                pub fn slice_from_le(values: &mut [Self]) {
+                    if ::core::cfg!(target_endian = "big") {
+                        Self::slice_swap_bytes(values)
+                    }
+                }
+
+                /// Converts all `values` from big endian to native endianness in place.
+                ///
+                /// This is a no-op on big-endian platforms.
+                #[inline]
+                pub fn slice_from_be(values: &mut [Self]) {
+                    if ::core::cfg!(target_endian = "little") {
+                        Self::slice_swap_bytes(values)
+                    }
+                }
+
+                /// Converts all `values` from native endianness to little endian in place.
+                ///
+                /// This is a no-op on little-endian platforms.
+                #[inline]
+                pub fn slice_to_le(values: &mut [Self]) {
+                    Self::slice_from_le(values)
+                }
+
+                /// Converts all `values` from native endianness to big endian in place.
+                ///
+                /// This is a no-op on big-endian platforms.
+                #[inline]
+                pub fn slice_to_be(values: &mut [Self]) {
+                    Self::slice_from_be(values)
+                }
+            }
+        )*
+    };
+}
+impl_slice_methods! {
+    impl U16 => slice_from_u16, slice_to_u16;
+    impl I16 => slice_from_i16, slice_to_i16;
+    impl U24 => slice_from_u32, slice_to_u32;
+    impl I24 => slice_from_i32, slice_to_i32;
+    impl U32 => slice_from_u32, slice_to_u32;
+    impl I32 => slice_from_i32, slice_to_i32;
+    impl U40 => slice_from_u64, slice_to_u64;
+    impl I40 => slice_from_i64, slice_to_i64;
+    impl U48 => slice_from_u64, slice_to_u64;
+    impl I48 => slice_from_i64, slice_to_i64;
+    impl U56 => slice_from_u64, slice_to_u64;
+    impl I56 => slice_from_i64, slice_to_i64;
+    impl U64 => slice_from_u64, slice_to_u64;
+    impl I64 => slice_from_i64, slice_to_i64;
+    impl U72 => slice_from_u128, slice_to_u128;
+    impl I72 => slice_from_i128, slice_to_i128;
+    impl U80 => slice_from_u128, slice_to_u128;
+    impl I80 => slice_from_i128, slice_to_i128;
+    impl U88 => slice_from_u128, slice_to_u128;
+    impl I88 => slice_from_i128, slice_to_i128;
+    impl U96 => slice_from_u128, slice_to_u128;
+    impl I96 => slice_from_i128, slice_to_i128;
+    impl U104 => slice_from_u128, slice_to_u128;
+    impl I104 => slice_from_i128, slice_to_i128;
+    impl U112 => slice_from_u128, slice_to_u128;
+    impl I112 => slice_from_i128, slice_to_i128;
+    impl U120 => slice_from_u128, slice_to_u128;
+    impl I120 => slice_from_i128, slice_to_i128;
+    impl U128 => slice_from_u128, slice_to_u128;
+    impl I128 => slice_from_i128, slice_to_i128;
+}
@@ -45,7 +45,7 @@ fn try_from_i32_for_i24() {
     );
     assert_eq!(
         I24::try_from(i32::from_le_bytes([0x00, 0x00, 0x80, 0x00])),
-        Err(TryFromIntError(()))
+        Err(TryFromIntError::new(TryFromIntErrorKind::PosOverflow, 24))
     );
     assert_eq!(
         I24::try_from(i32::from_le_bytes([0xFF, 0xFF, 0x7F, 0x00])),
@@ -53,7 +53,7 @@ fn try_from_i32_for_i24() {
     );
     assert_eq!(
         I24::try_from(i32::from_le_bytes([0xFF, 0xFF, 0x7F, 0xFF])),
-        Err(TryFromIntError(()))
+        Err(TryFromIntError::new(TryFromIntErrorKind::NegOverflow, 24))
     );
 }
 
@@ -131,3 +131,2560 @@ fn try_from_works_for_u24() {
     assert_eq!(<I24>::try_from(U24::MIN), Ok(I24::default()));
     assert!(<I24>::try_from(U24::MAX).is_err());
 }
+
+#[test]
+fn from_signed_primitive_zero_works() {
+    assert_eq!(i32::from(I24::from(0_i8)), 0);
+    assert_eq!(i64::from(I40::from(0_i16)), 0);
+    assert_eq!(i128::from(I72::from(0_i64)), 0);
+    assert_eq!(i32::from(I24::from(-1_i8)), -1);
+    assert_eq!(i32::from(I24::from(1_i8)), 1);
+}
+
+mod ops {
+    use crate::*;
+
+    fn u24(value: u32) -> U24 {
+        U24::try_from(value).unwrap()
+    }
+
+    fn i24(value: i32) -> I24 {
+        I24::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn arithmetic_works() {
+        assert_eq!(u24(1) + u24(2), u24(3));
+        assert_eq!(u24(0xFF_FFFE) + u24(1), U24::MAX);
+        assert_eq!(u24(5) - u24(3), u24(2));
+        assert_eq!(u24(0x1000) * u24(0x0FFF), u24(0xFF_F000));
+        assert_eq!(u24(100) / u24(7), u24(14));
+        assert_eq!(u24(100) % u24(7), u24(2));
+        assert_eq!(i24(-5) + i24(3), i24(-2));
+        assert_eq!(i24(-5) - i24(3), i24(-8));
+        assert_eq!(i24(-5) * i24(3), i24(-15));
+        assert_eq!(i24(-100) / i24(7), i24(-14));
+        assert_eq!(i24(-100) % i24(7), i24(-2));
+        assert_eq!(-i24(42), i24(-42));
+        assert_eq!(-I24::MAX, I24::MIN + i24(1));
+        assert_eq!(I24::MIN % i24(1), i24(0));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)] // Note: we explicitly test the by-reference impls here.
+    fn reference_and_assign_variants_work() {
+        let mut x = u24(10);
+        x += u24(5);
+        x -= &u24(3);
+        x *= u24(2);
+        x /= &u24(4);
+        x %= u24(4);
+        assert_eq!(x, u24(2));
+        assert_eq!(&u24(1) + u24(2), u24(3));
+        assert_eq!(u24(1) + &u24(2), u24(3));
+        assert_eq!(&u24(1) + &u24(2), u24(3));
+        assert_eq!(-&i24(1), i24(-1));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn add_overflow_panics() {
+        let _ = U24::MAX + u24(1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn sub_overflow_panics() {
+        let _ = I24::MIN - i24(1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn mul_overflow_panics() {
+        // Note: the product fits into `u32` but not into 24 bits.
+        let _ = u24(0x1000) * u24(0x1000);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn neg_overflow_panics() {
+        let _ = -I24::MIN;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn div_overflow_panics() {
+        let _ = I24::MIN / i24(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with overflow")]
+    fn rem_overflow_panics() {
+        let _ = I24::MIN % i24(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn div_overflow_panics_for_power_of_two_width() {
+        let _ = I32::MIN / I32::from(-1_i32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_by_zero_panics() {
+        let _ = u24(1) / u24(0);
+    }
+}
+
+mod arith {
+    use crate::*;
+
+    /// Interesting sample values for an integer type with the given bounds.
+    fn samples(min: i128, max: i128) -> impl Iterator<Item = i128> + Clone {
+        [
+            min,
+            min + 1,
+            min / 2,
+            -2,
+            -1,
+            0,
+            1,
+            2,
+            3,
+            7,
+            255,
+            256,
+            max / 2,
+            max - 1,
+            max,
+        ]
+        .into_iter()
+        .filter(move |&value| min <= value && value <= max)
+    }
+
+    /// Wraps the exact `value` into the bounds of an integer type with `bits` bits.
+    fn wrap(value: i128, bits: u32, signed: bool) -> i128 {
+        let modulus = 1_i128 << bits;
+        let wrapped = value.rem_euclid(modulus);
+        if signed && wrapped >= modulus / 2 {
+            return wrapped - modulus;
+        }
+        wrapped
+    }
+
+    macro_rules! test_against_exact_model {
+        ( $( fn $test_name:ident for $ty:ty as $repr:ty );* $(;)? ) => {
+            $(
+                #[test]
+                fn $test_name() {
+                    let min = <$repr>::from(<$ty>::MIN) as i128;
+                    let max = <$repr>::from(<$ty>::MAX) as i128;
+                    let signed = min < 0;
+                    let bits = <$ty>::BITS;
+                    let new = |value: i128| <$ty>::try_from(value as $repr).unwrap();
+                    let check = |exact: i128, (result, overflow): ($ty, bool)| {
+                        let in_bounds = min <= exact && exact <= max;
+                        assert_eq!(overflow, !in_bounds);
+                        assert_eq!(<$repr>::from(result) as i128, wrap(exact, bits, signed));
+                    };
+                    for lhs in samples(min, max) {
+                        check(-lhs, new(lhs).overflowing_neg());
+                        for exp in 0..5 {
+                            match lhs.checked_pow(exp) {
+                                Some(exact) => check(exact, new(lhs).overflowing_pow(exp)),
+                                None => assert!(new(lhs).overflowing_pow(exp).1),
+                            }
+                        }
+                        for rhs in samples(min, max) {
+                            let (a, b) = (new(lhs), new(rhs));
+                            check(lhs + rhs, a.overflowing_add(b));
+                            check(lhs - rhs, a.overflowing_sub(b));
+                            check(lhs * rhs, a.overflowing_mul(b));
+                            assert_eq!(a.checked_add(b).is_none(), a.overflowing_add(b).1);
+                            assert_eq!(a.wrapping_mul(b), a.overflowing_mul(b).0);
+                            assert_eq!(
+                                <$repr>::from(a.saturating_add(b)) as i128,
+                                (lhs + rhs).clamp(min, max),
+                            );
+                            assert_eq!(
+                                <$repr>::from(a.saturating_sub(b)) as i128,
+                                (lhs - rhs).clamp(min, max),
+                            );
+                            assert_eq!(
+                                <$repr>::from(a.saturating_mul(b)) as i128,
+                                (lhs * rhs).clamp(min, max),
+                            );
+                            if rhs != 0 {
+                                check(lhs / rhs, a.overflowing_div(b));
+                                assert_eq!(a.checked_div(b).is_none(), a.overflowing_div(b).1);
+                                assert_eq!(a.checked_rem(b).is_none(), a.overflowing_div(b).1);
+                                if !a.overflowing_rem(b).1 {
+                                    assert_eq!(<$repr>::from(a.wrapping_rem(b)) as i128, lhs % rhs);
+                                }
+                            } else {
+                                assert_eq!(a.checked_div(b), None);
+                                assert_eq!(a.checked_rem(b), None);
+                            }
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    test_against_exact_model! {
+        fn u16_matches_exact_model for U16 as u16;
+        fn i16_matches_exact_model for I16 as i16;
+        fn u24_matches_exact_model for U24 as u32;
+        fn i24_matches_exact_model for I24 as i32;
+        fn u32_matches_exact_model for U32 as u32;
+        fn i32_matches_exact_model for I32 as i32;
+        fn u48_matches_exact_model for U48 as u64;
+        fn i48_matches_exact_model for I48 as i64;
+        fn u56_matches_exact_model for U56 as u64;
+        fn i56_matches_exact_model for I56 as i64;
+    }
+
+    #[test]
+    fn shifts_respect_bitwidth() {
+        let one = U24::try_from(1_u32).unwrap();
+        assert_eq!(
+            one.checked_shl(23),
+            Some(U24::try_from(0x80_0000_u32).unwrap())
+        );
+        assert_eq!(one.checked_shl(24), None);
+        assert_eq!(
+            one.overflowing_shl(25),
+            (U24::try_from(2_u32).unwrap(), true)
+        );
+        assert_eq!(
+            U24::MAX.wrapping_shl(4),
+            U24::try_from(0xFF_FFF0_u32).unwrap()
+        );
+        assert_eq!(U24::MAX.wrapping_shr(20), U24::try_from(0xF_u32).unwrap());
+        assert_eq!(I24::MIN.wrapping_shr(23), I24::try_from(-1_i32).unwrap());
+        assert_eq!(I24::MIN.checked_shr(24), None);
+    }
+
+    #[test]
+    fn signed_specific_methods_work() {
+        let i24 = |value: i32| I24::try_from(value).unwrap();
+        assert_eq!(i24(-5).checked_abs(), Some(i24(5)));
+        assert_eq!(I24::MIN.checked_abs(), None);
+        assert_eq!(I24::MIN.wrapping_abs(), I24::MIN);
+        assert_eq!(I24::MIN.overflowing_abs(), (I24::MIN, true));
+        assert_eq!(I24::MIN.saturating_abs(), I24::MAX);
+        assert_eq!(I24::MIN.saturating_neg(), I24::MAX);
+        assert_eq!(I24::MIN.saturating_div(i24(-1)), I24::MAX);
+        assert_eq!(I24::MIN.overflowing_div(i24(-1)), (I24::MIN, true));
+        assert_eq!(I24::MIN.overflowing_rem(i24(-1)), (i24(0), true));
+        assert_eq!(i24(-2).saturating_pow(23), I24::MIN);
+        assert_eq!(i24(-2).saturating_pow(24), I24::MAX);
+        assert!(i24(-1).is_negative());
+        assert!(!i24(0).is_negative());
+    }
+
+    #[test]
+    fn unsigned_neg_works() {
+        let u24 = |value: u32| U24::try_from(value).unwrap();
+        assert_eq!(u24(0).checked_neg(), Some(u24(0)));
+        assert_eq!(u24(1).checked_neg(), None);
+        assert_eq!(u24(1).wrapping_neg(), U24::MAX);
+        assert_eq!(u24(5).saturating_div(u24(2)), u24(2));
+    }
+}
+
+mod bit_ops {
+    use crate::*;
+
+    #[test]
+    fn bitwise_ops_match_repr() {
+        let values = [0_u32, 1, 0x80_0000, 0xFF_FFFF, 0x12_3456, 0xAB_CDEF];
+        for lhs in values {
+            for rhs in values {
+                let (a, b) = (U24::try_from(lhs).unwrap(), U24::try_from(rhs).unwrap());
+                assert_eq!(u32::from(a & b), lhs & rhs);
+                assert_eq!(u32::from(a | b), lhs | rhs);
+                assert_eq!(u32::from(a ^ b), lhs ^ rhs);
+                let mut c = a;
+                c ^= &b;
+                c |= b;
+                c &= b;
+                assert_eq!(u32::from(c), ((lhs ^ rhs) | rhs) & rhs);
+            }
+            assert_eq!(u32::from(!U24::try_from(lhs).unwrap()), !lhs & 0xFF_FFFF);
+        }
+        assert_eq!(!I24::default(), I24::try_from(-1_i32).unwrap());
+        assert_eq!(!I24::MAX, I24::MIN);
+    }
+
+    #[test]
+    fn shifts_work() {
+        let u24 = |value: u32| U24::try_from(value).unwrap();
+        let i24 = |value: i32| I24::try_from(value).unwrap();
+        assert_eq!(u24(1) << 23_u32, u24(0x80_0000));
+        assert_eq!(u24(0xFF_FFFF) << 4_u8, u24(0xFF_FFF0));
+        assert_eq!(u24(0x80_0000) >> 23_i64, u24(1));
+        assert_eq!(U24::MAX >> 4_usize, u24(0x0F_FFFF));
+        assert_eq!(I24::MIN >> 4_i32, i24(-0x08_0000));
+        assert_eq!(i24(-1) >> 23_u32, i24(-1));
+        assert_eq!(i24(0x40_0000) << 1_u32, I24::MIN);
+        let mut x = u24(3);
+        x <<= 2_u32;
+        x >>= &1_i8;
+        assert_eq!(x, u24(6));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn shl_overflow_panics() {
+        let _ = U24::MAX << 24_u32;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to shift right with overflow")]
+    fn shr_negative_panics() {
+        let _ = I24::MAX >> -1_i32;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to shift right with overflow")]
+    fn shr_large_amount_panics() {
+        let _ = U48::MAX >> (1_u64 << 32);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn shifts_mask_amount_in_release() {
+        assert_eq!(U24::MAX << 25_u32, U24::MAX << 1_u32);
+        assert_eq!(U32::MAX >> 33_u32, U32::MAX >> 1_u32);
+    }
+}
+
+mod bit_methods {
+    use crate::*;
+
+    const SAMPLES: [u64; 10] = [
+        0,
+        1,
+        2,
+        0x80,
+        0x0123_4567_89AB_CDEF,
+        0xFEDC_BA98_7654_3210,
+        0x8000_0000_0000_0000,
+        0x00FF_00FF_00FF_00FF,
+        u64::MAX - 1,
+        u64::MAX,
+    ];
+
+    macro_rules! test_matches_primitive {
+        ( $( fn $test_name:ident for $ty:ty as $prim:ty );* $(;)? ) => {
+            $(
+                #[test]
+                fn $test_name() {
+                    for sample in SAMPLES {
+                        let prim = sample as $prim;
+                        let value = <$ty>::from(prim);
+                        assert_eq!(value.count_ones(), prim.count_ones());
+                        assert_eq!(value.count_zeros(), prim.count_zeros());
+                        assert_eq!(value.leading_zeros(), prim.leading_zeros());
+                        assert_eq!(value.trailing_zeros(), prim.trailing_zeros());
+                        assert_eq!(value.leading_ones(), prim.leading_ones());
+                        assert_eq!(value.trailing_ones(), prim.trailing_ones());
+                        assert_eq!(<$prim>::from(value.swap_bytes()), prim.swap_bytes());
+                        assert_eq!(<$prim>::from(value.reverse_bits()), prim.reverse_bits());
+                        for n in [0, 1, 7, 8, 13, <$ty>::BITS - 1, <$ty>::BITS, 100] {
+                            assert_eq!(<$prim>::from(value.rotate_left(n)), prim.rotate_left(n));
+                            assert_eq!(<$prim>::from(value.rotate_right(n)), prim.rotate_right(n));
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    test_matches_primitive! {
+        fn u16_matches_primitive for U16 as u16;
+        fn i16_matches_primitive for I16 as i16;
+        fn u32_matches_primitive for U32 as u32;
+        fn i32_matches_primitive for I32 as i32;
+        fn u64_matches_primitive for U64 as u64;
+        fn i64_matches_primitive for I64 as i64;
+    }
+
+    #[test]
+    fn u40_respects_bitwidth() {
+        const MASK: u64 = (1 << 40) - 1;
+        for sample in SAMPLES {
+            let bits = sample & MASK;
+            let value = U40::try_from(bits).unwrap();
+            assert_eq!(value.count_ones(), bits.count_ones());
+            assert_eq!(value.count_zeros(), 40 - bits.count_ones());
+            assert_eq!(value.leading_zeros(), bits.leading_zeros() - 24);
+            assert_eq!(value.trailing_zeros(), bits.trailing_zeros().min(40));
+            assert_eq!(value.leading_ones(), (bits << 24).leading_ones());
+            assert_eq!(value.trailing_ones(), bits.trailing_ones());
+            assert_eq!(u64::from(value.swap_bytes()), bits.swap_bytes() >> 24);
+            assert_eq!(u64::from(value.reverse_bits()), bits.reverse_bits() >> 24);
+            for n in 1..40 {
+                let rotated = ((bits << n) | (bits >> (40 - n))) & MASK;
+                assert_eq!(u64::from(value.rotate_left(n)), rotated);
+                assert_eq!(u64::from(value.rotate_right(40 - n)), rotated);
+            }
+            assert_eq!(value.rotate_left(40), value);
+        }
+    }
+
+    #[test]
+    fn i24_respects_bitwidth() {
+        let i24 = |value: i32| I24::try_from(value).unwrap();
+        assert_eq!(i24(-1).count_ones(), 24);
+        assert_eq!(i24(-1).leading_ones(), 24);
+        assert_eq!(i24(-1).trailing_ones(), 24);
+        assert_eq!(i24(-1).leading_zeros(), 0);
+        assert_eq!(i24(0).leading_zeros(), 24);
+        assert_eq!(i24(0).trailing_zeros(), 24);
+        assert_eq!(i24(1).leading_zeros(), 23);
+        assert_eq!(I24::MIN.trailing_zeros(), 23);
+        assert_eq!(I24::MIN.rotate_left(1), i24(1));
+        assert_eq!(i24(1).rotate_right(1), I24::MIN);
+        assert_eq!(i24(-2).rotate_right(1), I24::MAX);
+        assert_eq!(i24(1).reverse_bits(), I24::MIN);
+        assert_eq!(i24(0x12_3456).swap_bytes(), i24(0x56_3412));
+    }
+
+    #[test]
+    fn power_of_two_works() {
+        let u24 = |value: u32| U24::try_from(value).unwrap();
+        assert!(!u24(0).is_power_of_two());
+        assert!(u24(1).is_power_of_two());
+        assert!(u24(0x80_0000).is_power_of_two());
+        assert!(!u24(0x80_0001).is_power_of_two());
+        assert_eq!(u24(0).next_power_of_two(), u24(1));
+        assert_eq!(u24(1).next_power_of_two(), u24(1));
+        assert_eq!(u24(3).next_power_of_two(), u24(4));
+        assert_eq!(u24(0x40_0001).next_power_of_two(), u24(0x80_0000));
+        assert_eq!(
+            u24(0x80_0000).checked_next_power_of_two(),
+            Some(u24(0x80_0000))
+        );
+        assert_eq!(u24(0x80_0001).checked_next_power_of_two(), None);
+        assert_eq!(U128::MAX.checked_next_power_of_two(), None);
+    }
+}
+
+mod parse {
+    use crate::*;
+    use core::str::FromStr;
+
+    fn kind_of<T: FromStr<Err = ParseIntError>>(src: &str) -> IntErrorKind {
+        *T::from_str(src).err().unwrap().kind()
+    }
+
+    #[test]
+    fn from_str_works() {
+        assert_eq!("0".parse::<U24>(), Ok(U24::MIN));
+        assert_eq!("16777215".parse::<U24>(), Ok(U24::MAX));
+        assert_eq!("+42".parse::<U24>(), Ok(U24::try_from(42_u32).unwrap()));
+        assert_eq!("-8388608".parse::<I24>(), Ok(I24::MIN));
+        assert_eq!("8388607".parse::<I24>(), Ok(I24::MAX));
+        assert_eq!(
+            "-170141183460469231731687303715884105728".parse::<I128>(),
+            Ok(I128::MIN)
+        );
+        assert_eq!(
+            "340282366920938463463374607431768211455".parse::<U128>(),
+            Ok(U128::MAX)
+        );
+    }
+
+    #[test]
+    fn from_str_radix_works() {
+        assert_eq!(U24::from_str_radix("FFFFFF", 16), Ok(U24::MAX));
+        assert_eq!(U24::from_str_radix("ffffff", 16), Ok(U24::MAX));
+        assert_eq!(I24::from_str_radix("-800000", 16), Ok(I24::MIN));
+        assert_eq!(
+            U40::from_str_radix("1010", 2),
+            Ok(U40::try_from(10_u64).unwrap())
+        );
+        assert_eq!(
+            U48::from_str_radix("zz", 36),
+            Ok(U48::try_from(36 * 36 - 1_u64).unwrap())
+        );
+        assert_eq!(
+            *U24::from_str_radix("1000000", 16).unwrap_err().kind(),
+            IntErrorKind::PosOverflow
+        );
+    }
+
+    #[test]
+    fn errors_are_precise() {
+        assert_eq!(kind_of::<U24>(""), IntErrorKind::Empty);
+        assert_eq!(kind_of::<I24>("-"), IntErrorKind::InvalidDigit);
+        assert_eq!(kind_of::<U24>("12a"), IntErrorKind::InvalidDigit);
+        assert_eq!(kind_of::<U24>(" 1"), IntErrorKind::InvalidDigit);
+        assert_eq!(kind_of::<U24>("-1"), IntErrorKind::InvalidDigit);
+        // Out of bounds for 24 bits but within bounds for the `u32` `Repr`.
+        assert_eq!(kind_of::<U24>("16777216"), IntErrorKind::PosOverflow);
+        assert_eq!(kind_of::<I24>("8388608"), IntErrorKind::PosOverflow);
+        assert_eq!(kind_of::<I24>("-8388609"), IntErrorKind::NegOverflow);
+        // Out of bounds for the `Repr` as well.
+        assert_eq!(kind_of::<U24>("99999999999"), IntErrorKind::PosOverflow);
+        assert_eq!(kind_of::<I24>("-99999999999"), IntErrorKind::NegOverflow);
+        assert_eq!(
+            kind_of::<U128>("340282366920938463463374607431768211456"),
+            IntErrorKind::PosOverflow
+        );
+    }
+
+    #[test]
+    fn error_display_works() {
+        extern crate std;
+        use std::string::ToString;
+        assert_eq!(
+            "".parse::<U24>().unwrap_err().to_string(),
+            "cannot parse integer from empty string"
+        );
+        assert_eq!(
+            "x".parse::<U24>().unwrap_err().to_string(),
+            "invalid digit found in string"
+        );
+        assert_eq!(
+            "16777216".parse::<U24>().unwrap_err().to_string(),
+            "number too large to fit in target type"
+        );
+        assert_eq!(
+            "-8388609".parse::<I24>().unwrap_err().to_string(),
+            "number too small to fit in target type"
+        );
+    }
+}
+
+mod size {
+    use crate::*;
+
+    macro_rules! test_size_conversions {
+        ( $( fn $test_name:ident for $ty:ty as $signedness:ident );* $(;)? ) => {
+            $(
+                #[test]
+                fn $test_name() {
+                    let signed = stringify!($signedness) == "signed";
+                    let value_bits = <$ty>::BITS - u32::from(signed);
+                    // Converting from pointer-sized primitives.
+                    assert_eq!(
+                        <$ty>::try_from(usize::MAX).is_ok(),
+                        usize::BITS <= value_bits,
+                    );
+                    assert_eq!(
+                        <$ty>::try_from(isize::MAX).is_ok(),
+                        isize::BITS - 1 <= value_bits,
+                    );
+                    assert_eq!(
+                        <$ty>::try_from(isize::MIN).is_ok(),
+                        signed && isize::BITS <= <$ty>::BITS,
+                    );
+                    assert_eq!(<$ty>::try_from(42_usize), Ok(<$ty>::from(42_u16)));
+                    assert_eq!(<$ty>::try_from(42_isize), Ok(<$ty>::from(42_u16)));
+                    // Converting into pointer-sized primitives.
+                    assert_eq!(
+                        usize::try_from(<$ty>::MAX).is_ok(),
+                        value_bits <= usize::BITS,
+                    );
+                    assert_eq!(
+                        isize::try_from(<$ty>::MAX).is_ok(),
+                        value_bits < isize::BITS,
+                    );
+                    assert_eq!(usize::try_from(<$ty>::MIN).is_ok(), !signed);
+                    assert_eq!(
+                        isize::try_from(<$ty>::MIN).is_ok(),
+                        !signed || <$ty>::BITS <= isize::BITS,
+                    );
+                    assert_eq!(usize::try_from(<$ty>::from(42_u16)), Ok(42));
+                    assert_eq!(isize::try_from(<$ty>::from(42_u16)), Ok(42));
+                }
+            )*
+        };
+    }
+    test_size_conversions! {
+        fn u32_size_conversions for U32 as unsigned;
+        fn i32_size_conversions for I32 as signed;
+        fn u40_size_conversions for U40 as unsigned;
+        fn i40_size_conversions for I40 as signed;
+        fn u64_size_conversions for U64 as unsigned;
+        fn i64_size_conversions for I64 as signed;
+        fn u72_size_conversions for U72 as unsigned;
+        fn i72_size_conversions for I72 as signed;
+        fn u128_size_conversions for U128 as unsigned;
+        fn i128_size_conversions for I128 as signed;
+    }
+
+    #[test]
+    fn small_size_conversions() {
+        assert_eq!(usize::from(U16::MAX), 0xFFFF);
+        assert_eq!(isize::from(I16::MIN), -0x8000);
+        assert_eq!(usize::try_from(U24::MAX), Ok(0xFF_FFFF));
+        assert_eq!(isize::try_from(I24::MIN), Ok(-0x80_0000));
+        assert!(usize::try_from(I24::try_from(-1_i32).unwrap()).is_err());
+        assert!(U24::try_from(0x100_0000_usize).is_err());
+        assert!(U24::try_from(-1_isize).is_err());
+        assert_eq!(I24::try_from(-1_isize), Ok(I24::try_from(-1_i32).unwrap()));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn pointer_width_64() {
+        assert!(U56::try_from(usize::MAX).is_err());
+        assert_eq!(U64::from(usize::MAX), U64::MAX);
+        assert_eq!(I64::from(isize::MIN), I64::MIN);
+        assert_eq!(usize::try_from(U64::MAX), Ok(usize::MAX));
+        assert!(usize::try_from(U72::from(U64::MAX) + U72::from(1_u8)).is_err());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn pointer_width_32() {
+        assert_eq!(U32::try_from(usize::MAX), Ok(U32::MAX));
+        assert_eq!(I32::try_from(isize::MIN), Ok(I32::MIN));
+        assert_eq!(U40::try_from(usize::MAX), Ok(U40::from(u32::MAX)));
+        assert!(usize::try_from(U40::from(u32::MAX) + U40::from(1_u8)).is_err());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "16")]
+    fn pointer_width_16() {
+        assert_eq!(U16::try_from(usize::MAX), Ok(U16::MAX));
+        assert_eq!(I16::try_from(isize::MIN), Ok(I16::MIN));
+        assert!(usize::try_from(U24::from(u16::MAX) + U24::from(1_u8)).is_err());
+    }
+}
+
+mod const_fn {
+    use crate::*;
+    use core::cmp::Ordering;
+
+    const OFFSET: U40 = match U40::checked_from_u64(0x12_3456_789A) {
+        Some(value) => value,
+        None => panic!("out of bounds"),
+    };
+    const OPCODES: [U24; 3] = [
+        U24::from_u8_const(1),
+        U24::from_u16_const(0xFFFF),
+        U24::from_le_bytes([0x56, 0x34, 0x12]),
+    ];
+    const BIG: U72 = U72::from_u64_const(u64::MAX);
+    const NEGATIVE: I48 = I48::from_i32_const(-42);
+    const SUM: Option<U24> = OPCODES[0].checked_add(OPCODES[1]);
+    const OVERFLOW: Option<U24> = U24::MAX.checked_add(OPCODES[0]);
+    const ORDERING: Ordering = NEGATIVE.const_cmp(I48::MIN);
+    const _: () = assert!(OPCODES[2].const_eq(U24::from_be_bytes([0x12, 0x34, 0x56])));
+    const BITS: u32 = OFFSET.count_ones();
+    const PARSED: Result<U24, ParseIntError> = U24::from_str_radix("123456", 16);
+
+    #[test]
+    fn const_evaluation_works() {
+        assert_eq!(OFFSET, U40::try_from(0x12_3456_789A_u64).unwrap());
+        assert_eq!(OFFSET.to_repr(), 0x12_3456_789A_u64);
+        assert_eq!(OPCODES[1], U24::from(0xFFFF_u16));
+        assert_eq!(OPCODES[2].to_repr(), 0x12_3456);
+        assert_eq!(OPCODES[2].to_be_bytes(), [0x12, 0x34, 0x56]);
+        assert_eq!(BIG.to_repr(), u128::from(u64::MAX));
+        assert_eq!(NEGATIVE.to_repr(), -42_i64);
+        assert_eq!(SUM, Some(U24::try_from(0x1_0000_u32).unwrap()));
+        assert_eq!(OVERFLOW, None);
+        assert_eq!(ORDERING, Ordering::Greater);
+        assert_eq!(BITS, 0x12_3456_789A_u64.count_ones());
+        assert_eq!(PARSED, Ok(OPCODES[2]));
+    }
+
+    #[test]
+    fn checked_from_works() {
+        assert_eq!(U24::checked_from_u32(0xFF_FFFF), Some(U24::MAX));
+        assert_eq!(U24::checked_from_u32(0x100_0000), None);
+        assert_eq!(U24::checked_from_i8(-1), None);
+        assert_eq!(I24::checked_from_i32(-0x80_0000), Some(I24::MIN));
+        assert_eq!(I24::checked_from_i32(-0x80_0001), None);
+        assert_eq!(I24::checked_from_u128(0x7F_FFFF), Some(I24::MAX));
+        assert_eq!(I24::checked_from_u128(0x80_0000), None);
+        assert_eq!(U128::checked_from_u128(u128::MAX), Some(U128::MAX));
+        assert_eq!(I128::checked_from_i128(i128::MIN), Some(I128::MIN));
+        assert_eq!(I128::checked_from_u128(u128::MAX), None);
+        assert_eq!(U16::checked_from_usize(0xFFFF), Some(U16::MAX));
+        assert_eq!(I16::checked_from_isize(-1), Some(I16::from(-1_i16)));
+    }
+
+    #[test]
+    fn const_cmp_matches_ord() {
+        let values = [
+            I24::MIN,
+            I24::from(-1_i16),
+            I24::from(0_i8),
+            I24::from(1_i8),
+            I24::MAX,
+        ];
+        for lhs in values {
+            for rhs in values {
+                assert_eq!(lhs.const_cmp(rhs), lhs.cmp(&rhs));
+                assert_eq!(lhs.const_eq(rhs), lhs == rhs);
+            }
+        }
+    }
+
+    #[test]
+    fn literal_macros_work() {
+        const OPCODE: U24 = crate::u24!(0xFF_FFFF);
+        assert_eq!(OPCODE, U24::MAX);
+        assert_eq!(crate::u16!(0), U16::default());
+        assert_eq!(crate::i24!(-0x80_0000), I24::MIN);
+        assert_eq!(i64::from(crate::i48!(-5)), -5);
+        assert_eq!(u64::from(crate::u40!(0xFF_FFFF_FFFF)), 0xFF_FFFF_FFFF);
+        assert_eq!(crate::u128!(u128::MAX), U128::MAX);
+        assert_eq!(crate::i128!(i128::MIN), I128::MIN);
+    }
+
+    #[test]
+    fn new_const_works() {
+        assert_eq!(U24::new_const(0xFF_FFFF), U24::MAX);
+        assert_eq!(I24::new_const(-0x80_0000), I24::MIN);
+        assert_eq!(I56::new_const(-1), I56::from(-1_i8));
+    }
+
+    #[test]
+    #[should_panic(expected = "value is out of bounds for U24")]
+    fn new_const_panics_out_of_bounds() {
+        U24::new_const(0x100_0000);
+    }
+
+    #[test]
+    #[should_panic(expected = "value is out of bounds for I24")]
+    fn new_const_panics_out_of_bounds_signed() {
+        I24::new_const(0x80_0000);
+    }
+}
+
+mod traits {
+    use super::*;
+
+    fn roundtrip_le<T: UnalignedInteger>(value: T) -> T {
+        T::from_le_bytes(value.to_le_bytes())
+    }
+
+    fn roundtrip_be<T: UnalignedInteger>(value: T) -> T {
+        T::from_be_bytes(value.to_be_bytes())
+    }
+
+    fn bounds<T: UnalignedInteger>() -> (T::Repr, T::Repr) {
+        (T::MIN.to_repr(), T::MAX.to_repr())
+    }
+
+    fn negative_count<T: Signed>(values: &[T]) -> usize {
+        values.iter().filter(|value| value.is_negative()).count()
+    }
+
+    #[test]
+    fn associated_consts_work() {
+        assert_eq!(<U24 as UnalignedInteger>::BITS, 24);
+        assert_eq!(<U24 as UnalignedInteger>::BYTES, 3);
+        const _: () = assert!(!<U24 as UnalignedInteger>::IS_SIGNED);
+        assert_eq!(<I40 as UnalignedInteger>::BITS, 40);
+        assert_eq!(<I40 as UnalignedInteger>::BYTES, 5);
+        const _: () = assert!(<I40 as UnalignedInteger>::IS_SIGNED);
+        assert_eq!(<U128 as UnalignedInteger>::BYTES, 16);
+        assert_eq!(<I128 as UnalignedInteger>::MIN, I128::MIN);
+    }
+
+    #[test]
+    fn repr_conversions_work() {
+        assert_eq!(bounds::<U24>(), (0, 0xFF_FFFF));
+        assert_eq!(bounds::<I24>(), (-0x80_0000, 0x7F_FFFF));
+        assert_eq!(bounds::<U128>(), (u128::MIN, u128::MAX));
+        assert_eq!(
+            <U24 as UnalignedInteger>::from_repr_truncating(0x1234_5678),
+            U24::new_const(0x34_5678),
+        );
+        assert_eq!(<I24 as UnalignedInteger>::to_repr(I24::MIN), -0x80_0000);
+    }
+
+    #[test]
+    fn byte_conversions_work() {
+        assert_eq!(roundtrip_le(U24::MAX), U24::MAX);
+        assert_eq!(roundtrip_be(I40::MIN), I40::MIN);
+        assert_eq!(roundtrip_le(I56::from(-2_i8)), I56::from(-2_i8));
+        let bytes = <U24 as UnalignedInteger>::to_le_bytes(U24::new_const(0x01_0203));
+        assert_eq!(bytes, [0x03, 0x02, 0x01]);
+    }
+
+    #[test]
+    fn signed_unsigned_split_works() {
+        let values = [I24::MIN, I24::from(-1_i8), I24::new_const(0), I24::MAX];
+        assert_eq!(negative_count(&values), 2);
+        assert!(<U40 as Unsigned>::is_power_of_two(U40::from(0x80_u8)));
+        assert!(!<U40 as Unsigned>::is_power_of_two(U40::from(0x81_u8)));
+    }
+}
+
+mod ord {
+    use crate::*;
+    use core::cmp::Ordering;
+    use core::hash::{Hash, Hasher};
+
+    /// Byte values that are interesting for comparisons, e.g. with respect to the sign bit.
+    const PATTERNS: [u8; 6] = [0x00, 0x01, 0x7F, 0x80, 0xFE, 0xFF];
+
+    /// Compares every 16-bit value against a set of pivots.
+    #[test]
+    fn order_matches_repr_for_all_16_bit_values() {
+        let u16_pivots = [
+            0x0000, 0x0001, 0x00FF, 0x0100, 0x7FFF, 0x8000, 0xFF00, 0xFFFF,
+        ];
+        let i16_pivots = [i16::MIN, -0x100, -1, 0, 1, 0xFF, 0x100, i16::MAX];
+        for value in u16::MIN..=u16::MAX {
+            let lhs = U16::from(value);
+            for pivot in u16_pivots {
+                let rhs = U16::from(pivot);
+                assert_eq!(lhs.cmp(&rhs), value.cmp(&pivot));
+                assert_eq!(lhs.const_cmp(rhs), value.cmp(&pivot));
+                assert_eq!(lhs.partial_cmp(&rhs), value.partial_cmp(&pivot));
+                assert_eq!(lhs < rhs, value < pivot);
+                assert_eq!(lhs >= rhs, value >= pivot);
+            }
+            if let Some(next) = value.checked_add(1) {
+                assert!(lhs < U16::from(next));
+            }
+        }
+        for value in i16::MIN..=i16::MAX {
+            let lhs = I16::from(value);
+            for pivot in i16_pivots {
+                let rhs = I16::from(pivot);
+                assert_eq!(lhs.cmp(&rhs), value.cmp(&pivot));
+                assert_eq!(lhs.const_cmp(rhs), value.cmp(&pivot));
+                assert_eq!(lhs.partial_cmp(&rhs), value.partial_cmp(&pivot));
+                assert_eq!(lhs <= rhs, value <= pivot);
+                assert_eq!(lhs > rhs, value > pivot);
+            }
+            if let Some(next) = value.checked_add(1) {
+                assert!(lhs < I16::from(next));
+            }
+        }
+    }
+
+    #[test]
+    fn order_matches_successor_for_all_24_bit_values() {
+        for repr in U24::MIN.to_repr()..U24::MAX.to_repr() {
+            let (value, succ) = (U24::new_const(repr), U24::new_const(repr + 1));
+            assert_eq!(value.cmp(&succ), Ordering::Less);
+            assert_eq!(value.const_cmp(succ), Ordering::Less);
+            assert_eq!(succ.const_cmp(value), Ordering::Greater);
+        }
+        for repr in I24::MIN.to_repr()..I24::MAX.to_repr() {
+            let (value, succ) = (I24::new_const(repr), I24::new_const(repr + 1));
+            assert_eq!(value.cmp(&succ), Ordering::Less);
+            assert_eq!(value.const_cmp(succ), Ordering::Less);
+            assert_eq!(succ.const_cmp(value), Ordering::Greater);
+        }
+    }
+
+    macro_rules! test_order_matches_repr {
+        ( $( $ty:ty ),* $(,)? ) => {{
+            $(
+                let mut samples = [<$ty>::default(); PATTERNS.len() * PATTERNS.len() * 2];
+                let mut n = 0;
+                for msb in PATTERNS {
+                    for lsb in PATTERNS {
+                        for mid in [msb, lsb] {
+                            let mut bytes = [mid; ::core::mem::size_of::<$ty>()];
+                            bytes[0] = lsb;
+                            bytes[::core::mem::size_of::<$ty>() - 1] = msb;
+                            samples[n] = <$ty>::from_le_bytes(bytes);
+                            n += 1;
+                        }
+                    }
+                }
+                for lhs in samples {
+                    for rhs in samples {
+                        assert_eq!(lhs.cmp(&rhs), lhs.to_repr().cmp(&rhs.to_repr()));
+                        assert_eq!(lhs < rhs, lhs.to_repr() < rhs.to_repr());
+                        assert_eq!(lhs.max(rhs).to_repr(), lhs.to_repr().max(rhs.to_repr()));
+                    }
+                }
+            )*
+        }};
+    }
+
+    #[test]
+    fn order_matches_repr() {
+        test_order_matches_repr!(
+            U16, I16, U24, I24, U32, I32, U40, I40, U48, I48, U56, I56, U64, I64, U72, I72, U80,
+            I80, U88, I88, U96, I96, U104, I104, U112, I112, U120, I120, U128, I128
+        );
+    }
+
+    /// Hasher that records the bytes written to it.
+    #[derive(Default)]
+    struct RecordingHasher {
+        bytes: [u8; 32],
+        len: usize,
+    }
+
+    impl Hasher for RecordingHasher {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.bytes[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+            self.len += bytes.len();
+        }
+    }
+
+    #[test]
+    fn hash_writes_raw_bytes() {
+        let value = U40::from_le_bytes([0x01, 0x02, 0x03, 0x04, 0x05]);
+        let mut hasher = RecordingHasher::default();
+        value.hash(&mut hasher);
+        assert_eq!(&hasher.bytes[..hasher.len], &value.to_ne_bytes());
+        let value = I24::MIN;
+        let mut hasher = RecordingHasher::default();
+        value.hash(&mut hasher);
+        assert_eq!(&hasher.bytes[..hasher.len], &value.to_ne_bytes());
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use crate::*;
+
+    #[test]
+    fn json_uses_numbers() {
+        assert_eq!(serde_json::to_string(&U24::MAX).unwrap(), "16777215");
+        assert_eq!(serde_json::to_string(&I24::MIN).unwrap(), "-8388608");
+        assert_eq!(
+            serde_json::to_string(&I64::MIN).unwrap(),
+            "-9223372036854775808"
+        );
+        assert_eq!(serde_json::from_str::<U24>("16777215").unwrap(), U24::MAX);
+        assert_eq!(serde_json::from_str::<I24>("-8388608").unwrap(), I24::MIN);
+        assert_eq!(
+            serde_json::from_str::<U64>("18446744073709551615").unwrap(),
+            U64::MAX
+        );
+    }
+
+    #[test]
+    fn json_uses_strings_for_wide_integers() {
+        assert_eq!(
+            serde_json::to_string(&U72::MAX).unwrap(),
+            "\"4722366482869645213695\"",
+        );
+        assert_eq!(
+            serde_json::to_string(&I128::MIN).unwrap(),
+            "\"-170141183460469231731687303715884105728\"",
+        );
+        assert_eq!(
+            serde_json::from_str::<U72>("\"4722366482869645213695\"").unwrap(),
+            U72::MAX,
+        );
+        assert_eq!(serde_json::from_str::<I80>("-5").unwrap(), I80::from(-5_i8));
+        assert_eq!(
+            serde_json::from_str::<U128>("42").unwrap(),
+            U128::from(42_u8)
+        );
+    }
+
+    #[test]
+    fn json_uses_strings_for_integers_wider_than_128_bits() {
+        let i256_min =
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+        assert_eq!(
+            serde_json::to_string(&I256::MIN).unwrap(),
+            ["\"", i256_min, "\""].concat(),
+        );
+        assert_eq!(
+            serde_json::from_str::<I256>(&["\"", i256_min, "\""].concat()).unwrap(),
+            I256::MIN,
+        );
+        assert_eq!(
+            serde_json::from_str::<U136>("42").unwrap(),
+            U136::from(42_u8)
+        );
+        assert!(serde_json::from_str::<U136>("-1").is_err());
+        let bytes = bincode::serialize(&U256::MAX).unwrap();
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(bincode::deserialize::<U256>(&bytes).unwrap(), U256::MAX);
+    }
+
+    #[test]
+    fn json_rejects_out_of_bounds() {
+        assert!(serde_json::from_str::<U24>("16777216").is_err());
+        assert!(serde_json::from_str::<U24>("-1").is_err());
+        assert!(serde_json::from_str::<I24>("8388608").is_err());
+        assert!(serde_json::from_str::<I24>("-8388609").is_err());
+        assert!(serde_json::from_str::<U72>("\"4722366482869645213696\"").is_err());
+        assert!(serde_json::from_str::<I72>("\"abc\"").is_err());
+        assert!(serde_json::from_str::<U40>("\"5\"").is_err());
+    }
+
+    #[test]
+    fn postcard_uses_size_of_bytes() {
+        let mut buffer = [0x00_u8; 32];
+        let value = U24::from_le_bytes([0x01, 0x02, 0x03]);
+        let bytes = postcard::to_slice(&value, &mut buffer).unwrap();
+        assert_eq!(bytes, &[0x01, 0x02, 0x03]);
+        assert_eq!(postcard::from_bytes::<U24>(bytes).unwrap(), value);
+        let bytes = postcard::to_slice(&I120::MIN, &mut buffer).unwrap();
+        assert_eq!(bytes.len(), 15);
+        assert_eq!(postcard::from_bytes::<I120>(bytes).unwrap(), I120::MIN);
+        assert!(postcard::from_bytes::<U24>(&[0x01, 0x02]).is_err());
+    }
+
+    #[test]
+    fn bincode_uses_size_of_bytes() {
+        let value = I40::from_le_bytes([0x01, 0x02, 0x03, 0x04, 0x85]);
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes[..], [0x01, 0x02, 0x03, 0x04, 0x85]);
+        assert_eq!(bincode::deserialize::<I40>(&bytes).unwrap(), value);
+        assert_eq!(bincode::serialize(&U24::MAX).unwrap().len(), 3);
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck {
+    use crate::*;
+
+    #[test]
+    fn cast_slice_works() {
+        let bytes = [0x01, 0x02, 0x03, 0xFF, 0xFF, 0xFF];
+        let ints: &[U24] = ::bytemuck::cast_slice(&bytes);
+        assert_eq!(ints, &[U24::from_le_bytes([0x01, 0x02, 0x03]), U24::MAX]);
+        let ints: &[I24] = ::bytemuck::cast_slice(&bytes);
+        assert_eq!(ints[1], I24::from(-1_i8));
+        let back: &[u8] = ::bytemuck::cast_slice(ints);
+        assert_eq!(back, &bytes);
+        assert!(::bytemuck::try_cast_slice::<u8, U40>(&bytes).is_err());
+    }
+
+    #[test]
+    fn cast_slice_mut_works() {
+        let mut ints = [U40::MIN; 2];
+        let bytes: &mut [u8] = ::bytemuck::cast_slice_mut(&mut ints);
+        bytes[5..].copy_from_slice(&[0xFF; 5]);
+        assert_eq!(ints, [U40::MIN, U40::MAX]);
+        assert_eq!(<I56 as ::bytemuck::Zeroable>::zeroed(), I56::default());
+    }
+}
+
+#[cfg(feature = "num-traits")]
+mod num_traits {
+    use crate::*;
+    use ::num_traits::{
+        Bounded, CheckedNeg, FromPrimitive, Num, One, PrimInt, Saturating, ToPrimitive,
+        WrappingAdd, WrappingShl, Zero,
+    };
+
+    /// Sums `values` generically over `num_traits::PrimInt`.
+    fn checked_sum<T: PrimInt>(values: &[T]) -> Option<T> {
+        values
+            .iter()
+            .try_fold(T::zero(), |sum, value| sum.checked_add(value))
+    }
+
+    #[test]
+    fn prim_int_works() {
+        assert_eq!(
+            checked_sum(&[U24::MAX - U24::one(), U24::one()]),
+            Some(U24::MAX)
+        );
+        assert_eq!(checked_sum(&[U24::MAX, U24::one()]), None);
+        assert_eq!(checked_sum(&[I40::min_value(), -I40::one()]), None);
+        assert_eq!(PrimInt::count_ones(U24::MAX), 24);
+        assert_eq!(PrimInt::leading_zeros(I48::one()), 47);
+        assert_eq!(
+            PrimInt::pow(U40::from(2_u8), 39),
+            U40::MAX / U40::from(2_u8) + U40::one()
+        );
+        assert_eq!(PrimInt::signed_shr(U24::MAX, 4), U24::MAX);
+        assert_eq!(
+            PrimInt::unsigned_shr(I24::from(-1_i8), 4),
+            I24::MAX >> 3_u32
+        );
+        assert_eq!(PrimInt::signed_shl(I24::one(), 23), I24::MIN);
+        assert_eq!(
+            PrimInt::to_be(U24::new_const(0x01_0203)).to_ne_bytes(),
+            [0x01, 0x02, 0x03]
+        );
+        assert_eq!(
+            PrimInt::to_le(U24::new_const(0x01_0203)).to_ne_bytes(),
+            [0x03, 0x02, 0x01]
+        );
+        assert_eq!(<U56 as PrimInt>::from_le(U56::MAX.to_le()), U56::MAX);
+    }
+
+    #[test]
+    fn num_works() {
+        assert!(U72::zero().is_zero());
+        assert!(!I72::one().is_zero());
+        assert_eq!(<U24 as Bounded>::max_value(), U24::MAX);
+        assert_eq!(
+            <I24 as Num>::from_str_radix("-7f", 16),
+            Ok(I24::from(-127_i8))
+        );
+        assert_eq!(
+            <U24 as Num>::from_str_radix("1000000", 16)
+                .unwrap_err()
+                .kind(),
+            &IntErrorKind::PosOverflow
+        );
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(U24::MAX.to_u32(), Some(0xFF_FFFF));
+        assert_eq!(U24::MAX.to_u16(), None);
+        assert_eq!(I48::from(-1_i8).to_u64(), None);
+        assert_eq!(I48::MIN.to_i64(), Some(-(1_i64 << 47)));
+        assert_eq!(U128::MAX.to_u128(), Some(u128::MAX));
+        assert_eq!(ToPrimitive::to_f32(&I24::MIN), Some(-8_388_608.0));
+        assert_eq!(U24::from_u64(0xFF_FFFF), Some(U24::MAX));
+        assert_eq!(U24::from_u64(0x100_0000), None);
+        assert_eq!(I24::from_i64(-8_388_609), None);
+        assert_eq!(U40::from_f64(2.9), Some(U40::from(2_u8)));
+        assert_eq!(U40::from_f64(-1.0), None);
+        assert_eq!(<U24 as ::num_traits::NumCast>::from(-1_i32), None);
+        assert_eq!(
+            <I24 as ::num_traits::NumCast>::from(-1_i32),
+            Some(I24::from(-1_i8))
+        );
+        assert_eq!(<I24 as ::num_traits::NumCast>::from(u128::MAX), None);
+        assert_eq!(<U24 as ::num_traits::NumCast>::from(f64::NAN), None);
+        assert_eq!(<I96 as ::num_traits::NumCast>::from(U96::MAX), None);
+        assert_eq!(
+            <U96 as ::num_traits::NumCast>::from(I96::MAX),
+            Some(U96::MAX >> 1_u32)
+        );
+    }
+
+    #[test]
+    fn checked_wrapping_saturating_works() {
+        assert_eq!(CheckedNeg::checked_neg(&U24::one()), None);
+        assert_eq!(CheckedNeg::checked_neg(&I24::one()), Some(I24::from(-1_i8)));
+        assert_eq!(WrappingAdd::wrapping_add(&U24::MAX, &U24::one()), U24::MIN);
+        assert_eq!(WrappingShl::wrapping_shl(&U24::one(), 25), U24::from(2_u8));
+        assert_eq!(Saturating::saturating_add(I24::MAX, I24::one()), I24::MAX);
+        assert_eq!(Saturating::saturating_sub(I24::MIN, I24::one()), I24::MIN);
+    }
+
+    #[test]
+    fn signed_works() {
+        use ::num_traits::Signed as _;
+        // Note: the crate-internal inherent `is_positive` would shadow `Signed::is_positive`.
+        let is_positive = <I24 as ::num_traits::Signed>::is_positive;
+        assert_eq!(I24::from(-5_i8).abs(), I24::from(5_i8));
+        assert_eq!(I24::from(-5_i8).signum(), I24::from(-1_i8));
+        assert_eq!(I24::zero().signum(), I24::zero());
+        assert_eq!(I24::MAX.signum(), I24::one());
+        assert!(!is_positive(&I24::zero()));
+        assert!(is_positive(&I24::one()));
+        assert!(!I24::zero().is_negative());
+        assert!(I24::MIN.is_negative());
+        assert_eq!(I24::from(3_i8).abs_sub(&I24::from(5_i8)), I24::zero());
+        assert_eq!(I24::from(5_i8).abs_sub(&I24::from(3_i8)), I24::from(2_i8));
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy {
+    use crate::*;
+    use ::zerocopy::{FromBytes, IntoBytes};
+
+    #[test]
+    fn ref_from_bytes_works() {
+        let bytes = [0x01, 0x02, 0x03, 0xFF, 0xFF, 0xFF];
+        let ints = <[U24]>::ref_from_bytes(&bytes[..]).unwrap();
+        assert_eq!(ints, &[U24::from_le_bytes([0x01, 0x02, 0x03]), U24::MAX]);
+        assert_eq!(ints.as_bytes(), &bytes);
+        let int = I48::ref_from_bytes(&bytes[..]).unwrap();
+        assert_eq!(int.as_bytes(), &bytes);
+        assert!(<[U40]>::ref_from_bytes(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn mut_from_bytes_works() {
+        let mut bytes = [0x00_u8; 10];
+        let ints = <[I40]>::mut_from_bytes(&mut bytes[..]).unwrap();
+        ints[1] = I40::MIN;
+        assert_eq!(bytes[5..], I40::MIN.to_ne_bytes());
+        let (int, rest) = U24::read_from_prefix(&bytes[..]).unwrap();
+        assert_eq!(int, U24::MIN);
+        assert_eq!(rest.len(), 7);
+    }
+}
+
+mod endian {
+    use crate::*;
+
+    #[test]
+    fn storage_is_fixed_endian() {
+        let value = U24::new_const(0x01_0203);
+        assert_eq!(U24Le::new(value).to_bytes(), [0x03, 0x02, 0x01]);
+        assert_eq!(U24Be::new(value).to_bytes(), [0x01, 0x02, 0x03]);
+        assert_eq!(U24Le::from_bytes([0x03, 0x02, 0x01]).get(), value);
+        assert_eq!(U24Be::from_bytes([0x01, 0x02, 0x03]).get(), value);
+        assert_eq!(
+            I48Be::new(I48::from(-2_i8)).to_bytes(),
+            [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]
+        );
+        assert_eq!(core::mem::size_of::<U24Be>(), 3);
+        assert_eq!(core::mem::align_of::<I128Le>(), 1);
+    }
+
+    #[test]
+    fn conversions_work() {
+        let value = I40::from(-42_i8);
+        assert_eq!(I40::from(I40Le::from(value)), value);
+        assert_eq!(I40::from(I40Be::from(value)), value);
+        assert_eq!(i64::from(I40Be::from(value)), -42);
+        assert_eq!(U24Le::try_from(0xFF_FFFF_u32), Ok(U24Le::MAX));
+        assert!(U24Be::try_from(0x100_0000_u32).is_err());
+        assert_eq!("-42".parse::<I40Be>(), Ok(I40Be::new(value)));
+        let mut stored = U56Be::default();
+        stored.set(U56::MAX);
+        assert_eq!(stored, U56Be::MAX);
+    }
+
+    #[test]
+    fn primitive_conversions_work() {
+        assert_eq!(U24Le::from(0xFFFF_u16), U24Le::new(U24::from(0xFFFF_u16)));
+        assert_eq!(I24Be::from(-1_i8), I24Be::new(I24::from(-1_i8)));
+        assert_eq!(u64::from(U24Be::MAX), 0xFF_FFFF);
+        assert_eq!(i128::from(I72Le::MIN), -(1_i128 << 71));
+        assert!(U24Le::try_from(-1_i32).is_err());
+        assert_eq!(u16::try_from(U24Be::from(7_u8)), Ok(7));
+        assert!(u16::try_from(U24Le::MAX).is_err());
+    }
+
+    #[test]
+    fn ops_work() {
+        let (lhs, rhs) = (U24Le::from(40_u8), U24Le::from(2_u8));
+        assert_eq!(lhs + rhs, U24Le::from(42_u8));
+        assert_eq!(lhs - rhs, U24Le::from(38_u8));
+        assert_eq!(lhs * rhs, U24Le::from(80_u8));
+        assert_eq!(lhs / rhs, U24Le::from(20_u8));
+        assert_eq!(lhs % U24Le::from(3_u8), U24Le::from(1_u8));
+        assert_eq!(lhs & rhs, U24Le::default());
+        assert_eq!(lhs | rhs, U24Le::from(42_u8));
+        assert_eq!(lhs ^ lhs, U24Le::default());
+        assert_eq!(!U24Be::default(), U24Be::MAX);
+        assert_eq!(U24Be::MAX >> 20_u32, U24Be::from(0xF_u8));
+        assert_eq!(
+            U24Be::from(1_u8) << 23_i32,
+            U24Be::new(U24::new_const(0x80_0000))
+        );
+        assert_eq!(-I40Be::from(5_i8), I40Be::from(-5_i8));
+        let mut value = I40Le::from(-1_i8);
+        value += I40Le::from(2_i8);
+        value <<= 4_u8;
+        value -= &I40Le::from(1_i8);
+        assert_eq!(value, I40Le::from(15_i8));
+    }
+
+    #[test]
+    fn unaligned_integer_works() {
+        let value = U24Be::new(U24::new_const(0x01_0203));
+        assert_eq!(<U24Be as UnalignedInteger>::to_repr(value), 0x01_0203_u32);
+        assert_eq!(
+            <U24Be as UnalignedInteger>::to_le_bytes(value),
+            [0x03, 0x02, 0x01]
+        );
+        assert_eq!(
+            <U24Le as UnalignedInteger>::from_be_bytes([0x01, 0x02, 0x03]).get(),
+            value.get()
+        );
+        assert_eq!(
+            <I40Le as UnalignedInteger>::from_repr_truncating(-1_i64),
+            I40Le::from(-1_i8)
+        );
+        assert_eq!(<I40Be as UnalignedInteger>::MIN, I40Be::MIN);
+        assert_eq!(<I40Be as UnalignedInteger>::BYTES, 5);
+        assert!(<I40Le as Signed>::is_negative(I40Le::MIN));
+        assert!(<U24Le as Unsigned>::is_power_of_two(U24Le::from(64_u8)));
+        let bytes = [0x01, 0x02, 0x03, 0xFF, 0xFE];
+        let mut reader = ByteReader::<BigEndian>::new(&bytes);
+        assert_eq!(reader.read::<U24Le>(), Ok(U24Le::new(value.get())));
+        assert_eq!(reader.read::<I16Be>(), Ok(I16Be::from(-2_i8)));
+    }
+
+    #[test]
+    fn order_matches_native() {
+        let values = [
+            I24::MIN,
+            I24::from(-0x100_i16),
+            I24::from(-1_i8),
+            I24::from(0_i8),
+            I24::from(1_i8),
+            I24::from(0x100_i16),
+            I24::MAX,
+        ];
+        for lhs in values {
+            for rhs in values {
+                assert_eq!(I24Le::new(lhs).cmp(&I24Le::new(rhs)), lhs.cmp(&rhs));
+                assert_eq!(I24Be::new(lhs).cmp(&I24Be::new(rhs)), lhs.cmp(&rhs));
+                let (lhs, rhs) = (
+                    U24::from_ne_bytes(lhs.to_ne_bytes()),
+                    U24::from_ne_bytes(rhs.to_ne_bytes()),
+                );
+                assert_eq!(U24Le::new(lhs).cmp(&U24Le::new(rhs)), lhs.cmp(&rhs));
+                assert_eq!(U24Be::new(lhs).cmp(&U24Be::new(rhs)), lhs.cmp(&rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn struct_layout_works() {
+        #[allow(dead_code)] // Note: fields are only read via bytes
+        struct Header {
+            magic: U32Be,
+            len: U24Le,
+            offset: I40Be,
+        }
+        assert_eq!(core::mem::size_of::<Header>(), 12);
+        assert_eq!(core::mem::align_of::<Header>(), 1);
+    }
+}
+
+mod generic {
+    use crate::*;
+
+    fn roundtrip_le<const N: usize>(value: UInt<N>) -> UInt<N> {
+        UInt::from_le_bytes(value.to_le_bytes())
+    }
+
+    macro_rules! test_generic_matches_concrete {
+        ( $( $ty:ident <=> $generic:ident<$n:literal> as $wide:ident, $to_wide:ident );* $(;)? ) => {
+            $(
+                assert_eq!($generic::<$n>::from(<$ty>::MIN), $generic::<$n>::MIN);
+                assert_eq!($generic::<$n>::from(<$ty>::MAX), $generic::<$n>::MAX);
+                assert_eq!($generic::<$n>::MIN.$to_wide(), <$ty>::MIN.to_repr() as $wide);
+                assert_eq!($generic::<$n>::MAX.$to_wide(), <$ty>::MAX.to_repr() as $wide);
+                assert_eq!(<$ty>::from($generic::<$n>::MAX), <$ty>::MAX);
+                assert_eq!($generic::<$n>::BITS, <$ty>::BITS);
+                assert_eq!(core::mem::size_of::<$generic<$n>>(), core::mem::size_of::<$ty>());
+            )*
+        };
+    }
+
+    #[test]
+    fn generic_matches_concrete() {
+        test_generic_matches_concrete! {
+            U16 <=> UInt<2> as u128, to_u128;
+            I16 <=> Int<2> as i128, to_i128;
+            U24 <=> UInt<3> as u128, to_u128;
+            I24 <=> Int<3> as i128, to_i128;
+            U40 <=> UInt<5> as u128, to_u128;
+            I40 <=> Int<5> as i128, to_i128;
+            U72 <=> UInt<9> as u128, to_u128;
+            I72 <=> Int<9> as i128, to_i128;
+            U128 <=> UInt<16> as u128, to_u128;
+            I128 <=> Int<16> as i128, to_i128;
+        }
+        assert_eq!(UInt::<1>::MAX.to_u128(), 0xFF);
+        assert_eq!(Int::<1>::MIN.to_i128(), -0x80);
+        assert_eq!(core::mem::align_of::<Int<7>>(), 1);
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(UInt::<3>::try_from(0xFF_FFFF_u128), Ok(UInt::<3>::MAX));
+        assert!(UInt::<3>::try_from(0x100_0000_u128).is_err());
+        assert_eq!(Int::<3>::try_from(-0x80_0000_i128), Ok(Int::<3>::MIN));
+        assert!(Int::<3>::try_from(-0x80_0001_i128).is_err());
+        assert_eq!(i128::from(Int::<5>::from(I40::from(-5_i8))), -5);
+        assert_eq!(roundtrip_le(UInt::<7>::MAX), UInt::<7>::MAX);
+        assert_eq!(
+            Int::<3>::from_be_bytes([0xFF, 0xFF, 0xFE]).to_le_bytes(),
+            [0xFE, 0xFF, 0xFF]
+        );
+    }
+
+    fn roundtrip_prim<const N: usize>(value: i64) -> Result<i64, TryFromIntError> {
+        i64::try_from(Int::<N>::try_from(value)?)
+    }
+
+    #[test]
+    fn primitive_conversions_work() {
+        assert_eq!(roundtrip_prim::<1>(-0x80), Ok(-0x80));
+        assert_eq!(roundtrip_prim::<3>(0x7F_FFFF), Ok(0x7F_FFFF));
+        assert_eq!(roundtrip_prim::<8>(i64::MIN), Ok(i64::MIN));
+        assert_eq!(roundtrip_prim::<16>(i64::MAX), Ok(i64::MAX));
+        let error = roundtrip_prim::<3>(-0x80_0001).unwrap_err();
+        assert_eq!(error.kind(), &TryFromIntErrorKind::NegOverflow);
+        assert_eq!(error.target_bits(), 24);
+        assert_eq!(UInt::<2>::try_from(0xFFFF_u16), Ok(UInt::<2>::MAX));
+        assert_eq!(
+            UInt::<2>::try_from(-1_i8).unwrap_err().kind(),
+            &TryFromIntErrorKind::SignMismatch
+        );
+        assert_eq!(
+            u8::try_from(UInt::<5>::from_le_bytes([0xFF, 0, 0, 0, 0])),
+            Ok(0xFF)
+        );
+        let error = u8::try_from(UInt::<5>::MAX).unwrap_err();
+        assert_eq!(error.kind(), &TryFromIntErrorKind::PosOverflow);
+        assert_eq!(error.target_bits(), 8);
+        assert_eq!(u128::try_from(Int::<16>::MAX), Ok(i128::MAX as u128));
+        assert_eq!(
+            u128::try_from(Int::<4>::MIN).unwrap_err().kind(),
+            &TryFromIntErrorKind::SignMismatch
+        );
+        assert_eq!(
+            Int::<16>::try_from(u128::MAX).unwrap_err().target_bits(),
+            128
+        );
+    }
+
+    #[test]
+    fn parse_and_fmt_works() {
+        assert_eq!("16777215".parse::<UInt<3>>(), Ok(UInt::<3>::MAX));
+        assert_eq!(
+            "16777216".parse::<UInt<3>>().unwrap_err().kind(),
+            &IntErrorKind::PosOverflow
+        );
+        assert_eq!(
+            "-8388609".parse::<Int<3>>().unwrap_err().kind(),
+            &IntErrorKind::NegOverflow
+        );
+        assert_eq!("-5".parse::<Int<6>>().map(i128::from), Ok(-5));
+        let mut buffer = [0x00_u8; 16];
+        let mut cursor = &mut buffer[..];
+        core::fmt::Write::write_fmt(&mut Writer(&mut cursor), format_args!("{}", Int::<3>::MIN))
+            .unwrap();
+        assert_eq!(&buffer[..8], b"-8388608");
+    }
+
+    struct Writer<'a, 'b>(&'a mut &'b mut [u8]);
+
+    impl core::fmt::Write for Writer<'_, '_> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let buffer = core::mem::take(self.0);
+            let (head, tail) = buffer.split_at_mut(s.len());
+            head.copy_from_slice(s.as_bytes());
+            *self.0 = tail;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn order_works() {
+        let values = [-0x80_0000, -0x100, -1, 0, 1, 0x100, 0x7F_FFFF];
+        for lhs in values {
+            for rhs in values {
+                let (a, b) = (
+                    Int::<3>::try_from(lhs).unwrap(),
+                    Int::<3>::try_from(rhs).unwrap(),
+                );
+                assert_eq!(a.cmp(&b), lhs.cmp(&rhs));
+                let (lhs, rhs) = (lhs as u128 & 0xFF_FFFF, rhs as u128 & 0xFF_FFFF);
+                let (a, b) = (
+                    UInt::<3>::try_from(lhs).unwrap(),
+                    UInt::<3>::try_from(rhs).unwrap(),
+                );
+                assert_eq!(a.cmp(&b), lhs.cmp(&rhs));
+            }
+        }
+    }
+}
+
+mod wide {
+    use crate::*;
+
+    /// Asserts that the formatted arguments are equal to `expected` without allocating.
+    pub(super) fn assert_fmt(args: core::fmt::Arguments, expected: &str) {
+        struct Expect<'a>(&'a str);
+
+        impl core::fmt::Write for Expect<'_> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                self.0 = self.0.strip_prefix(s).ok_or(core::fmt::Error)?;
+                Ok(())
+            }
+        }
+
+        let mut expect = Expect(expected);
+        core::fmt::write(&mut expect, args).expect("formatted string differs");
+        assert_eq!(expect.0, "", "formatted string is too short");
+    }
+
+    #[test]
+    fn arith_matches_primitives() {
+        let values = [
+            0_i128,
+            1,
+            -1,
+            7,
+            -7,
+            1 << 64,
+            -(1 << 100),
+            i128::MAX,
+            i128::MIN,
+        ];
+        for lhs in values {
+            for rhs in values {
+                let (a, b) = (I136::from(lhs), I136::from(rhs));
+                // Note: results within the bounds of `i128` must match exactly.
+                let as_i128 = |value: I136| i128::try_from(value).ok();
+                if let Some(sum) = lhs.checked_add(rhs) {
+                    assert_eq!(as_i128(a + b), Some(sum));
+                }
+                if let Some(diff) = lhs.checked_sub(rhs) {
+                    assert_eq!(as_i128(a - b), Some(diff));
+                }
+                if let Some(product) = lhs.checked_mul(rhs) {
+                    assert_eq!(as_i128(a * b), Some(product));
+                }
+                if let (Some(quotient), Some(remainder)) =
+                    (lhs.checked_div(rhs), lhs.checked_rem(rhs))
+                {
+                    assert_eq!(as_i128(a / b), Some(quotient));
+                    assert_eq!(as_i128(a % b), Some(remainder));
+                }
+                let (lhs, rhs) = (lhs as u128, rhs as u128);
+                let (a, b) = (U136::from(lhs), U136::from(rhs));
+                let as_u128 = |value: U136| u128::try_from(value).ok();
+                if let Some(sum) = lhs.checked_add(rhs) {
+                    assert_eq!(as_u128(a + b), Some(sum));
+                }
+                if let Some(product) = lhs.checked_mul(rhs) {
+                    assert_eq!(as_u128(a * b), Some(product));
+                }
+                assert_eq!(a.checked_div(b).and_then(as_u128), lhs.checked_div(rhs));
+                assert_eq!(a.checked_rem(b).and_then(as_u128), lhs.checked_rem(rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn overflow_works() {
+        assert_eq!(
+            U256::MAX.overflowing_add(U256::from(1_u8)),
+            (U256::default(), true)
+        );
+        assert_eq!(
+            U136::default().overflowing_sub(U136::from(1_u8)),
+            (U136::MAX, true)
+        );
+        assert_eq!(
+            I256::MAX.overflowing_add(I256::from(1_u8)),
+            (I256::MIN, true)
+        );
+        assert_eq!(
+            I256::MIN.overflowing_div(I256::from(-1_i8)),
+            (I256::MIN, true)
+        );
+        assert_eq!(
+            I256::MIN.overflowing_rem(I256::from(-1_i8)),
+            (I256::default(), true)
+        );
+        assert_eq!(I256::MIN.overflowing_neg(), (I256::MIN, true));
+        assert_eq!(I136::MIN.checked_mul(I136::from(-1_i8)), None);
+        assert_eq!(U200::MAX.checked_mul(U200::from(2_u8)), None);
+        assert_eq!(U200::MAX.checked_div(U200::default()), None);
+        assert_eq!(U200::MAX.saturating_add(U200::from(1_u8)), U200::MAX);
+        assert_eq!(I200::MIN.saturating_sub(I200::from(1_u8)), I200::MIN);
+        assert_eq!(I144::from(-3_i8) * I144::from(5_u8), I144::from(-15_i8));
+        assert_eq!(-I144::from(-3_i8), I144::from(3_u8));
+    }
+
+    #[test]
+    fn shift_and_pow_works() {
+        assert_eq!(
+            U136::from(1_u8) << 135,
+            U136::from_be_bytes({
+                let mut bytes = [0x00_u8; 17];
+                bytes[0] = 0x80;
+                bytes
+            })
+        );
+        assert_eq!(U136::MAX >> 135, U136::from(1_u8));
+        assert_eq!(
+            U136::MAX >> 4,
+            U136::from_be_bytes({
+                let mut bytes = [0xFF_u8; 17];
+                bytes[0] = 0x0F;
+                bytes
+            })
+        );
+        assert_eq!(U256::MAX >> 1, U256::MAX / U256::from(2_u8));
+        assert_eq!(U256::MAX.wrapping_shr(4), U256::MAX / U256::from(16_u8));
+        assert_eq!(U256::MAX.checked_shr(255), Some(U256::from(1_u8)));
+        assert_eq!(I256::MIN >> 255, I256::from(-1_i8));
+        assert_eq!(I136::MIN >> 135, I136::from(-1_i8));
+        assert_eq!(U136::from(1_u8).checked_shl(136), None);
+        assert_eq!(
+            U160::from(3_u8).checked_pow(100),
+            "515377520732011331036461129765621272702107522001"
+                .parse()
+                .ok(),
+        );
+        assert_eq!(U152::from(3_u8).checked_pow(100), None);
+        assert_eq!(I192::from(-2_i8).checked_pow(191), Some(I192::MIN));
+    }
+
+    #[test]
+    fn parse_and_fmt_works() {
+        let u256_max =
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        let i256_min =
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+        assert_eq!(u256_max.parse::<U256>(), Ok(U256::MAX));
+        assert_eq!(i256_min.parse::<I256>(), Ok(I256::MIN));
+        assert_eq!("-0".parse::<I136>(), Ok(I136::default()));
+        assert_eq!(
+            U136::from_str_radix("ffffffffffffffffffffffffffffffffff", 16),
+            Ok(U136::MAX)
+        );
+        let kind_of = |src: &str| *src.parse::<I136>().unwrap_err().kind();
+        assert_eq!(kind_of(""), IntErrorKind::Empty);
+        assert_eq!(kind_of("-"), IntErrorKind::InvalidDigit);
+        assert_eq!(kind_of("12a"), IntErrorKind::InvalidDigit);
+        assert_eq!(
+            kind_of("43556142965880123323311949751266331066368"),
+            IntErrorKind::PosOverflow
+        );
+        assert_eq!(
+            kind_of("-43556142965880123323311949751266331066369"),
+            IntErrorKind::NegOverflow
+        );
+        assert_eq!(
+            "-1".parse::<U136>().unwrap_err().kind(),
+            &IntErrorKind::InvalidDigit
+        );
+        assert_fmt(format_args!("{}", U256::MAX), u256_max);
+        assert_fmt(format_args!("{:?}", I256::MIN), i256_min);
+        assert_fmt(format_args!("{:>6}", I136::from(-42_i8)), "   -42");
+        assert_fmt(
+            format_args!("{:#x}", I136::MIN),
+            "0x8000000000000000000000000000000000",
+        );
+        assert_fmt(format_args!("{:X}", U136::from(0xBEEF_u16)), "BEEF");
+        assert_fmt(format_args!("{:#b}", U136::from(5_u8)), "0b101");
+        assert_fmt(format_args!("{:o}", U256::default()), "0");
+    }
+
+    #[test]
+    fn order_works() {
+        let values = [
+            I256::MIN,
+            I256::from(i128::MIN),
+            I256::from(-1_i8),
+            I256::default(),
+            I256::from(u128::MAX),
+            I256::MAX,
+        ];
+        for (i, lhs) in values.iter().enumerate() {
+            for (j, rhs) in values.iter().enumerate() {
+                assert_eq!(lhs.cmp(rhs), i.cmp(&j));
+                assert_eq!(lhs.const_eq(*rhs), i == j);
+            }
+        }
+        assert!(U256::MAX > U256::from(u128::MAX));
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(U136::from(U128::MAX), U136::from(u128::MAX));
+        assert_eq!(I136::from(I24::MIN), I136::from(-0x80_0000_i32));
+        assert_eq!(U256::from(U136::MAX).to_le_bytes()[17], 0x00);
+        assert_eq!(I256::from(I136::MIN), -I256::from(U136::from(1_u8) << 135));
+        assert_eq!(I144::from(U136::MAX).to_be_bytes()[0], 0x00);
+        assert_eq!(U136::try_from(U144::from(U136::MAX)), Ok(U136::MAX));
+        assert!(U136::try_from(U144::from(U136::MAX) + U144::from(1_u8)).is_err());
+        assert!(U136::try_from(-1_i8).is_err());
+        assert!(U256::try_from(I136::MIN).is_err());
+        assert_eq!(i128::try_from(I256::from(i128::MIN)), Ok(i128::MIN));
+        assert!(i128::try_from(I256::from(u128::MAX)).is_err());
+        assert_eq!(u8::try_from(U200::from(255_u8)), Ok(255));
+        assert!(u8::try_from(I200::from(-1_i8)).is_err());
+        assert_eq!(I24::try_from(I168::from(-5_i8)), Ok(I24::from(-5_i8)));
+        assert!(U24::try_from(U168::from(U32::MAX)).is_err());
+        assert_eq!(usize::try_from(U136::from(usize::MAX)), Ok(usize::MAX));
+    }
+}
+
+mod bit_int {
+    use super::wide::assert_fmt;
+    use crate::*;
+
+    #[test]
+    fn bounds_work() {
+        assert_eq!(U12::MIN.to_repr(), 0);
+        assert_eq!(U12::MAX.to_repr(), 0xFFF);
+        assert_eq!(I7::MIN.to_repr(), -64);
+        assert_eq!(I7::MAX.to_repr(), 63);
+        assert_eq!(U1::MAX.to_repr(), 1);
+        assert_eq!(I1::MIN.to_repr(), -1);
+        assert_eq!(I63::MIN.to_repr(), i64::MIN >> 1);
+        assert_eq!(U20::new(0x10_0000), None);
+        assert_eq!(I7::new(-65), None);
+        assert_eq!(I7::new(-64), Some(I7::MIN));
+        assert_eq!(core::mem::size_of::<U12>(), 2);
+        assert_eq!(core::mem::size_of::<U20>(), 4);
+        assert_eq!(core::mem::size_of::<I7>(), 1);
+    }
+
+    #[test]
+    fn arith_works() {
+        assert_eq!(U12::MAX.overflowing_add(U12::from(1_u8)), (U12::MIN, true));
+        assert_eq!(I7::MAX.overflowing_add(I7::new_const(1)), (I7::MIN, true));
+        assert_eq!(I7::MIN.overflowing_div(I7::new_const(-1)), (I7::MIN, true));
+        assert_eq!(
+            I7::MIN.overflowing_rem(I7::new_const(-1)),
+            (I7::new_const(0), true)
+        );
+        assert_eq!(I7::MIN.checked_neg(), None);
+        assert_eq!(U12::new_const(64).checked_mul(U12::new_const(64)), None);
+        assert_eq!(
+            U12::new_const(63) * U12::new_const(65),
+            U12::new_const(4095)
+        );
+        assert_eq!(U12::MAX.saturating_add(U12::from(1_u8)), U12::MAX);
+        assert_eq!(I20::new_const(-5) + I20::new_const(3), I20::new_const(-2));
+        assert_eq!(U12::new_const(1) << 11_u32, U12::new_const(0x800));
+        assert_eq!(U12::new_const(1).checked_shl(12), None);
+        assert_eq!(I7::MIN >> 6_u32, I7::new_const(-1));
+        assert_eq!(!U12::MIN, U12::MAX);
+        assert_eq!(!I7::MIN, I7::MAX);
+        assert_eq!(U12::MAX ^ U12::new_const(0xF0F), U12::new_const(0x0F0));
+    }
+
+    #[test]
+    fn parse_works() {
+        assert_eq!("4095".parse::<U12>(), Ok(U12::MAX));
+        assert_eq!("-64".parse::<I7>(), Ok(I7::MIN));
+        assert_eq!(
+            "4096".parse::<U12>().unwrap_err().kind(),
+            &IntErrorKind::PosOverflow
+        );
+        assert_eq!(
+            "-65".parse::<I7>().unwrap_err().kind(),
+            &IntErrorKind::NegOverflow
+        );
+        assert_eq!(
+            "1a".parse::<U20>().unwrap_err().kind(),
+            &IntErrorKind::InvalidDigit
+        );
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(U16::from(U12::MAX), U16::from(0xFFF_u16));
+        assert_eq!(U24::from(U20::MAX), U24::try_from(0xF_FFFF_u32).unwrap());
+        assert_eq!(I16::from(I7::MIN), I16::from(-64_i8));
+        assert_eq!(I24::from(U20::MAX), I24::try_from(0xF_FFFF_i32).unwrap());
+        assert_eq!(I64::from(I63::MIN), I64::from(i64::MIN >> 1));
+        assert_eq!(u16::from(U12::MAX), 0xFFF);
+        assert_eq!(i8::from(I7::MIN), -64);
+        assert_eq!(usize::from(U12::MAX), 0xFFF);
+        assert_eq!(U12::from(0xFF_u8), U12::new_const(0xFF));
+        assert_eq!(
+            U12::try_from(0x1000_u16),
+            Err(TryFromIntError::new(TryFromIntErrorKind::PosOverflow, 12))
+        );
+        assert_eq!(U12::try_from(U24::from(0xFFF_u16)), Ok(U12::MAX));
+        assert!(U12::try_from(U16::from(0x1000_u16)).is_err());
+        assert!(U12::try_from(-1_i8).is_err());
+        assert!(I7::try_from(64_u8).is_err());
+        assert_eq!(I7::try_from(I16::from(-64_i8)), Ok(I7::MIN));
+        assert!(U16::try_from(U20::new_const(0x1_0000)).is_err());
+        assert_eq!(U16::try_from(U20::new_const(0xFFFF)), Ok(U16::MAX));
+        assert!(U16::try_from(I12::new_const(-1)).is_err());
+        assert!(u8::try_from(U12::new_const(0x100)).is_err());
+    }
+
+    #[test]
+    fn fmt_works() {
+        assert_fmt(format_args!("{}", I7::MIN), "-64");
+        assert_fmt(format_args!("{:?}", U12::MAX), "4095");
+        assert_fmt(format_args!("{:#x}", U20::MAX), "0xfffff");
+    }
+}
+
+mod bit_packed {
+    use crate::*;
+
+    #[test]
+    fn get_and_set_work() {
+        let mut packed = BitPacked::<U12, 6>::new();
+        assert_eq!(packed.len(), 4);
+        for (index, value) in [0xABC_u16, 0x123, 0xFFF, 0x001].into_iter().enumerate() {
+            packed.set(index, U12::new_const(value));
+        }
+        assert_eq!(packed.to_bytes(), [0xBC, 0x3A, 0x12, 0xFF, 0x1F, 0x00]);
+        assert_eq!(packed.get(1), Some(U12::new_const(0x123)));
+        assert_eq!(packed.get(4), None);
+        packed.set(2, U12::new_const(0));
+        assert_eq!(packed.to_bytes(), [0xBC, 0x3A, 0x12, 0x00, 0x10, 0x00]);
+        assert_eq!(packed.get(3), Some(U12::new_const(0x001)));
+    }
+
+    #[test]
+    fn signed_values_work() {
+        let values = [I7::MIN, I7::new_const(-1), I7::new_const(0), I7::MAX];
+        let packed = BitPacked::<I7, 4>::from_array(values);
+        assert_eq!(packed.len(), 4);
+        assert!(packed.iter().eq(values));
+        assert_eq!(BitPacked::<I7, 4>::from_bytes(packed.to_bytes()), packed);
+    }
+
+    #[test]
+    fn wide_values_work() {
+        let mut packed = BitPacked::<I63, 16>::default();
+        assert_eq!(packed.len(), 2);
+        packed.set(0, I63::MIN);
+        packed.set(1, I63::MAX);
+        assert_eq!(packed.get(0), Some(I63::MIN));
+        assert_eq!(packed.get(1), Some(I63::MAX));
+        assert_eq!(packed.as_bytes()[15], 0x1F);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_out_of_bounds_panics() {
+        BitPacked::<U20, 5>::new().set(2, U20::MAX);
+    }
+}
+
+mod slice {
+    use crate::*;
+
+    #[test]
+    fn from_repr_works() {
+        let src = [0_u32, 1, 0xFF_FFFF, 0x12_3456];
+        let mut dst = [U24::default(); 4];
+        assert_eq!(U24::slice_from_u32(&src, &mut dst), Ok(()));
+        assert_eq!(dst.map(U24::to_repr), src);
+        let src = [-0x80_0000_i32, -1, 0, 0x7F_FFFF];
+        let mut dst = [I24::default(); 4];
+        assert_eq!(I24::slice_from_i32(&src, &mut dst), Ok(()));
+        assert_eq!(dst.map(I24::to_repr), src);
+    }
+
+    #[test]
+    fn from_repr_reports_first_failing_index() {
+        let mut src = [0x7F_u32; 40];
+        src[21] = 0x100_0000;
+        src[37] = u32::MAX;
+        let mut dst = [U24::default(); 40];
+        let error = U24::slice_from_u32(&src, &mut dst).unwrap_err();
+        assert_eq!(error.index(), 21);
+        assert!(dst[..21].iter().all(|value| value.to_repr() == 0x7F));
+        let mut dst = [I24::default(); 3];
+        let error = I24::slice_from_i32(&[0, -0x80_0001, 0], &mut dst).unwrap_err();
+        assert_eq!(error.index(), 1);
+        let mut dst = [I40::default(); 2];
+        let result = <I40 as UnalignedInteger>::slice_from_repr(&[0, i64::MAX], &mut dst);
+        assert_eq!(result, Err(TryFromIntSliceError::new(1)));
+    }
+
+    #[test]
+    fn to_repr_works() {
+        let src = [I48::MIN, I48::from(-1_i8), I48::MAX];
+        let mut dst = [0_i64; 3];
+        I48::slice_to_i64(&src, &mut dst);
+        assert_eq!(dst, src.map(i64::from));
+    }
+
+    #[test]
+    #[should_panic]
+    fn length_mismatch_panics() {
+        let _ = U24::slice_from_u32(&[1, 2, 3], &mut [U24::default(); 2]);
+    }
+
+    #[test]
+    fn endian_conversions_work() {
+        let mut values = [U24::from_be_bytes([0x01, 0x02, 0x03]); 3];
+        U24::slice_swap_bytes(&mut values);
+        assert!(values
+            .iter()
+            .all(|value| value.to_be_bytes() == [0x03, 0x02, 0x01]));
+        let mut values = [U40::from_ne_bytes([0x01, 0x02, 0x03, 0x04, 0x05])];
+        U40::slice_to_be(&mut values);
+        assert_eq!(
+            values[0].to_ne_bytes(),
+            U40::from_ne_bytes([0x01, 0x02, 0x03, 0x04, 0x05]).to_be_bytes()
+        );
+        U40::slice_from_be(&mut values);
+        U40::slice_to_le(&mut values);
+        assert_eq!(
+            values[0].to_ne_bytes(),
+            U40::from_ne_bytes([0x01, 0x02, 0x03, 0x04, 0x05]).to_le_bytes()
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod packed_vec {
+    use crate::*;
+
+    #[test]
+    fn bulk_conversions_work() {
+        let values = PackedVec::<I24>::try_from_reprs(&[-0x80_0000, -1, 0, 0x7F_FFFF]).unwrap();
+        assert_eq!(values.len(), 4);
+        assert_eq!(values[0], I24::MIN);
+        assert_eq!(values.to_reprs(), [-0x80_0000, -1, 0, 0x7F_FFFF]);
+        assert_eq!(
+            PackedVec::<I24>::try_from_reprs(&[0, 0x80_0000])
+                .unwrap_err()
+                .index(),
+            1
+        );
+    }
+
+    #[test]
+    fn failed_extend_keeps_values() {
+        let mut values = PackedVec::<U40>::new();
+        values.push(U40::MAX);
+        assert!(values.try_extend_from_reprs(&[1, 2, u64::MAX]).is_err());
+        assert_eq!(values.as_slice(), [U40::MAX]);
+        values.try_extend_from_reprs(&[1, 2]).unwrap();
+        assert_eq!(values.pop(), Some(U40::from(2_u8)));
+        assert_eq!(values.len(), 2);
+        let vec: alloc::vec::Vec<U40> = values.into();
+        assert_eq!(vec.len(), 2);
+    }
+}
+
+mod non_zero {
+    use super::wide::assert_fmt;
+    use crate::*;
+    use core::mem::{align_of, size_of};
+    use core::num;
+
+    #[test]
+    fn size_and_align_of_works() {
+        assert_eq!(size_of::<Option<NonZeroU16>>(), 2);
+        assert_eq!(size_of::<Option<NonZeroI16>>(), 2);
+        assert_eq!(size_of::<Option<NonZeroU32>>(), 4);
+        assert_eq!(size_of::<Option<NonZeroI64>>(), 8);
+        assert_eq!(size_of::<Option<NonZeroU128>>(), 16);
+        assert_eq!(size_of::<Option<NonZeroI128>>(), 16);
+        assert_eq!(align_of::<NonZeroU32>(), 1);
+        assert_eq!(align_of::<NonZeroI128>(), 1);
+        assert_eq!(size_of::<NonZeroU16>(), 2);
+        assert_eq!(size_of::<NonZeroI64>(), 8);
+    }
+
+    #[test]
+    fn new_and_get_work() {
+        assert_eq!(NonZeroU16::new(U16::default()), None);
+        assert_eq!(NonZeroI32::new(I32::default()), None);
+        assert_eq!(
+            NonZeroU16::new(U16::MAX).map(NonZeroU16::get),
+            Some(U16::MAX)
+        );
+        assert_eq!(
+            NonZeroI32::new(I32::MIN).map(NonZeroI32::get),
+            Some(I32::MIN)
+        );
+        let value = unsafe { NonZeroI64::new_unchecked(I64::MIN) };
+        assert_eq!(value.get(), I64::MIN);
+        assert_eq!(NonZeroU16::BITS, 16);
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(
+            NonZeroU32::try_from(U32::default()),
+            Err(TryFromIntError::new(TryFromIntErrorKind::Zero, 32))
+        );
+        let value = NonZeroU32::try_from(U32::MAX).unwrap();
+        assert_eq!(U32::from(value), U32::MAX);
+        assert_eq!(num::NonZeroU32::from(value), num::NonZeroU32::MAX);
+        assert_eq!(
+            NonZeroI16::from(num::NonZeroI16::new(-1).unwrap()).get(),
+            I16::from(-1_i8)
+        );
+        let value = NonZeroI64::from(num::NonZeroI64::MIN);
+        assert_eq!(I64::from(value), I64::MIN);
+        assert_eq!(num::NonZeroI64::from(value), num::NonZeroI64::MIN);
+    }
+
+    #[test]
+    fn cmp_and_fmt_work() {
+        let one = NonZeroI64::new(I64::from(1_i8)).unwrap();
+        let minus_one = NonZeroI64::new(I64::from(-1_i8)).unwrap();
+        assert!(minus_one < one);
+        assert_ne!(minus_one, one);
+        assert_fmt(format_args!("{minus_one}"), "-1");
+        assert_fmt(
+            format_args!("{:?}", NonZeroU32::new(U32::MAX).unwrap()),
+            "4294967295",
+        );
+        assert_fmt(
+            format_args!("{:x}", NonZeroU16::new(U16::MAX).unwrap()),
+            "ffff",
+        );
+    }
+}
+
+mod non_max {
+    use super::wide::assert_fmt;
+    use crate::*;
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn size_and_align_of_works() {
+        assert_eq!(size_of::<Option<U16Max>>(), 2);
+        assert_eq!(size_of::<Option<U32Max>>(), 4);
+        assert_eq!(size_of::<Option<I64Max>>(), 8);
+        assert_eq!(size_of::<Option<U128Max>>(), 16);
+        assert_eq!(size_of::<Option<U32Except<0x1234>>>(), 4);
+        assert_eq!(size_of::<Option<I16Except<-1>>>(), 2);
+        assert_eq!(align_of::<U32Max>(), 1);
+        assert_eq!(align_of::<I128Except<0>>(), 1);
+    }
+
+    #[test]
+    fn new_and_get_work() {
+        assert_eq!(U32Max::new(U32::MAX), None);
+        assert_eq!(I32Max::new(I32::MAX), None);
+        assert_eq!(
+            U32Max::new(U32::default()).map(U32Max::get),
+            Some(U32::default())
+        );
+        assert_eq!(U32Max::default().get(), U32::default());
+        assert_eq!(I16Max::new(I16::MIN).map(I16Max::get), Some(I16::MIN));
+        assert_eq!(U16Max::MAX.get(), U16::from(0xFFFE_u16));
+        assert_eq!(I32Max::MAX.get(), I32::from(i32::MAX - 1));
+        assert_eq!(I32Max::MIN.get(), I32::MIN);
+        assert_eq!(U64Max::MIN.get(), U64::default());
+        let value = unsafe { I64Max::new_unchecked(I64::MIN) };
+        assert_eq!(value.get(), I64::MIN);
+        assert_eq!(U16Max::BITS, 16);
+        assert_eq!(U32Max::RESERVED, U32::MAX);
+    }
+
+    #[test]
+    fn configurable_value_works() {
+        assert_eq!(U32Except::<7>::new(U32::from(7_u8)), None);
+        assert_eq!(
+            U32Except::<7>::new(U32::MAX).map(U32Except::get),
+            Some(U32::MAX)
+        );
+        assert_eq!(U32Except::<7>::MIN.get(), U32::default());
+        assert_eq!(U32Except::<7>::MAX.get(), U32::MAX);
+        assert_eq!(U16Except::<0>::MIN.get(), U16::from(1_u8));
+        assert_eq!(
+            I16Except::<{ i16::MIN }>::MIN.get(),
+            I16::from(i16::MIN + 1)
+        );
+        assert_eq!(I64Except::<-1>::new(I64::from(-1_i8)), None);
+        assert_eq!(
+            I64Except::<-1>::new(I64::default()).map(I64Except::get),
+            Some(I64::default())
+        );
+        assert_eq!(I128Except::<42>::RESERVED, I128::from(42_i8));
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(
+            U32Max::try_from(u32::MAX),
+            Err(TryFromIntError::new(TryFromIntErrorKind::PosOverflow, 32))
+        );
+        assert_eq!(
+            U32Max::try_from(-1_i8),
+            Err(TryFromIntError::new(TryFromIntErrorKind::SignMismatch, 32))
+        );
+        assert_eq!(
+            U32Max::try_from(0xFFFF_FFFE_u64).map(U32Max::get),
+            Ok(U32::from(0xFFFF_FFFE_u32))
+        );
+        assert_eq!(
+            U16Max::try_from(U24::new_const(0x1_0000)),
+            Err(TryFromIntError::new(TryFromIntErrorKind::PosOverflow, 16))
+        );
+        assert_eq!(
+            U16Max::try_from(I72::from(7_i8)).map(U16Max::get),
+            Ok(U16::from(7_u8))
+        );
+        assert_eq!(
+            I32Max::try_from(I16::MIN).map(I32Max::get),
+            Ok(I32::from(i16::MIN))
+        );
+        assert_eq!(
+            U32Except::<7>::try_from(7_u8),
+            Err(TryFromIntError::new(TryFromIntErrorKind::Reserved, 32))
+        );
+        assert_eq!(
+            U64Except::<0>::try_from(U40::default()),
+            Err(TryFromIntError::new(TryFromIntErrorKind::Zero, 64))
+        );
+        assert_eq!(
+            I16Except::<{ i16::MIN }>::try_from(i16::MIN),
+            Err(TryFromIntError::new(TryFromIntErrorKind::NegOverflow, 16))
+        );
+        assert_eq!(U16::from(U16Max::MAX), U16::from(0xFFFE_u16));
+    }
+
+    #[test]
+    fn cmp_and_fmt_work() {
+        let minus_one = I64Max::try_from(-1_i8).unwrap();
+        assert!(I64Max::MIN < minus_one);
+        assert!(minus_one < I64Max::default());
+        assert!(U32Max::MAX > U32Max::default());
+        assert_fmt(format_args!("{minus_one}"), "-1");
+        assert_fmt(format_args!("{:?}", U32Max::MAX), "4294967294");
+        assert_fmt(format_args!("{:X}", U32Except::<0>::MAX), "FFFFFFFF");
+        assert_fmt(format_args!("{:b}", U16Max::MAX), "1111111111111110");
+    }
+}
+
+mod float {
+    use crate::*;
+
+    #[test]
+    fn lossless_from_works() {
+        assert_eq!(f32::from(I24::MIN), -8_388_608.0);
+        assert_eq!(f32::from(U24::MAX), 16_777_215.0);
+        assert_eq!(f64::from(U48::MAX), 281_474_976_710_655.0);
+        assert_eq!(f64::from(I48::MIN), -140_737_488_355_328.0);
+    }
+
+    #[test]
+    fn to_float_rounds_like_as() {
+        assert_eq!(U32::MAX.to_f32(), u32::MAX as f32);
+        assert_eq!(U56::MAX.to_f64(), 72_057_594_037_927_935_u64 as f64);
+        assert_eq!(I128::MIN.to_f32(), i128::MIN as f32);
+        assert_eq!(U128::MAX.to_f32(), u128::MAX as f32);
+        assert_eq!(I72::from(-1_i8).to_f64(), -1.0);
+    }
+
+    #[test]
+    fn saturating_from_float_works() {
+        // Note: 24-bit audio samples are commonly scaled from `[-1.0, 1.0]`.
+        const SCALE: f32 = 8_388_608.0;
+        assert_eq!(
+            I24::saturating_from_f32(0.5 * SCALE),
+            I24::new_const(4_194_304)
+        );
+        assert_eq!(I24::saturating_from_f32(SCALE), I24::MAX);
+        assert_eq!(I24::saturating_from_f32(-SCALE), I24::MIN);
+        assert_eq!(I24::saturating_from_f64(-2.9), I24::new_const(-2));
+        assert_eq!(I24::saturating_from_f64(f64::NAN), I24::new_const(0));
+        assert_eq!(U24::saturating_from_f32(-1.5), U24::new_const(0));
+        assert_eq!(U24::saturating_from_f32(f32::INFINITY), U24::MAX);
+        assert_eq!(U40::saturating_from_f64(1e300), U40::MAX);
+        assert_eq!(I128::saturating_from_f32(f32::NEG_INFINITY), I128::MIN);
+        assert_eq!(U128::saturating_from_f32(f32::INFINITY), U128::MAX);
+    }
+
+    #[test]
+    fn checked_from_float_works() {
+        assert_eq!(U24::checked_from_f32(16_777_215.9), None);
+        assert_eq!(U24::checked_from_f64(16_777_215.9), Some(U24::MAX));
+        assert_eq!(U24::checked_from_f64(16_777_216.0), None);
+        assert_eq!(U24::checked_from_f64(-0.9), Some(U24::new_const(0)));
+        assert_eq!(U24::checked_from_f64(-1.0), None);
+        assert_eq!(I24::checked_from_f64(-8_388_608.9), Some(I24::MIN));
+        assert_eq!(I24::checked_from_f64(-8_388_609.0), None);
+        assert_eq!(I24::checked_from_f64(8_388_607.5), Some(I24::MAX));
+        assert_eq!(I24::checked_from_f64(8_388_608.0), None);
+        assert_eq!(I32::checked_from_f32(-2_147_483_648.0), Some(I32::MIN));
+        assert_eq!(I32::checked_from_f32(2_147_483_648.0), None);
+        assert_eq!(
+            I64::checked_from_f64(-9_223_372_036_854_775_808.0),
+            Some(I64::MIN)
+        );
+        assert_eq!(I64::checked_from_f64(9_223_372_036_854_775_808.0), None);
+        assert_eq!(
+            U128::checked_from_f32(f32::MAX),
+            Some(U128::new_const(f32::MAX as u128))
+        );
+        assert_eq!(I128::checked_from_f32(f32::MAX), None);
+        assert_eq!(U32::checked_from_f64(f64::NAN), None);
+        assert_eq!(U32::checked_from_f64(f64::INFINITY), None);
+        assert_eq!(I56::checked_from_f64(f64::NEG_INFINITY), None);
+    }
+}
+
+mod cast {
+    use crate::*;
+
+    /// Sample values covering the boundaries of all primitive integer types.
+    const SAMPLES: [i128; 12] = [
+        0,
+        1,
+        -1,
+        0x7F,
+        -0x80,
+        0x1234_5678,
+        -0x1234_5678_9ABC,
+        i64::MAX as i128,
+        i64::MIN as i128,
+        u64::MAX as i128,
+        i128::MAX,
+        i128::MIN,
+    ];
+
+    macro_rules! test_primitive_casts_match_as {
+        ( $( $from:ty => [$( $to:ty ),*] );* $(;)? ) => {
+            $(
+                for sample in SAMPLES {
+                    let value = sample as $from;
+                    $(
+                        assert_eq!(<$to>::wrapping_from(value), value as $to);
+                        let saturated = <$to>::try_from(value).unwrap_or(if (value as i128) < 0 && <$from>::MIN != 0 {
+                            <$to>::MIN
+                        } else {
+                            <$to>::MAX
+                        });
+                        assert_eq!(<$to>::saturating_from(value), saturated);
+                    )*
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn primitive_casts_match_as() {
+        test_primitive_casts_match_as! {
+            i8 => [u8, u16, i16, u128, i128];
+            u16 => [u8, i8, i32, u64];
+            i32 => [u8, i8, u16, i16, u32, u64, i64];
+            u64 => [i8, u32, i32, i64, u128, i128];
+            i128 => [u8, i8, u16, i16, u32, i32, u64, i64, u128];
+            u128 => [i8, u32, i64, i128];
+        }
+    }
+
+    #[test]
+    fn wrapping_from_works() {
+        assert_eq!(
+            U16::wrapping_from(U24::new_const(0x12_3456)),
+            U16::new_const(0x3456)
+        );
+        assert_eq!(
+            I16::wrapping_from(U24::new_const(0x12_FFFF)),
+            I16::from(-1_i8)
+        );
+        assert_eq!(
+            U24::wrapping_from(I16::from(-2_i8)),
+            U24::new_const(0xFF_FFFE)
+        );
+        assert_eq!(I24::wrapping_from(U24::MAX), I24::from(-1_i8));
+        assert_eq!(U40::wrapping_from(-1_i8), U40::MAX);
+        assert_eq!(U40::wrapping_from(u8::MAX), U40::from(0xFF_u8));
+        assert_eq!(u8::wrapping_from(I72::MIN), 0);
+        assert_eq!(i64::wrapping_from(U72::MAX), -1);
+        assert_eq!(
+            I128::wrapping_from(U56::MAX),
+            I128::from(0xFF_FFFF_FFFF_FFFF_u64)
+        );
+        assert_eq!(U96::wrapping_from(I120::MIN), U96::default());
+        assert_eq!(U24::new_const(0x12_3456).truncate::<u16>(), 0x3456);
+        assert_eq!(I48::from(-1_i8).truncate::<U128>(), U128::MAX);
+    }
+
+    #[test]
+    fn saturating_from_works() {
+        assert_eq!(U16::saturating_from(U24::new_const(0x12_3456)), U16::MAX);
+        assert_eq!(
+            U16::saturating_from(U24::new_const(0x3456)),
+            U16::new_const(0x3456)
+        );
+        assert_eq!(U24::saturating_from(I16::MIN), U24::default());
+        assert_eq!(I24::saturating_from(U24::MAX), I24::MAX);
+        assert_eq!(I24::saturating_from(I128::MIN), I24::MIN);
+        assert_eq!(I24::saturating_from(i128::MAX), I24::MAX);
+        assert_eq!(U128::saturating_from(I128::MIN), U128::default());
+        assert_eq!(I128::saturating_from(U128::MAX), I128::MAX);
+        assert_eq!(u8::saturating_from(I40::MIN), 0);
+        assert_eq!(i8::saturating_from(I40::MIN), i8::MIN);
+        assert_eq!(I40::MAX.saturate::<u32>(), u32::MAX);
+    }
+
+    #[test]
+    fn cast_sign_works() {
+        assert_eq!(U24::MAX.cast_signed(), I24::from(-1_i8));
+        assert_eq!(I24::MIN.cast_unsigned(), U24::new_const(0x80_0000));
+        assert_eq!(U128::MAX.cast_signed(), I128::from(-1_i8));
+        assert_eq!(I40::MIN.cast_unsigned().cast_signed(), I40::MIN);
+    }
+}
+
+mod try_from_error {
+    use crate::*;
+
+    fn kind_and_bits<T, E>(result: Result<T, E>) -> (TryFromIntErrorKind, u32)
+    where
+        E: Into<TryFromIntError>,
+    {
+        match result {
+            Ok(_) => panic!("expected the conversion to fail"),
+            Err(error) => {
+                let error = error.into();
+                (*error.kind(), error.target_bits())
+            }
+        }
+    }
+
+    #[test]
+    fn kind_works() {
+        use TryFromIntErrorKind::*;
+        assert_eq!(kind_and_bits(u16::try_from(U32::MAX)), (PosOverflow, 16));
+        assert_eq!(kind_and_bits(u16::try_from(I32::MIN)), (SignMismatch, 16));
+        assert_eq!(kind_and_bits(i16::try_from(I32::MIN)), (NegOverflow, 16));
+        assert_eq!(kind_and_bits(U24::try_from(-1_i64)), (SignMismatch, 24));
+        assert_eq!(kind_and_bits(I24::try_from(u64::MAX)), (PosOverflow, 24));
+        assert_eq!(kind_and_bits(I24::try_from(U24::MAX)), (PosOverflow, 24));
+        assert_eq!(kind_and_bits(U24::try_from(I24::MIN)), (SignMismatch, 24));
+        assert_eq!(kind_and_bits(I24::try_from(I40::MIN)), (NegOverflow, 24));
+        assert_eq!(
+            kind_and_bits(usize::try_from(I24::MIN)),
+            (SignMismatch, usize::BITS)
+        );
+        assert_eq!(kind_and_bits(I24::try_from(isize::MIN)), (NegOverflow, 24));
+        assert_eq!(
+            kind_and_bits(UInt::<3>::try_from(u128::MAX)),
+            (PosOverflow, 24)
+        );
+        assert_eq!(
+            kind_and_bits(Int::<3>::try_from(i128::MIN)),
+            (NegOverflow, 24)
+        );
+        assert_eq!(kind_and_bits(U24::try_from(U256::MAX)), (PosOverflow, 24));
+        assert_eq!(kind_and_bits(u8::try_from(I256::MIN)), (SignMismatch, 8));
+        assert_eq!(kind_and_bits(U136::try_from(-1_i8)), (SignMismatch, 136));
+        assert_eq!(kind_and_bits(U12::try_from(-1_i32)), (SignMismatch, 12));
+        assert_eq!(kind_and_bits(I12::try_from(I24::MIN)), (NegOverflow, 12));
+        assert_eq!(kind_and_bits(u8::try_from(U12::MAX)), (PosOverflow, 8));
+        assert_eq!(kind_and_bits(U32Max::try_from(U32::MAX)), (PosOverflow, 32));
+        assert_eq!(
+            kind_and_bits(NonZeroI64::try_from(I64::default())),
+            (Zero, 64)
+        );
+    }
+
+    #[test]
+    fn from_core_error_works() {
+        fn narrow(value: u16) -> Result<u8, TryFromIntError> {
+            Ok(u8::try_from(value)?)
+        }
+        assert_eq!(narrow(42), Ok(42));
+        let error = narrow(300).unwrap_err();
+        assert_eq!(error.kind(), &TryFromIntErrorKind::Primitive);
+        assert_eq!(error.target_bits(), 0);
+    }
+
+    #[test]
+    fn display_works() {
+        extern crate std;
+        use std::string::ToString;
+        assert_eq!(
+            U24::try_from(u32::MAX).unwrap_err().to_string(),
+            "out of range integral type conversion attempted: value too large for 24-bit integer type"
+        );
+        assert_eq!(
+            I48::try_from(i64::MIN).unwrap_err().to_string(),
+            "out of range integral type conversion attempted: value too small for 48-bit integer type"
+        );
+        assert_eq!(
+            U40::try_from(-1_i8).unwrap_err().to_string(),
+            "out of range integral type conversion attempted: negative value for 40-bit unsigned integer type"
+        );
+        assert_eq!(
+            NonZeroU16::try_from(U16::default()).unwrap_err().to_string(),
+            "out of range integral type conversion attempted: zero value for 16-bit non-zero integer type"
+        );
+        assert_eq!(
+            U32Except::<7>::try_from(7_u8).unwrap_err().to_string(),
+            "out of range integral type conversion attempted: reserved value for 32-bit integer type"
+        );
+        assert_eq!(
+            TryFromIntError::from(u8::try_from(300_u16).unwrap_err()).to_string(),
+            "out of range integral type conversion attempted"
+        );
+        let error: &dyn core::error::Error = &U24::try_from(u32::MAX).unwrap_err();
+        assert!(error.source().is_none());
+    }
+}
+
+#[cfg(feature = "std")]
+mod io {
+    use crate::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn read_works() {
+        let mut reader: &[u8] = &[
+            0x01, 0x02, 0x03, // U24 little-endian
+            0x01, 0x02, 0x03, // U24 big-endian
+            0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // I48 little-endian
+            0x80, 0x00, 0x00, 0x00, 0x00, // I40 big-endian
+        ];
+        assert_eq!(reader.read_u24_le().unwrap(), U24::new_const(0x03_0201));
+        assert_eq!(
+            reader.read_u24::<BigEndian>().unwrap(),
+            U24::new_const(0x01_0203)
+        );
+        assert_eq!(reader.read_i48::<LittleEndian>().unwrap(), I48::from(-2_i8));
+        assert_eq!(reader.read_i40_be().unwrap(), I40::MIN);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn read_past_end_fails() {
+        let mut reader: &[u8] = &[0x01, 0x02];
+        let error = reader.read_u24_be().unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    /// Mirrors the primitive methods of `byteorder::ReadBytesExt`.
+    trait ReadBytesExt: std::io::Read {
+        fn read_u32<B: ByteOrder>(&mut self) -> std::io::Result<u32> {
+            let mut bytes = [0x00_u8; 4];
+            self.read_exact(&mut bytes)?;
+            Ok(u32::from(B::from_bytes::<U32>(bytes)))
+        }
+    }
+
+    impl<R> ReadBytesExt for R where R: std::io::Read + ?Sized {}
+
+    #[test]
+    fn coexists_with_primitive_extension_traits() {
+        let mut reader: &[u8] = &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+        assert_eq!(reader.read_u32::<BigEndian>().unwrap(), 0x0102_0304);
+        assert_eq!(
+            reader.read_u24::<BigEndian>().unwrap(),
+            U24::new_const(0x05_0607)
+        );
+    }
+
+    #[test]
+    fn write_read_roundtrip_works() {
+        let mut buffer = Vec::new();
+        buffer.write_u40_be(U40::MAX).unwrap();
+        buffer.write_i24::<NetworkEndian>(I24::MIN).unwrap();
+        buffer
+            .write_unaligned::<U128, LittleEndian>(U128::from(1_u8))
+            .unwrap();
+        buffer
+            .write_unaligned::<I72, NativeEndian>(I72::from(-3_i8))
+            .unwrap();
+        assert_eq!(buffer.len(), 5 + 3 + 16 + 9);
+        assert_eq!(&buffer[5..8], [0x80, 0x00, 0x00]);
+        let mut reader = buffer.as_slice();
+        assert_eq!(reader.read_u40::<BigEndian>().unwrap(), U40::MAX);
+        assert_eq!(reader.read_i24_be().unwrap(), I24::MIN);
+        assert_eq!(
+            reader.read_unaligned::<U128, LittleEndian>().unwrap(),
+            U128::from(1_u8)
+        );
+        assert_eq!(
+            reader.read_unaligned::<I72, NativeEndian>().unwrap(),
+            I72::from(-3_i8)
+        );
+    }
+}
+
+mod byte_cursor {
+    use crate::*;
+
+    #[test]
+    fn read_works() {
+        let bytes = [
+            0x01, 0x02, 0x03, // U24 big-endian
+            0xFE, 0xFF, 0xFF, 0xFF, 0xFF, // I40 little-endian
+            0x12, 0x34, // u16 big-endian
+        ];
+        let mut reader = ByteReader::<BigEndian>::new(&bytes);
+        assert_eq!(reader.peek::<U24>(), Ok(U24::new_const(0x01_0203)));
+        assert_eq!(reader.offset(), 0);
+        assert_eq!(reader.read::<U24>(), Ok(U24::new_const(0x01_0203)));
+        assert_eq!(reader.read_bytes(5), Ok(&bytes[3..8]));
+        assert_eq!(reader.read::<u16>(), Ok(0x1234));
+        assert!(reader.is_empty());
+        let mut reader = ByteReader::<LittleEndian>::new(&bytes);
+        reader.skip(3).unwrap();
+        assert_eq!(reader.read::<I40>(), Ok(I40::from(-2_i8)));
+        assert_eq!(reader.remaining(), 2);
+        assert_eq!(reader.remaining_bytes(), [0x12, 0x34]);
+    }
+
+    #[test]
+    fn read_out_of_bounds_fails() {
+        let bytes = [0x00; 5];
+        let mut reader = ByteReader::<NetworkEndian>::new(&bytes);
+        reader.skip(2).unwrap();
+        let error = reader.read::<U32>().unwrap_err();
+        assert_eq!(
+            (error.offset(), error.required(), error.remaining()),
+            (2, 4, 3)
+        );
+        assert_eq!(reader.peek::<u32>().unwrap_err(), error);
+        assert!(reader.skip(4).is_err());
+        assert!(reader.read_bytes(4).is_err());
+        assert_eq!(reader.offset(), 2);
+        assert_eq!(reader.read::<I24>(), Ok(I24::new_const(0)));
+    }
+
+    #[test]
+    fn write_works() {
+        let mut buffer = [0xAA_u8; 10];
+        let mut writer = ByteWriter::<BigEndian>::new(&mut buffer);
+        writer.write(U24::new_const(0x01_0203)).unwrap();
+        writer.skip(1).unwrap();
+        writer.write(I40::MIN).unwrap();
+        writer.write_bytes(&[0x55]).unwrap();
+        assert!(writer.is_full());
+        assert_eq!(
+            writer.written(),
+            [0x01, 0x02, 0x03, 0xAA, 0x80, 0x00, 0x00, 0x00, 0x00, 0x55]
+        );
+    }
+
+    #[test]
+    fn write_out_of_bounds_fails() {
+        let mut buffer = [0x00_u8; 4];
+        let mut writer = ByteWriter::<LittleEndian>::new(&mut buffer);
+        writer.write(0x1234_u16).unwrap();
+        let error = writer.write(U24::MAX).unwrap_err();
+        assert_eq!(
+            (error.offset(), error.required(), error.remaining()),
+            (2, 3, 2)
+        );
+        assert!(writer.skip(3).is_err());
+        assert!(writer.write_bytes(&[0x00; 3]).is_err());
+        assert_eq!(writer.written(), [0x34, 0x12]);
+    }
+
+    #[test]
+    fn error_display_works() {
+        extern crate std;
+        use std::string::ToString;
+        let error = ByteReader::<BigEndian>::new(&[0x00; 2])
+            .read::<U24>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "out of bounds access of 3 bytes at offset 0 with 2 bytes remaining"
+        );
+    }
+
+    #[test]
+    fn write_read_roundtrip_works() {
+        let mut buffer = [0x00_u8; 3 + 9 + 16 + 8];
+        let mut writer = ByteWriter::<NativeEndian>::new(&mut buffer);
+        writer.write(I24::MIN).unwrap();
+        writer.write(U72::MAX).unwrap();
+        writer.write(I128::from(-5_i8)).unwrap();
+        writer.write(-7_isize as i64).unwrap();
+        let mut reader = ByteReader::<NativeEndian>::new(&buffer);
+        assert_eq!(reader.read::<I24>(), Ok(I24::MIN));
+        assert_eq!(reader.read::<U72>(), Ok(U72::MAX));
+        assert_eq!(reader.read::<I128>(), Ok(I128::from(-5_i8)));
+        assert_eq!(reader.read::<i64>(), Ok(-7));
+    }
+}
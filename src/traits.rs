@@ -0,0 +1,219 @@
+//! Module to define the public traits implemented by all unaligned integer
+//! types provided by this crate.
+//!
+//! The traits allow downstream code to be generic over all unaligned integers.
+//! They are sealed and cannot be implemented outside of this crate.
+
+pub(crate) mod sealed {
+    /// Prevents implementations of the public traits outside of this crate.
+    pub trait Sealed {}
+
+    macro_rules! impl_sealed_for {
+        ( $( $prim:ty ),* ) => {
+            $( impl Sealed for $prim {} )*
+        };
+    }
+    impl_sealed_for!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+}
+
+/// Trait implemented by all unaligned integer types provided by this crate.
+///
+/// Integer types wider than 128 bits such as `U256` do not implement it since they have no `Repr` primitive.
+///
+/// # Example
+///
+/// ```
+/// use intx::{UnalignedInteger, U24, I40};
+///
+/// fn encode<T: UnalignedInteger>(value: T, buffer: &mut [u8]) -> usize {
+///     let bytes = value.to_le_bytes();
+///     buffer[..T::BYTES].copy_from_slice(bytes.as_ref());
+///     T::BYTES
+/// }
+///
+/// let mut buffer = [0x00_u8; 8];
+/// assert_eq!(encode(U24::MAX, &mut buffer), 3);
+/// assert_eq!(encode(I40::MIN, &mut buffer), 5);
+/// ```
+pub trait UnalignedInteger:
+    sealed::Sealed
+    + Copy
+    + Default
+    + Eq
+    + Ord
+    + ::core::hash::Hash
+    + ::core::fmt::Debug
+    + ::core::fmt::Display
+    + ::core::str::FromStr<Err = crate::ParseIntError>
+    + Into<Self::Repr>
+    + TryFrom<Self::Repr>
+{
+    /// The smallest integer primitive type that is larger than `Self`.
+    ///
+    /// # Example
+    ///
+    /// For `U24` this is `u32`.
+    type Repr: Copy + Eq + Ord + ::core::hash::Hash + ::core::fmt::Debug;
+
+    /// The byte array type with the size of `Self`.
+    ///
+    /// # Example
+    ///
+    /// For `U24` this is `[u8; 3]`.
+    type Bytes: Copy
+        + Default
+        + Eq
+        + ::core::hash::Hash
+        + ::core::fmt::Debug
+        + AsRef<[u8]>
+        + AsMut<[u8]>;
+
+    /// The amount of bits required by this integer type.
+    const BITS: u32;
+
+    /// The amount of bytes required by this integer type.
+    const BYTES: usize;
+
+    /// The smallest value that can be represented by this integer type.
+    const MIN: Self;
+
+    /// The largest value that can be represented by this integer type.
+    const MAX: Self;
+
+    /// `true` if this integer type is signed.
+    const IS_SIGNED: bool;
+
+    /// Returns the integer value converted to its `Repr` primitive type.
+    fn to_repr(self) -> Self::Repr;
+
+    /// Creates an integer from the `Repr` primitive truncating its most significant bits.
+    fn from_repr_truncating(repr: Self::Repr) -> Self;
+
+    /// Returns the memory representation of this integer as a byte array in little-endian byte order.
+    fn to_le_bytes(self) -> Self::Bytes;
+
+    /// Returns the memory representation of this integer as a byte array in big-endian byte order.
+    fn to_be_bytes(self) -> Self::Bytes;
+
+    /// Creates an integer value from its representation as a byte array in little endian.
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Creates an integer value from its representation as a byte array in big endian.
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Converts the `Repr` primitives of `src` into `dst` checking that every value is within bounds.
+    ///
+    /// # Errors
+    ///
+    /// If a value of `src` is out of bounds for `Self`. The error reports the index
+    /// of the first such value. All values of `dst` before that index are converted.
+    ///
+    /// # Panics
+    ///
+    /// If `src` and `dst` have different lengths.
+    #[inline]
+    #[track_caller]
+    fn slice_from_repr(
+        src: &[Self::Repr],
+        dst: &mut [Self],
+    ) -> Result<(), crate::TryFromIntSliceError> {
+        crate::slice::from_repr_slice(src, dst)
+    }
+
+    /// Converts the values of `src` into their `Repr` primitives in `dst`.
+    ///
+    /// # Panics
+    ///
+    /// If `src` and `dst` have different lengths.
+    #[inline]
+    #[track_caller]
+    fn slice_to_repr(src: &[Self], dst: &mut [Self::Repr]) {
+        crate::slice::to_repr_slice(src, dst)
+    }
+}
+
+/// Trait implemented by all signed unaligned integer types provided by this crate.
+pub trait Signed: UnalignedInteger + ::core::ops::Neg<Output = Self> {
+    /// Returns `true` if `self` is negative and `false` if it is zero or positive.
+    fn is_negative(self) -> bool;
+}
+
+/// Trait implemented by all unsigned unaligned integer types provided by this crate.
+pub trait Unsigned: UnalignedInteger {
+    /// Returns `true` if and only if `self == 2^k` for some `k`.
+    fn is_power_of_two(self) -> bool;
+}
+
+/// Implements the public traits of this module for the unaligned integer `$name`.
+macro_rules! impl_unaligned_integer {
+    ( $name:ident, $repr:ty, $signedness:ident ) => {
+        impl $crate::traits::sealed::Sealed for $name {}
+
+        impl $crate::UnalignedInteger for $name {
+            type Repr = $repr;
+            type Bytes = [::core::primitive::u8; ::core::mem::size_of::<Self>()];
+
+            const BITS: ::core::primitive::u32 = Self::BITS;
+            const BYTES: ::core::primitive::usize = ::core::mem::size_of::<Self>();
+            const MIN: Self = Self::MIN;
+            const MAX: Self = Self::MAX;
+            const IS_SIGNED: ::core::primitive::bool =
+                $crate::traits::impl_unaligned_integer!(@is_signed $signedness);
+
+            #[inline]
+            fn to_repr(self) -> Self::Repr {
+                Self::to_repr(self)
+            }
+
+            #[inline]
+            fn from_repr_truncating(repr: Self::Repr) -> Self {
+                Self::from_repr_truncating(repr)
+            }
+
+            #[inline]
+            fn to_le_bytes(self) -> Self::Bytes {
+                Self::to_le_bytes(self)
+            }
+
+            #[inline]
+            fn to_be_bytes(self) -> Self::Bytes {
+                Self::to_be_bytes(self)
+            }
+
+            #[inline]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                Self::from_le_bytes(bytes)
+            }
+
+            #[inline]
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                Self::from_be_bytes(bytes)
+            }
+        }
+
+        $crate::traits::impl_unaligned_integer!(@impl $name as $signedness);
+    };
+    ( @is_signed unsigned ) => {
+        false
+    };
+    ( @is_signed signed ) => {
+        true
+    };
+    ( @impl $name:ident as unsigned ) => {
+        impl $crate::Unsigned for $name {
+            #[inline]
+            fn is_power_of_two(self) -> ::core::primitive::bool {
+                Self::is_power_of_two(self)
+            }
+        }
+    };
+    ( @impl $name:ident as signed ) => {
+        impl $crate::Signed for $name {
+            #[inline]
+            fn is_negative(self) -> ::core::primitive::bool {
+                Self::is_negative(self)
+            }
+        }
+    };
+}
+pub(crate) use impl_unaligned_integer;
@@ -16,13 +16,17 @@ macro_rules! impl_try_from_for {
 
             #[inline]
             fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
-                let repr = <<$from as crate::UnalignedInteger>::Repr
+                let repr = <<$from as crate::Integer>::Repr
                     as ::core::convert::From<$from>>::from(value);
-                let lower = <<Self as crate::UnalignedInteger>::Repr
+                let lower = <<Self as crate::Integer>::Repr
                     as ::core::convert::TryFrom<<$from
-                    as crate::UnalignedInteger>::Repr>>::try_from(repr)?;
+                    as crate::Integer>::Repr>>::try_from(repr)
+                    .map_err(|_| $crate::error::try_from_int_error!(
+                        $to,
+                        <$from as $crate::Integer>::sign_ext_byte(value) != 0x00_u8
+                    ))?;
                 let result = <Self as ::core::convert::From<<Self
-                    as crate::UnalignedInteger>::Repr>>::from(lower);
+                    as crate::Integer>::Repr>>::from(lower);
                 ::core::result::Result::Ok(result)
             }
         }
@@ -36,7 +40,10 @@ macro_rules! impl_try_from_for {
             #[inline]
             fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
                 if !<$from as $crate::IsWithinBoundsOf<$to>>::is_within_bounds(value) {
-                    return ::core::result::Result::Err($crate::TryFromIntError(()));
+                    return ::core::result::Result::Err($crate::error::try_from_int_error!(
+                        $to,
+                        <$from as $crate::Integer>::sign_ext_byte(value) != 0x00_u8
+                    ));
                 }
                 let mut dst = [0x00_u8; ::core::mem::size_of::<Self>()];
                 let src = value.to_ne_bytes();
@@ -55,7 +62,10 @@ macro_rules! impl_try_from_for {
             fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
                 let bytes = value.to_ne_bytes();
                 if bytes[<$from>::msb_pos()] & 0x80_u8 != 0x00_u8 {
-                    return ::core::result::Result::Err($crate::TryFromIntError(()))
+                    return ::core::result::Result::Err($crate::error::try_from_int_error!(
+                        $to,
+                        <$from as $crate::Integer>::sign_ext_byte(value) != 0x00_u8
+                    ));
                 }
                 ::core::result::Result::Ok(Self::from_ne_bytes(bytes))
             }
@@ -70,8 +80,8 @@ macro_rules! impl_try_from_for {
 
             #[inline]
             fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
-                <Self as ::core::convert::TryFrom<<$from as $crate::UnalignedInteger>::Repr>>::try_from(
-                    <<$from as $crate::UnalignedInteger>::Repr as ::core::convert::From<$from>>::from(value)
+                <Self as ::core::convert::TryFrom<<$from as $crate::Integer>::Repr>>::try_from(
+                    <<$from as $crate::Integer>::Repr as ::core::convert::From<$from>>::from(value)
                 )
             }
         }
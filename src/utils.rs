@@ -1,3 +1,5 @@
+use core::cmp::Ordering;
+
 /// Returns the sign extension byte for signed integers.
 ///
 /// Those are the bytes with which the integer is extended upon conversion
@@ -10,45 +12,118 @@ pub const fn sign_ext_byte(is_positive: bool) -> u8 {
     }
 }
 
-/// Copies bytes from smaller `src` to larger `dst` array and respects endianess.
+/// Returns the offset of the least significant byte of `M` bytes within `N` bytes.
+///
+/// This is `0` on little-endian platforms and `N - M` on big-endian platforms.
+#[inline]
+const fn lsb_offset<const N: usize, const M: usize>() -> usize {
+    match cfg!(target_endian = "big") {
+        true => N.abs_diff(M),
+        false => 0,
+    }
+}
+
+/// Copies bytes from smaller or equally sized `src` to larger `dst` array and respects endianess.
 ///
 /// # Note
 ///
 /// The `dst` array is untouched for areas that have no respective `src` values.
 #[inline]
-pub fn extend_bytes<const N: usize, const M: usize>(dst: &mut [u8; N], src: &[u8; M]) {
-    debug_assert!(N > M);
-    let offset = cfg!(target_endian = "big")
-        .then(|| usize::abs_diff(N, M))
-        .unwrap_or(0);
-    dst[offset..][..M].copy_from_slice(src);
+pub const fn extend_bytes<const N: usize, const M: usize>(dst: &mut [u8; N], src: &[u8; M]) {
+    debug_assert!(N >= M);
+    let offset = lsb_offset::<N, M>();
+    let mut i = 0;
+    while i < M {
+        dst[offset + i] = src[i];
+        i += 1;
+    }
 }
 
-/// Copies bytes from larger `src` to smaller `dst` array and respects endianess.
+/// Copies bytes from larger or equally sized `src` to `dst` array and respects endianess.
 ///
 /// # Note
 ///
 /// Only copies over elements from `src` to `dst` within bounds.
 #[inline]
-pub fn truncate_bytes<const N: usize, const M: usize>(dst: &mut [u8; N], src: &[u8; M]) {
-    debug_assert!(N < M);
-    let offset = cfg!(target_endian = "big")
-        .then(|| usize::abs_diff(N, M))
-        .unwrap_or(0);
-    dst[..].copy_from_slice(&src[offset..][..N]);
+pub const fn truncate_bytes<const N: usize, const M: usize>(dst: &mut [u8; N], src: &[u8; M]) {
+    debug_assert!(N <= M);
+    let offset = lsb_offset::<N, M>();
+    let mut i = 0;
+    while i < N {
+        dst[i] = src[offset + i];
+        i += 1;
+    }
+}
+
+/// Returns the `src` bytes resized to `N` bytes and respects endianess.
+///
+/// Truncates the most significant bytes if `N` is smaller than `M`
+/// and extends with `ext` bytes if `N` is larger than `M`.
+#[inline]
+pub const fn resize_bytes<const N: usize, const M: usize>(src: [u8; M], ext: u8) -> [u8; N] {
+    let mut dst = [ext; N];
+    match N <= M {
+        true => truncate_bytes(&mut dst, &src),
+        false => extend_bytes(&mut dst, &src),
+    }
+    dst
+}
+
+/// Compares the `lhs` and `rhs` bytes starting from their most significant byte.
+///
+/// The bytes are in big-endian order if `is_big_endian` is `true` and in little-endian order otherwise.
+/// If `is_signed` is `true` the sign bit of the most significant byte is flipped
+/// so that negative values compare less than positive values.
+#[inline]
+pub const fn cmp_bytes<const N: usize>(
+    lhs: &[u8; N],
+    rhs: &[u8; N],
+    is_signed: bool,
+    is_big_endian: bool,
+) -> Ordering {
+    let sign_flip = match is_signed {
+        true => 0x80_u8,
+        false => 0x00_u8,
+    };
+    let mut i = 0;
+    while i < N {
+        let pos = match is_big_endian {
+            true => i,
+            false => N - 1 - i,
+        };
+        let (mut lhs, mut rhs) = (lhs[pos], rhs[pos]);
+        if i == 0 {
+            lhs ^= sign_flip;
+            rhs ^= sign_flip;
+        }
+        if lhs < rhs {
+            return Ordering::Less;
+        }
+        if lhs > rhs {
+            return Ordering::Greater;
+        }
+        i += 1;
+    }
+    Ordering::Equal
 }
 
 /// Returns the array with reversed order of values.
 #[inline]
-pub fn reverse_bytes<const N: usize>(array: [u8; N]) -> [u8; N] {
+pub const fn reverse_bytes<const N: usize>(array: [u8; N]) -> [u8; N] {
     let mut array = array;
-    array.reverse();
+    let mut i = 0;
+    while i < N / 2 {
+        let tmp = array[i];
+        array[i] = array[N - 1 - i];
+        array[N - 1 - i] = tmp;
+        i += 1;
+    }
     array
 }
 
 /// Converts the byte array from little-endian to native-endian if necessary.
 #[inline]
-pub fn le_bytes_to_ne<const N: usize>(array: [u8; N]) -> [u8; N] {
+pub const fn le_bytes_to_ne<const N: usize>(array: [u8; N]) -> [u8; N] {
     match cfg!(target_endian = "little") {
         true => array,
         false => reverse_bytes(array),
@@ -57,7 +132,7 @@ pub fn le_bytes_to_ne<const N: usize>(array: [u8; N]) -> [u8; N] {
 
 /// Converts the byte array from native-endian to little-endian if necessary.
 #[inline]
-pub fn ne_bytes_to_le<const N: usize>(array: [u8; N]) -> [u8; N] {
+pub const fn ne_bytes_to_le<const N: usize>(array: [u8; N]) -> [u8; N] {
     match cfg!(target_endian = "little") {
         true => array,
         false => reverse_bytes(array),
@@ -66,7 +141,7 @@ pub fn ne_bytes_to_le<const N: usize>(array: [u8; N]) -> [u8; N] {
 
 /// Converts the byte array from big-endian to native-endian if necessary.
 #[inline]
-pub fn be_bytes_to_ne<const N: usize>(array: [u8; N]) -> [u8; N] {
+pub const fn be_bytes_to_ne<const N: usize>(array: [u8; N]) -> [u8; N] {
     match cfg!(target_endian = "big") {
         true => array,
         false => reverse_bytes(array),
@@ -75,7 +150,7 @@ pub fn be_bytes_to_ne<const N: usize>(array: [u8; N]) -> [u8; N] {
 
 /// Converts the byte array from native-endian to big-endian if necessary.
 #[inline]
-pub fn ne_bytes_to_be<const N: usize>(array: [u8; N]) -> [u8; N] {
+pub const fn ne_bytes_to_be<const N: usize>(array: [u8; N]) -> [u8; N] {
     match cfg!(target_endian = "big") {
         true => array,
         false => reverse_bytes(array),
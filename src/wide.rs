@@ -0,0 +1,797 @@
+//! Module to define the unaligned integer types wider than 128 bits.
+//!
+//! Unlike the integer types defined in `defs.rs` these have no `Repr` primitive.
+//! Instead all arithmetic is performed on the multi-limb integers of the `limbs`
+//! module after which the results are truncated to the bitwidth of the integer type.
+//! Overflow semantics are the same as for all other integer types of this crate.
+
+use crate::limbs::{self, Limbs};
+
+macro_rules! wide_int {
+    (
+        $(
+            $( #[$docs:meta] )*
+            @[$signedness:ident]
+            $vis:vis struct $name:ident([u8; $num_bytes:literal])
+        );* $(;)?
+    ) => {
+        $(
+            $( #[$docs] )*
+            #[derive(
+                ::core::marker::Copy,
+                ::core::clone::Clone,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+            )]
+            #[cfg_attr(
+                feature = "zerocopy",
+                derive(
+                    ::zerocopy::FromBytes,
+                    ::zerocopy::IntoBytes,
+                    ::zerocopy::Unaligned,
+                    ::zerocopy::Immutable,
+                    ::zerocopy::KnownLayout,
+                )
+            )]
+            #[repr(transparent)]
+            $vis struct $name([::core::primitive::u8; $num_bytes]);
+
+            wide_int!(@impl $name, $num_bytes, $signedness);
+        )*
+    };
+    ( @impl $name:ident, $num_bytes:literal, $signedness:ident ) => {
+        impl $name {
+            /// The amount of bits required by this integer type.
+            pub const BITS: ::core::primitive::u32 = $num_bytes * 8_u32;
+
+            /// The integer value `0`.
+            const ZERO: Self = Self([0x00_u8; $num_bytes]);
+
+            /// `true` if this integer type is signed.
+            const IS_SIGNED: ::core::primitive::bool = wide_int!(@is_signed $signedness);
+
+            /// Returns the index position of the most significant byte.
+            #[inline]
+            const fn msb_pos() -> ::core::primitive::usize {
+                if ::core::cfg!(target_endian = "big") {
+                    0_usize
+                } else {
+                    $num_bytes - 1_usize
+                }
+            }
+
+            /// Returns `true` if `self` is negative which is never the case for unsigned integers.
+            #[inline]
+            pub(crate) const fn is_negative_value(self) -> ::core::primitive::bool {
+                Self::IS_SIGNED && (self.0[Self::msb_pos()] & 0x80_u8) != 0x00_u8
+            }
+
+            /// Returns the integer value sign-extended to a 256-bit multi-limb integer.
+            #[inline]
+            pub(crate) const fn to_limbs(self) -> Limbs {
+                let ext = $crate::utils::sign_ext_byte(!self.is_negative_value());
+                limbs::from_ne_bytes(self.0, ext)
+            }
+
+            /// Creates an integer from the multi-limb integer truncating its most significant bits.
+            #[inline]
+            pub(crate) const fn from_limbs_truncating(limbs: Limbs) -> Self {
+                Self(limbs::to_ne_bytes(limbs))
+            }
+
+            /// Creates an integer from the multi-limb integer if it is within bounds.
+            ///
+            /// The `is_negative` flag tells if `limbs` represent a negative value
+            /// since the full range of 256-bit unsigned integers must be supported.
+            #[inline]
+            pub(crate) const fn checked_from_limbs(
+                limbs: Limbs,
+                is_negative: ::core::primitive::bool,
+            ) -> ::core::option::Option<Self> {
+                let result = Self::from_limbs_truncating(limbs);
+                if !limbs::eq(result.to_limbs(), limbs) || result.is_negative_value() != is_negative {
+                    return ::core::option::Option::None;
+                }
+                ::core::option::Option::Some(result)
+            }
+
+            /// Truncates the multi-limb integer to `Self` and flags overflow if the value did not fit.
+            #[inline]
+            const fn from_overflowing_limbs(
+                (limbs, overflow): (Limbs, ::core::primitive::bool),
+            ) -> (Self, ::core::primitive::bool) {
+                let result = Self::from_limbs_truncating(limbs);
+                (result, overflow || !limbs::eq(result.to_limbs(), limbs))
+            }
+
+            /// Creates an integer value from its memory representation as a byte array in native endianness.
+            #[inline]
+            pub const fn from_ne_bytes(bytes: [::core::primitive::u8; $num_bytes]) -> Self {
+                Self(bytes)
+            }
+
+            /// Creates an integer value from its representation as a byte array in little endian.
+            #[inline]
+            pub const fn from_le_bytes(bytes: [::core::primitive::u8; $num_bytes]) -> Self {
+                match ::core::cfg!(target_endian = "big") {
+                    true => Self($crate::utils::reverse_bytes(bytes)),
+                    false => Self(bytes),
+                }
+            }
+
+            /// Creates an integer value from its representation as a byte array in big endian.
+            #[inline]
+            pub const fn from_be_bytes(bytes: [::core::primitive::u8; $num_bytes]) -> Self {
+                match ::core::cfg!(target_endian = "big") {
+                    true => Self(bytes),
+                    false => Self($crate::utils::reverse_bytes(bytes)),
+                }
+            }
+
+            /// Returns the memory representation of this integer as a byte array in native byte order.
+            #[inline]
+            pub const fn to_ne_bytes(self) -> [::core::primitive::u8; $num_bytes] {
+                self.0
+            }
+
+            /// Returns the memory representation of this integer as a byte array in little-endian byte order.
+            #[inline]
+            pub const fn to_le_bytes(self) -> [::core::primitive::u8; $num_bytes] {
+                match ::core::cfg!(target_endian = "big") {
+                    true => $crate::utils::reverse_bytes(self.0),
+                    false => self.0,
+                }
+            }
+
+            /// Returns the memory representation of this integer as a byte array in big-endian byte order.
+            #[inline]
+            pub const fn to_be_bytes(self) -> [::core::primitive::u8; $num_bytes] {
+                match ::core::cfg!(target_endian = "big") {
+                    true => self.0,
+                    false => $crate::utils::reverse_bytes(self.0),
+                }
+            }
+
+            /// Returns `true` if `self` and `other` are equal.
+            ///
+            /// This is the `const` equivalent of `PartialEq::eq`.
+            #[inline]
+            pub const fn const_eq(self, other: Self) -> ::core::primitive::bool {
+                limbs::eq(self.to_limbs(), other.to_limbs())
+            }
+
+            /// Returns the [`Ordering`](::core::cmp::Ordering) between `self` and `other`.
+            ///
+            /// This is the `const` equivalent of `Ord::cmp`.
+            #[inline]
+            pub const fn const_cmp(self, other: Self) -> ::core::cmp::Ordering {
+                let is_big_endian = ::core::cfg!(target_endian = "big");
+                $crate::utils::cmp_bytes(&self.0, &other.0, Self::IS_SIGNED, is_big_endian)
+            }
+
+            /// Creates an integer from the `u64` value if it is within bounds.
+            ///
+            /// This is the `const` equivalent of `TryFrom<u64>`.
+            #[inline]
+            pub const fn checked_from_u64(value: ::core::primitive::u64) -> ::core::option::Option<Self> {
+                Self::checked_from_u128(value as ::core::primitive::u128)
+            }
+
+            /// Creates an integer from the `i64` value if it is within bounds.
+            ///
+            /// This is the `const` equivalent of `TryFrom<i64>`.
+            #[inline]
+            pub const fn checked_from_i64(value: ::core::primitive::i64) -> ::core::option::Option<Self> {
+                Self::checked_from_i128(value as ::core::primitive::i128)
+            }
+
+            /// Creates an integer from the `u128` value if it is within bounds.
+            ///
+            /// This is the `const` equivalent of `TryFrom<u128>`.
+            #[inline]
+            pub const fn checked_from_u128(value: ::core::primitive::u128) -> ::core::option::Option<Self> {
+                Self::checked_from_limbs(limbs::from_u128(value), false)
+            }
+
+            /// Creates an integer from the `i128` value if it is within bounds.
+            ///
+            /// This is the `const` equivalent of `TryFrom<i128>`.
+            #[inline]
+            pub const fn checked_from_i128(value: ::core::primitive::i128) -> ::core::option::Option<Self> {
+                Self::checked_from_limbs(limbs::from_i128(value), value < 0)
+            }
+
+            /// Calculates `self + rhs`.
+            ///
+            /// Returns a tuple of the addition along with a boolean indicating whether an
+            /// arithmetic overflow would occur. If an overflow would have occurred then
+            /// the wrapped value is returned.
+            #[inline]
+            pub const fn overflowing_add(self, rhs: Self) -> (Self, ::core::primitive::bool) {
+                let (lhs, rhs) = (self.to_limbs(), rhs.to_limbs());
+                Self::from_overflowing_limbs(wide_int!(@add $signedness, lhs, rhs))
+            }
+
+            /// Calculates `self - rhs`.
+            ///
+            /// Returns a tuple of the subtraction along with a boolean indicating whether an
+            /// arithmetic overflow would occur. If an overflow would have occurred then
+            /// the wrapped value is returned.
+            #[inline]
+            pub const fn overflowing_sub(self, rhs: Self) -> (Self, ::core::primitive::bool) {
+                let (lhs, rhs) = (self.to_limbs(), rhs.to_limbs());
+                Self::from_overflowing_limbs(wide_int!(@sub $signedness, lhs, rhs))
+            }
+
+            /// Calculates `self * rhs`.
+            ///
+            /// Returns a tuple of the multiplication along with a boolean indicating whether an
+            /// arithmetic overflow would occur. If an overflow would have occurred then
+            /// the wrapped value is returned.
+            #[inline]
+            pub const fn overflowing_mul(self, rhs: Self) -> (Self, ::core::primitive::bool) {
+                let (lhs, rhs) = (self.to_limbs(), rhs.to_limbs());
+                Self::from_overflowing_limbs(wide_int!(@mul $signedness, lhs, rhs))
+            }
+
+            /// Calculates `self / rhs`.
+            ///
+            /// Returns a tuple of the divisor along with a boolean indicating whether an
+            /// arithmetic overflow would occur. If an overflow would occur then `self` is returned.
+            ///
+            /// # Panics
+            ///
+            /// If `rhs` is zero.
+            #[inline]
+            pub const fn overflowing_div(self, rhs: Self) -> (Self, ::core::primitive::bool) {
+                if rhs.const_eq(Self::ZERO) {
+                    ::core::panic!("attempt to divide by zero")
+                }
+                let (lhs, rhs) = (self.to_limbs(), rhs.to_limbs());
+                match Self::from_overflowing_limbs(wide_int!(@div $signedness, lhs, rhs)) {
+                    (_, true) => (self, true),
+                    result => result,
+                }
+            }
+
+            /// Calculates the remainder when `self` is divided by `rhs`.
+            ///
+            /// Returns a tuple of the remainder after dividing along with a boolean indicating
+            /// whether an arithmetic overflow would occur. If an overflow would occur then 0 is returned.
+            ///
+            /// # Panics
+            ///
+            /// If `rhs` is zero.
+            #[inline]
+            pub const fn overflowing_rem(self, rhs: Self) -> (Self, ::core::primitive::bool) {
+                if rhs.const_eq(Self::ZERO) {
+                    ::core::panic!("attempt to calculate the remainder with a divisor of zero")
+                }
+                if self.overflowing_div(rhs).1 {
+                    return (Self::ZERO, true);
+                }
+                let (lhs, rhs) = (self.to_limbs(), rhs.to_limbs());
+                Self::from_overflowing_limbs(wide_int!(@rem $signedness, lhs, rhs))
+            }
+
+            /// Negates `self` in an overflowing fashion.
+            ///
+            /// Returns a tuple of the negated version of self along with a boolean
+            /// indicating whether an overflow happened.
+            #[inline]
+            pub const fn overflowing_neg(self) -> (Self, ::core::primitive::bool) {
+                Self::ZERO.overflowing_sub(self)
+            }
+
+            /// Shifts `self` left by `rhs` bits.
+            ///
+            /// Returns a tuple of the shifted version of `self` along with a boolean indicating
+            /// whether the shift value was larger than or equal to the number of bits.
+            /// If the shift value is too large, then value is masked by `Self::BITS - 1`
+            /// and this value is then used to perform the shift.
+            #[inline]
+            pub const fn overflowing_shl(self, rhs: ::core::primitive::u32) -> (Self, ::core::primitive::bool) {
+                let shifted = limbs::shl(self.to_limbs(), rhs % Self::BITS);
+                (Self::from_limbs_truncating(shifted), rhs >= Self::BITS)
+            }
+
+            /// Shifts `self` right by `rhs` bits.
+            ///
+            /// Returns a tuple of the shifted version of `self` along with a boolean indicating
+            /// whether the shift value was larger than or equal to the number of bits.
+            /// If the shift value is too large, then value is masked by `Self::BITS - 1`
+            /// and this value is then used to perform the shift.
+            #[inline]
+            pub const fn overflowing_shr(self, rhs: ::core::primitive::u32) -> (Self, ::core::primitive::bool) {
+                let lhs = self.to_limbs();
+                let shifted = wide_int!(@shr $signedness, lhs, rhs % Self::BITS);
+                (Self::from_limbs_truncating(shifted), rhs >= Self::BITS)
+            }
+
+            /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+            ///
+            /// Returns a tuple of the exponentiation along with a bool indicating
+            /// whether an overflow happened.
+            #[inline]
+            pub const fn overflowing_pow(self, mut exp: ::core::primitive::u32) -> (Self, ::core::primitive::bool) {
+                let mut base = self;
+                let mut acc = Self::from_limbs_truncating(limbs::from_u128(1));
+                let mut overflow = false;
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        let (result, o) = acc.overflowing_mul(base);
+                        acc = result;
+                        overflow |= o;
+                    }
+                    exp >>= 1;
+                    if exp > 0 {
+                        let (result, o) = base.overflowing_mul(base);
+                        base = result;
+                        overflow |= o;
+                    }
+                }
+                (acc, overflow)
+            }
+
+            wide_int!(@checked_wrapping
+                add(rhs: Self) -> checked_add, wrapping_add, overflowing_add;
+                sub(rhs: Self) -> checked_sub, wrapping_sub, overflowing_sub;
+                mul(rhs: Self) -> checked_mul, wrapping_mul, overflowing_mul;
+                shl(rhs: ::core::primitive::u32) -> checked_shl, wrapping_shl, overflowing_shl;
+                shr(rhs: ::core::primitive::u32) -> checked_shr, wrapping_shr, overflowing_shr;
+                pow(exp: ::core::primitive::u32) -> checked_pow, wrapping_pow, overflowing_pow;
+            );
+
+            /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0` or the division results in overflow.
+            #[inline]
+            pub const fn checked_div(self, rhs: Self) -> ::core::option::Option<Self> {
+                if rhs.const_eq(Self::ZERO) {
+                    return ::core::option::Option::None;
+                }
+                match self.overflowing_div(rhs) {
+                    (_, true) => ::core::option::Option::None,
+                    (result, false) => ::core::option::Option::Some(result),
+                }
+            }
+
+            /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0` or the division results in overflow.
+            #[inline]
+            pub const fn checked_rem(self, rhs: Self) -> ::core::option::Option<Self> {
+                if rhs.const_eq(Self::ZERO) {
+                    return ::core::option::Option::None;
+                }
+                match self.overflowing_rem(rhs) {
+                    (_, true) => ::core::option::Option::None,
+                    (result, false) => ::core::option::Option::Some(result),
+                }
+            }
+
+            /// Checked negation. Computes `-self`, returning `None` if the result overflows.
+            #[inline]
+            pub const fn checked_neg(self) -> ::core::option::Option<Self> {
+                match self.overflowing_neg() {
+                    (_, true) => ::core::option::Option::None,
+                    (result, false) => ::core::option::Option::Some(result),
+                }
+            }
+
+            /// Wrapping (modular) division. Computes `self / rhs`, wrapping around at the boundary of the type.
+            ///
+            /// # Panics
+            ///
+            /// If `rhs` is zero.
+            #[inline]
+            pub const fn wrapping_div(self, rhs: Self) -> Self {
+                self.overflowing_div(rhs).0
+            }
+
+            /// Wrapping (modular) remainder. Computes `self % rhs`, wrapping around at the boundary of the type.
+            ///
+            /// # Panics
+            ///
+            /// If `rhs` is zero.
+            #[inline]
+            pub const fn wrapping_rem(self, rhs: Self) -> Self {
+                self.overflowing_rem(rhs).0
+            }
+
+            /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type.
+            #[inline]
+            pub const fn wrapping_neg(self) -> Self {
+                self.overflowing_neg().0
+            }
+
+            /// Saturating integer addition. Computes `self + rhs`, saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                match self.overflowing_add(rhs) {
+                    (_, true) => match rhs.is_negative_value() {
+                        true => Self::MIN,
+                        false => Self::MAX,
+                    },
+                    (result, false) => result,
+                }
+            }
+
+            /// Saturating integer subtraction. Computes `self - rhs`, saturating at the numeric bounds instead of overflowing.
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                match self.overflowing_sub(rhs) {
+                    (_, true) => match rhs.is_negative_value() {
+                        true => Self::MAX,
+                        false => Self::MIN,
+                    },
+                    (result, false) => result,
+                }
+            }
+
+            /// Converts a string slice in a given base to an integer.
+            ///
+            /// The string is expected to be an optional `+` or `-` sign followed by digits.
+            /// Leading and trailing whitespace represent an error. Digits are a subset of these
+            /// characters, depending on `radix`:
+            ///
+            /// - `0-9`
+            /// - `a-z`
+            /// - `A-Z`
+            ///
+            /// # Errors
+            ///
+            /// If the string is empty, contains invalid digits or
+            /// if the parsed value does not fit into `Self`.
+            ///
+            /// # Panics
+            ///
+            /// If `radix` is not in the range from 2 to 36.
+            #[inline]
+            pub const fn from_str_radix(
+                src: &::core::primitive::str,
+                radix: ::core::primitive::u32,
+            ) -> ::core::result::Result<Self, $crate::ParseIntError> {
+                let (magnitude, is_negative) = match limbs::parse(src, radix, Self::IS_SIGNED) {
+                    ::core::result::Result::Ok(parsed) => parsed,
+                    ::core::result::Result::Err(kind) => {
+                        return ::core::result::Result::Err($crate::ParseIntError::new(kind))
+                    }
+                };
+                let is_negative = is_negative && !limbs::eq(magnitude, limbs::ZERO);
+                let value = match is_negative {
+                    true => limbs::neg(magnitude),
+                    false => magnitude,
+                };
+                match Self::checked_from_limbs(value, is_negative) {
+                    ::core::option::Option::Some(result) => ::core::result::Result::Ok(result),
+                    ::core::option::Option::None => {
+                        let kind = match is_negative {
+                            true => $crate::IntErrorKind::NegOverflow,
+                            false => $crate::IntErrorKind::PosOverflow,
+                        };
+                        ::core::result::Result::Err($crate::ParseIntError::new(kind))
+                    }
+                }
+            }
+        }
+
+        wide_int!(@bounds $name, $num_bytes, $signedness);
+
+        impl ::core::default::Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl ::core::cmp::PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(<Self as ::core::cmp::Ord>::cmp(self, other))
+            }
+        }
+
+        impl ::core::cmp::Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                Self::const_cmp(*self, *other)
+            }
+        }
+
+        impl ::core::hash::Hash for $name {
+            #[inline]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                state.write(&self.0)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::ParseIntError;
+
+            #[inline]
+            fn from_str(src: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                Self::from_str_radix(src, 10)
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                let is_nonnegative = !self.is_negative_value();
+                let magnitude = match is_nonnegative {
+                    true => self.to_limbs(),
+                    false => limbs::neg(self.to_limbs()),
+                };
+                $crate::wide::fmt_radix(f, magnitude, is_nonnegative, 10, false, "")
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                <Self as ::core::fmt::Display>::fmt(self, f)
+            }
+        }
+
+        wide_int!(@fmt_radix $name: LowerHex(16, false, "0x"), UpperHex(16, true, "0x"), Octal(8, false, "0o"), Binary(2, false, "0b"));
+
+        $crate::ops::impl_arith_ops!($name);
+        $crate::ops::impl_bit_ops!($name);
+        $crate::ops::impl_shift_ops!($name);
+
+        // SAFETY: `$name` is a `#[repr(transparent)]` wrapper around a byte array
+        //         for which the all-zero bit pattern is a valid value.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::Zeroable for $name {}
+
+        // SAFETY: `$name` is a `#[repr(transparent)]` wrapper around a byte array
+        //         which has no padding bytes and for which all bit patterns are valid.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::Pod for $name {}
+
+        #[cfg(feature = "serde")]
+        $crate::serde::impl_serde!($name, wide);
+    };
+    ( @checked_wrapping $(
+        $op:ident($rhs:ident: $rhs_ty:ty) -> $checked:ident, $wrapping:ident, $overflowing:ident;
+    )* ) => {
+        $(
+            #[doc = ::core::concat!("Checked `", ::core::stringify!($op), "`, returning `None` if overflow occurred.")]
+            #[inline]
+            pub const fn $checked(self, $rhs: $rhs_ty) -> ::core::option::Option<Self> {
+                match self.$overflowing($rhs) {
+                    (_, true) => ::core::option::Option::None,
+                    (result, false) => ::core::option::Option::Some(result),
+                }
+            }
+
+            #[doc = ::core::concat!("Wrapping `", ::core::stringify!($op), "`, wrapping around at the boundary of the type.")]
+            #[inline]
+            pub const fn $wrapping(self, $rhs: $rhs_ty) -> Self {
+                self.$overflowing($rhs).0
+            }
+        )*
+    };
+    ( @fmt_radix $name:ident: $( $fmt_trait:ident($radix:literal, $upper:literal, $prefix:literal) ),* ) => {
+        $(
+            impl ::core::fmt::$fmt_trait for $name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    // Note: just like Rust primitives the two's-complement bits of `Self` are formatted.
+                    let bits = limbs::from_ne_bytes(self.0, 0x00_u8);
+                    $crate::wide::fmt_radix(f, bits, true, $radix, $upper, $prefix)
+                }
+            }
+        )*
+    };
+    ( @is_signed unsigned ) => { false };
+    ( @is_signed signed ) => { true };
+    ( @bounds $name:ident, $num_bytes:literal, unsigned ) => {
+        impl $name {
+            /// The smallest value that can be represented by this integer type.
+            pub const MIN: Self = Self([0x00_u8; $num_bytes]);
+
+            /// The largest value that can be represented by this integer type.
+            pub const MAX: Self = Self([0xFF_u8; $num_bytes]);
+        }
+    };
+    ( @bounds $name:ident, $num_bytes:literal, signed ) => {
+        impl $name {
+            /// The smallest value that can be represented by this integer type.
+            pub const MIN: Self = {
+                let mut bytes = [0x00_u8; $num_bytes];
+                bytes[Self::msb_pos()] = 0x80_u8;
+                Self(bytes)
+            };
+
+            /// The largest value that can be represented by this integer type.
+            pub const MAX: Self = {
+                let mut bytes = [0xFF_u8; $num_bytes];
+                bytes[Self::msb_pos()] = 0x7F_u8;
+                Self(bytes)
+            };
+
+            /// Returns `true` if `self` is negative and `false` if `self` is zero or positive.
+            #[inline]
+            pub const fn is_negative(self) -> ::core::primitive::bool {
+                self.is_negative_value()
+            }
+        }
+
+        $crate::ops::impl_neg_op!($name);
+    };
+    ( @add unsigned, $lhs:ident, $rhs:ident ) => {
+        limbs::overflowing_add($lhs, $rhs)
+    };
+    ( @add signed, $lhs:ident, $rhs:ident ) => {{
+        let (result, _) = limbs::overflowing_add($lhs, $rhs);
+        let sign = limbs::is_negative($lhs);
+        (result, sign == limbs::is_negative($rhs) && sign != limbs::is_negative(result))
+    }};
+    ( @sub unsigned, $lhs:ident, $rhs:ident ) => {
+        limbs::overflowing_sub($lhs, $rhs)
+    };
+    ( @sub signed, $lhs:ident, $rhs:ident ) => {{
+        let (result, _) = limbs::overflowing_sub($lhs, $rhs);
+        let sign = limbs::is_negative($lhs);
+        (result, sign != limbs::is_negative($rhs) && sign != limbs::is_negative(result))
+    }};
+    ( @mul unsigned, $lhs:ident, $rhs:ident ) => {
+        limbs::overflowing_mul($lhs, $rhs)
+    };
+    ( @mul signed, $lhs:ident, $rhs:ident ) => {{
+        let (product, overflow) =
+            limbs::overflowing_mul(limbs::unsigned_abs($lhs), limbs::unsigned_abs($rhs));
+        $crate::wide::apply_sign(product, overflow, limbs::is_negative($lhs) != limbs::is_negative($rhs))
+    }};
+    ( @div unsigned, $lhs:ident, $rhs:ident ) => {
+        (limbs::div_rem($lhs, $rhs).0, false)
+    };
+    ( @div signed, $lhs:ident, $rhs:ident ) => {{
+        let (quotient, _) = limbs::div_rem(limbs::unsigned_abs($lhs), limbs::unsigned_abs($rhs));
+        $crate::wide::apply_sign(quotient, false, limbs::is_negative($lhs) != limbs::is_negative($rhs))
+    }};
+    ( @rem unsigned, $lhs:ident, $rhs:ident ) => {
+        (limbs::div_rem($lhs, $rhs).1, false)
+    };
+    ( @rem signed, $lhs:ident, $rhs:ident ) => {{
+        let (_, remainder) = limbs::div_rem(limbs::unsigned_abs($lhs), limbs::unsigned_abs($rhs));
+        $crate::wide::apply_sign(remainder, false, limbs::is_negative($lhs))
+    }};
+    ( @shr unsigned, $lhs:ident, $rhs:expr ) => {
+        limbs::shr($lhs, $rhs)
+    };
+    ( @shr signed, $lhs:ident, $rhs:expr ) => {
+        // Note: the sign extension of `to_limbs` makes this an arithmetic shift.
+        limbs::sar($lhs, $rhs)
+    };
+}
+
+/// Applies the sign to the unsigned `magnitude` of a signed operation.
+///
+/// Returns the signed result and whether an overflow occurred, either
+/// because `overflow` is already set or because the magnitude does
+/// not fit into a 256-bit signed integer.
+#[inline]
+pub(crate) const fn apply_sign(
+    magnitude: Limbs,
+    overflow: bool,
+    is_negative: bool,
+) -> (Limbs, bool) {
+    match is_negative {
+        true => {
+            let min = limbs::shl(limbs::from_u128(1), limbs::BITS - 1);
+            let overflow = overflow || limbs::cmp(magnitude, min).is_gt();
+            (limbs::neg(magnitude), overflow)
+        }
+        false => (magnitude, overflow || limbs::is_negative(magnitude)),
+    }
+}
+
+/// Formats the unsigned `limbs` in the given `radix` with `f`.
+pub(crate) fn fmt_radix(
+    f: &mut core::fmt::Formatter,
+    limbs: Limbs,
+    is_nonnegative: bool,
+    radix: u64,
+    upper: bool,
+    prefix: &str,
+) -> core::fmt::Result {
+    let mut buffer = [0x00_u8; limbs::MAX_DIGITS];
+    let start = limbs::write_digits(limbs, radix, upper, &mut buffer);
+    let digits = core::str::from_utf8(&buffer[start..]).map_err(|_| core::fmt::Error)?;
+    f.pad_integral(is_nonnegative, prefix, digits)
+}
+
+wide_int! {
+    /// 136-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U136([u8; 17]);
+    /// 136-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I136([u8; 17]);
+    /// 144-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U144([u8; 18]);
+    /// 144-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I144([u8; 18]);
+    /// 152-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U152([u8; 19]);
+    /// 152-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I152([u8; 19]);
+    /// 160-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U160([u8; 20]);
+    /// 160-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I160([u8; 20]);
+    /// 168-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U168([u8; 21]);
+    /// 168-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I168([u8; 21]);
+    /// 176-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U176([u8; 22]);
+    /// 176-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I176([u8; 22]);
+    /// 184-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U184([u8; 23]);
+    /// 184-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I184([u8; 23]);
+    /// 192-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U192([u8; 24]);
+    /// 192-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I192([u8; 24]);
+    /// 200-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U200([u8; 25]);
+    /// 200-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I200([u8; 25]);
+    /// 208-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U208([u8; 26]);
+    /// 208-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I208([u8; 26]);
+    /// 216-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U216([u8; 27]);
+    /// 216-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I216([u8; 27]);
+    /// 224-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U224([u8; 28]);
+    /// 224-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I224([u8; 28]);
+    /// 232-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U232([u8; 29]);
+    /// 232-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I232([u8; 29]);
+    /// 240-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U240([u8; 30]);
+    /// 240-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I240([u8; 30]);
+    /// 248-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U248([u8; 31]);
+    /// 248-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I248([u8; 31]);
+    /// 256-bit unsigned integer with alignment of 1.
+    @[unsigned]
+    pub struct U256([u8; 32]);
+    /// 256-bit signed integer with alignment of 1.
+    @[signed]
+    pub struct I256([u8; 32]);
+}
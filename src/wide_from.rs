@@ -0,0 +1,3821 @@
+//! Module to implement all `From` and `TryFrom` implementations for
+//! the unaligned integer types wider than 128 bits.
+//!
+//! All conversions go through the 256-bit multi-limb integers of the `limbs` module.
+//! Conversions are infallible if they are lossless and fallible otherwise.
+
+use crate::limbs::{self, Limbs};
+use crate::{
+    I104, I112, I120, I128, I136, I144, I152, I16, I160, I168, I176, I184, I192, I200, I208, I216,
+    I224, I232, I24, I240, I248, I256, I32, I40, I48, I56, I64, I72, I80, I88, I96, U104, U112,
+    U120, U128, U136, U144, U152, U16, U160, U168, U176, U184, U192, U200, U208, U216, U224, U232,
+    U24, U240, U248, U256, U32, U40, U48, U56, U64, U72, U80, U88, U96,
+};
+
+/// Trait implemented by all integer types that can be converted into multi-limb integers.
+trait IntoLimbs {
+    /// Returns the 256-bit multi-limb integer of `self` and `true` if `self` is negative.
+    fn into_limbs(self) -> (Limbs, bool);
+}
+
+macro_rules! impl_into_limbs {
+    ( $( impl IntoLimbs for $( $ty:ty ),* as $kind:ident; )* ) => {
+        $(
+            $( impl_into_limbs!(@impl $ty as $kind); )*
+        )*
+    };
+    ( @impl $ty:ty as unsigned ) => {
+        impl IntoLimbs for $ty {
+            #[inline]
+            #[allow(clippy::unnecessary_cast)] // Note: `$ty` might be `u128` already.
+            fn into_limbs(self) -> (Limbs, bool) {
+                (limbs::from_u128(self as u128), false)
+            }
+        }
+    };
+    ( @impl $ty:ty as signed ) => {
+        impl IntoLimbs for $ty {
+            #[inline]
+            #[allow(clippy::unnecessary_cast)] // Note: `$ty` might be `i128` already.
+            fn into_limbs(self) -> (Limbs, bool) {
+                (limbs::from_i128(self as i128), self < 0)
+            }
+        }
+    };
+    ( @impl $ty:ty as unaligned_unsigned ) => {
+        impl IntoLimbs for $ty {
+            #[inline]
+            fn into_limbs(self) -> (Limbs, bool) {
+                (limbs::from_u128(self.to_repr() as u128), false)
+            }
+        }
+    };
+    ( @impl $ty:ty as unaligned_signed ) => {
+        impl IntoLimbs for $ty {
+            #[inline]
+            fn into_limbs(self) -> (Limbs, bool) {
+                (limbs::from_i128(self.to_repr() as i128), self.is_negative())
+            }
+        }
+    };
+    ( @impl $ty:ty as wide ) => {
+        impl IntoLimbs for $ty {
+            #[inline]
+            fn into_limbs(self) -> (Limbs, bool) {
+                (self.to_limbs(), self.is_negative_value())
+            }
+        }
+    };
+}
+impl_into_limbs! {
+    impl IntoLimbs for u8, u16, u32, u64, u128, usize as unsigned;
+    impl IntoLimbs for i8, i16, i32, i64, i128, isize as signed;
+    impl IntoLimbs for U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96, U104, U112, U120, U128 as unaligned_unsigned;
+    impl IntoLimbs for I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, I104, I112, I120, I128 as unaligned_signed;
+    impl IntoLimbs for U136, I136, U144, I144, U152, I152, U160, I160, U168, I168, U176, I176, U184, I184, U192, I192, U200, I200, U208, I208, U216, I216, U224, I224, U232, I232, U240, I240, U248, I248, U256, I256 as wide;
+}
+
+macro_rules! impl_wide_conversions {
+    ( $( impl $trait:ident<$from:ty> for $to:ty $( as $kind:ident )?; )* ) => {
+        $( impl_wide_conversions!(@impl $trait<$from> for $to $( as $kind )?); )*
+    };
+    ( @impl From<$from:ty> for $to:ty ) => {
+        impl ::core::convert::From<$from> for $to {
+            #[inline]
+            fn from(value: $from) -> Self {
+                let (limbs, _) = <$from as IntoLimbs>::into_limbs(value);
+                Self::from_limbs_truncating(limbs)
+            }
+        }
+    };
+    ( @impl TryFrom<$from:ty> for $to:ty ) => {
+        impl ::core::convert::TryFrom<$from> for $to {
+            type Error = $crate::TryFromIntError;
+
+            #[inline]
+            fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
+                let (limbs, is_negative) = <$from as IntoLimbs>::into_limbs(value);
+                Self::checked_from_limbs(limbs, is_negative)
+                    .ok_or_else(|| $crate::error::try_from_int_error!(Self, is_negative))
+            }
+        }
+    };
+    ( @impl TryFrom<$from:ty> for $to:ty as primitive ) => {
+        impl ::core::convert::TryFrom<$from> for $to {
+            type Error = $crate::TryFromIntError;
+
+            #[inline]
+            fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
+                let (limbs, is_negative) = <$from as IntoLimbs>::into_limbs(value);
+                let result = match is_negative {
+                    true => limbs::to_i128(limbs).and_then(|value| <$to>::try_from(value).ok()),
+                    false => limbs::to_u128(limbs).and_then(|value| <$to>::try_from(value).ok()),
+                };
+                result.ok_or_else(|| $crate::error::try_from_int_error!($to, is_negative))
+            }
+        }
+    };
+    ( @impl TryFrom<$from:ty> for $to:ty as unaligned ) => {
+        impl ::core::convert::TryFrom<$from> for $to {
+            type Error = $crate::TryFromIntError;
+
+            #[inline]
+            fn try_from(value: $from) -> ::core::result::Result<Self, Self::Error> {
+                let (limbs, is_negative) = <$from as IntoLimbs>::into_limbs(value);
+                let result = match is_negative {
+                    true => limbs::to_i128(limbs).and_then(<$to>::checked_from_i128),
+                    false => limbs::to_u128(limbs).and_then(<$to>::checked_from_u128),
+                };
+                result.ok_or_else(|| $crate::error::try_from_int_error!($to, is_negative))
+            }
+        }
+    };
+}
+// The following macro calls contain lots of `From` and `TryFrom` impl declarations.
+//
+// They are ordered by the bitwidth of the implemented type with unsigned integer types
+// appearing before signed integer types and Rust primitives appearing first.
+impl_wide_conversions! {
+    impl From<u8> for U136;
+    impl From<u16> for U136;
+    impl From<u32> for U136;
+    impl From<u64> for U136;
+    impl From<u128> for U136;
+    impl From<usize> for U136;
+    impl TryFrom<i8> for U136;
+    impl TryFrom<i16> for U136;
+    impl TryFrom<i32> for U136;
+    impl TryFrom<i64> for U136;
+    impl TryFrom<i128> for U136;
+    impl TryFrom<isize> for U136;
+    impl From<U16> for U136;
+    impl TryFrom<I16> for U136;
+    impl From<U24> for U136;
+    impl TryFrom<I24> for U136;
+    impl From<U32> for U136;
+    impl TryFrom<I32> for U136;
+    impl From<U40> for U136;
+    impl TryFrom<I40> for U136;
+    impl From<U48> for U136;
+    impl TryFrom<I48> for U136;
+    impl From<U56> for U136;
+    impl TryFrom<I56> for U136;
+    impl From<U64> for U136;
+    impl TryFrom<I64> for U136;
+    impl From<U72> for U136;
+    impl TryFrom<I72> for U136;
+    impl From<U80> for U136;
+    impl TryFrom<I80> for U136;
+    impl From<U88> for U136;
+    impl TryFrom<I88> for U136;
+    impl From<U96> for U136;
+    impl TryFrom<I96> for U136;
+    impl From<U104> for U136;
+    impl TryFrom<I104> for U136;
+    impl From<U112> for U136;
+    impl TryFrom<I112> for U136;
+    impl From<U120> for U136;
+    impl TryFrom<I120> for U136;
+    impl From<U128> for U136;
+    impl TryFrom<I128> for U136;
+    impl TryFrom<I136> for U136;
+    impl TryFrom<U144> for U136;
+    impl TryFrom<I144> for U136;
+    impl TryFrom<U152> for U136;
+    impl TryFrom<I152> for U136;
+    impl TryFrom<U160> for U136;
+    impl TryFrom<I160> for U136;
+    impl TryFrom<U168> for U136;
+    impl TryFrom<I168> for U136;
+    impl TryFrom<U176> for U136;
+    impl TryFrom<I176> for U136;
+    impl TryFrom<U184> for U136;
+    impl TryFrom<I184> for U136;
+    impl TryFrom<U192> for U136;
+    impl TryFrom<I192> for U136;
+    impl TryFrom<U200> for U136;
+    impl TryFrom<I200> for U136;
+    impl TryFrom<U208> for U136;
+    impl TryFrom<I208> for U136;
+    impl TryFrom<U216> for U136;
+    impl TryFrom<I216> for U136;
+    impl TryFrom<U224> for U136;
+    impl TryFrom<I224> for U136;
+    impl TryFrom<U232> for U136;
+    impl TryFrom<I232> for U136;
+    impl TryFrom<U240> for U136;
+    impl TryFrom<I240> for U136;
+    impl TryFrom<U248> for U136;
+    impl TryFrom<I248> for U136;
+    impl TryFrom<U256> for U136;
+    impl TryFrom<I256> for U136;
+    impl From<u8> for I136;
+    impl From<u16> for I136;
+    impl From<u32> for I136;
+    impl From<u64> for I136;
+    impl From<u128> for I136;
+    impl From<usize> for I136;
+    impl From<i8> for I136;
+    impl From<i16> for I136;
+    impl From<i32> for I136;
+    impl From<i64> for I136;
+    impl From<i128> for I136;
+    impl From<isize> for I136;
+    impl From<U16> for I136;
+    impl From<I16> for I136;
+    impl From<U24> for I136;
+    impl From<I24> for I136;
+    impl From<U32> for I136;
+    impl From<I32> for I136;
+    impl From<U40> for I136;
+    impl From<I40> for I136;
+    impl From<U48> for I136;
+    impl From<I48> for I136;
+    impl From<U56> for I136;
+    impl From<I56> for I136;
+    impl From<U64> for I136;
+    impl From<I64> for I136;
+    impl From<U72> for I136;
+    impl From<I72> for I136;
+    impl From<U80> for I136;
+    impl From<I80> for I136;
+    impl From<U88> for I136;
+    impl From<I88> for I136;
+    impl From<U96> for I136;
+    impl From<I96> for I136;
+    impl From<U104> for I136;
+    impl From<I104> for I136;
+    impl From<U112> for I136;
+    impl From<I112> for I136;
+    impl From<U120> for I136;
+    impl From<I120> for I136;
+    impl From<U128> for I136;
+    impl From<I128> for I136;
+    impl TryFrom<U136> for I136;
+    impl TryFrom<U144> for I136;
+    impl TryFrom<I144> for I136;
+    impl TryFrom<U152> for I136;
+    impl TryFrom<I152> for I136;
+    impl TryFrom<U160> for I136;
+    impl TryFrom<I160> for I136;
+    impl TryFrom<U168> for I136;
+    impl TryFrom<I168> for I136;
+    impl TryFrom<U176> for I136;
+    impl TryFrom<I176> for I136;
+    impl TryFrom<U184> for I136;
+    impl TryFrom<I184> for I136;
+    impl TryFrom<U192> for I136;
+    impl TryFrom<I192> for I136;
+    impl TryFrom<U200> for I136;
+    impl TryFrom<I200> for I136;
+    impl TryFrom<U208> for I136;
+    impl TryFrom<I208> for I136;
+    impl TryFrom<U216> for I136;
+    impl TryFrom<I216> for I136;
+    impl TryFrom<U224> for I136;
+    impl TryFrom<I224> for I136;
+    impl TryFrom<U232> for I136;
+    impl TryFrom<I232> for I136;
+    impl TryFrom<U240> for I136;
+    impl TryFrom<I240> for I136;
+    impl TryFrom<U248> for I136;
+    impl TryFrom<I248> for I136;
+    impl TryFrom<U256> for I136;
+    impl TryFrom<I256> for I136;
+    impl From<u8> for U144;
+    impl From<u16> for U144;
+    impl From<u32> for U144;
+    impl From<u64> for U144;
+    impl From<u128> for U144;
+    impl From<usize> for U144;
+    impl TryFrom<i8> for U144;
+    impl TryFrom<i16> for U144;
+    impl TryFrom<i32> for U144;
+    impl TryFrom<i64> for U144;
+    impl TryFrom<i128> for U144;
+    impl TryFrom<isize> for U144;
+    impl From<U16> for U144;
+    impl TryFrom<I16> for U144;
+    impl From<U24> for U144;
+    impl TryFrom<I24> for U144;
+    impl From<U32> for U144;
+    impl TryFrom<I32> for U144;
+    impl From<U40> for U144;
+    impl TryFrom<I40> for U144;
+    impl From<U48> for U144;
+    impl TryFrom<I48> for U144;
+    impl From<U56> for U144;
+    impl TryFrom<I56> for U144;
+    impl From<U64> for U144;
+    impl TryFrom<I64> for U144;
+    impl From<U72> for U144;
+    impl TryFrom<I72> for U144;
+    impl From<U80> for U144;
+    impl TryFrom<I80> for U144;
+    impl From<U88> for U144;
+    impl TryFrom<I88> for U144;
+    impl From<U96> for U144;
+    impl TryFrom<I96> for U144;
+    impl From<U104> for U144;
+    impl TryFrom<I104> for U144;
+    impl From<U112> for U144;
+    impl TryFrom<I112> for U144;
+    impl From<U120> for U144;
+    impl TryFrom<I120> for U144;
+    impl From<U128> for U144;
+    impl TryFrom<I128> for U144;
+    impl From<U136> for U144;
+    impl TryFrom<I136> for U144;
+    impl TryFrom<I144> for U144;
+    impl TryFrom<U152> for U144;
+    impl TryFrom<I152> for U144;
+    impl TryFrom<U160> for U144;
+    impl TryFrom<I160> for U144;
+    impl TryFrom<U168> for U144;
+    impl TryFrom<I168> for U144;
+    impl TryFrom<U176> for U144;
+    impl TryFrom<I176> for U144;
+    impl TryFrom<U184> for U144;
+    impl TryFrom<I184> for U144;
+    impl TryFrom<U192> for U144;
+    impl TryFrom<I192> for U144;
+    impl TryFrom<U200> for U144;
+    impl TryFrom<I200> for U144;
+    impl TryFrom<U208> for U144;
+    impl TryFrom<I208> for U144;
+    impl TryFrom<U216> for U144;
+    impl TryFrom<I216> for U144;
+    impl TryFrom<U224> for U144;
+    impl TryFrom<I224> for U144;
+    impl TryFrom<U232> for U144;
+    impl TryFrom<I232> for U144;
+    impl TryFrom<U240> for U144;
+    impl TryFrom<I240> for U144;
+    impl TryFrom<U248> for U144;
+    impl TryFrom<I248> for U144;
+    impl TryFrom<U256> for U144;
+    impl TryFrom<I256> for U144;
+    impl From<u8> for I144;
+    impl From<u16> for I144;
+    impl From<u32> for I144;
+    impl From<u64> for I144;
+    impl From<u128> for I144;
+    impl From<usize> for I144;
+    impl From<i8> for I144;
+    impl From<i16> for I144;
+    impl From<i32> for I144;
+    impl From<i64> for I144;
+    impl From<i128> for I144;
+    impl From<isize> for I144;
+    impl From<U16> for I144;
+    impl From<I16> for I144;
+    impl From<U24> for I144;
+    impl From<I24> for I144;
+    impl From<U32> for I144;
+    impl From<I32> for I144;
+    impl From<U40> for I144;
+    impl From<I40> for I144;
+    impl From<U48> for I144;
+    impl From<I48> for I144;
+    impl From<U56> for I144;
+    impl From<I56> for I144;
+    impl From<U64> for I144;
+    impl From<I64> for I144;
+    impl From<U72> for I144;
+    impl From<I72> for I144;
+    impl From<U80> for I144;
+    impl From<I80> for I144;
+    impl From<U88> for I144;
+    impl From<I88> for I144;
+    impl From<U96> for I144;
+    impl From<I96> for I144;
+    impl From<U104> for I144;
+    impl From<I104> for I144;
+    impl From<U112> for I144;
+    impl From<I112> for I144;
+    impl From<U120> for I144;
+    impl From<I120> for I144;
+    impl From<U128> for I144;
+    impl From<I128> for I144;
+    impl From<U136> for I144;
+    impl From<I136> for I144;
+    impl TryFrom<U144> for I144;
+    impl TryFrom<U152> for I144;
+    impl TryFrom<I152> for I144;
+    impl TryFrom<U160> for I144;
+    impl TryFrom<I160> for I144;
+    impl TryFrom<U168> for I144;
+    impl TryFrom<I168> for I144;
+    impl TryFrom<U176> for I144;
+    impl TryFrom<I176> for I144;
+    impl TryFrom<U184> for I144;
+    impl TryFrom<I184> for I144;
+    impl TryFrom<U192> for I144;
+    impl TryFrom<I192> for I144;
+    impl TryFrom<U200> for I144;
+    impl TryFrom<I200> for I144;
+    impl TryFrom<U208> for I144;
+    impl TryFrom<I208> for I144;
+    impl TryFrom<U216> for I144;
+    impl TryFrom<I216> for I144;
+    impl TryFrom<U224> for I144;
+    impl TryFrom<I224> for I144;
+    impl TryFrom<U232> for I144;
+    impl TryFrom<I232> for I144;
+    impl TryFrom<U240> for I144;
+    impl TryFrom<I240> for I144;
+    impl TryFrom<U248> for I144;
+    impl TryFrom<I248> for I144;
+    impl TryFrom<U256> for I144;
+    impl TryFrom<I256> for I144;
+    impl From<u8> for U152;
+    impl From<u16> for U152;
+    impl From<u32> for U152;
+    impl From<u64> for U152;
+    impl From<u128> for U152;
+    impl From<usize> for U152;
+    impl TryFrom<i8> for U152;
+    impl TryFrom<i16> for U152;
+    impl TryFrom<i32> for U152;
+    impl TryFrom<i64> for U152;
+    impl TryFrom<i128> for U152;
+    impl TryFrom<isize> for U152;
+    impl From<U16> for U152;
+    impl TryFrom<I16> for U152;
+    impl From<U24> for U152;
+    impl TryFrom<I24> for U152;
+    impl From<U32> for U152;
+    impl TryFrom<I32> for U152;
+    impl From<U40> for U152;
+    impl TryFrom<I40> for U152;
+    impl From<U48> for U152;
+    impl TryFrom<I48> for U152;
+    impl From<U56> for U152;
+    impl TryFrom<I56> for U152;
+    impl From<U64> for U152;
+    impl TryFrom<I64> for U152;
+    impl From<U72> for U152;
+    impl TryFrom<I72> for U152;
+    impl From<U80> for U152;
+    impl TryFrom<I80> for U152;
+    impl From<U88> for U152;
+    impl TryFrom<I88> for U152;
+    impl From<U96> for U152;
+    impl TryFrom<I96> for U152;
+    impl From<U104> for U152;
+    impl TryFrom<I104> for U152;
+    impl From<U112> for U152;
+    impl TryFrom<I112> for U152;
+    impl From<U120> for U152;
+    impl TryFrom<I120> for U152;
+    impl From<U128> for U152;
+    impl TryFrom<I128> for U152;
+    impl From<U136> for U152;
+    impl TryFrom<I136> for U152;
+    impl From<U144> for U152;
+    impl TryFrom<I144> for U152;
+    impl TryFrom<I152> for U152;
+    impl TryFrom<U160> for U152;
+    impl TryFrom<I160> for U152;
+    impl TryFrom<U168> for U152;
+    impl TryFrom<I168> for U152;
+    impl TryFrom<U176> for U152;
+    impl TryFrom<I176> for U152;
+    impl TryFrom<U184> for U152;
+    impl TryFrom<I184> for U152;
+    impl TryFrom<U192> for U152;
+    impl TryFrom<I192> for U152;
+    impl TryFrom<U200> for U152;
+    impl TryFrom<I200> for U152;
+    impl TryFrom<U208> for U152;
+    impl TryFrom<I208> for U152;
+    impl TryFrom<U216> for U152;
+    impl TryFrom<I216> for U152;
+    impl TryFrom<U224> for U152;
+    impl TryFrom<I224> for U152;
+    impl TryFrom<U232> for U152;
+    impl TryFrom<I232> for U152;
+    impl TryFrom<U240> for U152;
+    impl TryFrom<I240> for U152;
+    impl TryFrom<U248> for U152;
+    impl TryFrom<I248> for U152;
+    impl TryFrom<U256> for U152;
+    impl TryFrom<I256> for U152;
+    impl From<u8> for I152;
+    impl From<u16> for I152;
+    impl From<u32> for I152;
+    impl From<u64> for I152;
+    impl From<u128> for I152;
+    impl From<usize> for I152;
+    impl From<i8> for I152;
+    impl From<i16> for I152;
+    impl From<i32> for I152;
+    impl From<i64> for I152;
+    impl From<i128> for I152;
+    impl From<isize> for I152;
+    impl From<U16> for I152;
+    impl From<I16> for I152;
+    impl From<U24> for I152;
+    impl From<I24> for I152;
+    impl From<U32> for I152;
+    impl From<I32> for I152;
+    impl From<U40> for I152;
+    impl From<I40> for I152;
+    impl From<U48> for I152;
+    impl From<I48> for I152;
+    impl From<U56> for I152;
+    impl From<I56> for I152;
+    impl From<U64> for I152;
+    impl From<I64> for I152;
+    impl From<U72> for I152;
+    impl From<I72> for I152;
+    impl From<U80> for I152;
+    impl From<I80> for I152;
+    impl From<U88> for I152;
+    impl From<I88> for I152;
+    impl From<U96> for I152;
+    impl From<I96> for I152;
+    impl From<U104> for I152;
+    impl From<I104> for I152;
+    impl From<U112> for I152;
+    impl From<I112> for I152;
+    impl From<U120> for I152;
+    impl From<I120> for I152;
+    impl From<U128> for I152;
+    impl From<I128> for I152;
+    impl From<U136> for I152;
+    impl From<I136> for I152;
+    impl From<U144> for I152;
+    impl From<I144> for I152;
+    impl TryFrom<U152> for I152;
+    impl TryFrom<U160> for I152;
+    impl TryFrom<I160> for I152;
+    impl TryFrom<U168> for I152;
+    impl TryFrom<I168> for I152;
+    impl TryFrom<U176> for I152;
+    impl TryFrom<I176> for I152;
+    impl TryFrom<U184> for I152;
+    impl TryFrom<I184> for I152;
+    impl TryFrom<U192> for I152;
+    impl TryFrom<I192> for I152;
+    impl TryFrom<U200> for I152;
+    impl TryFrom<I200> for I152;
+    impl TryFrom<U208> for I152;
+    impl TryFrom<I208> for I152;
+    impl TryFrom<U216> for I152;
+    impl TryFrom<I216> for I152;
+    impl TryFrom<U224> for I152;
+    impl TryFrom<I224> for I152;
+    impl TryFrom<U232> for I152;
+    impl TryFrom<I232> for I152;
+    impl TryFrom<U240> for I152;
+    impl TryFrom<I240> for I152;
+    impl TryFrom<U248> for I152;
+    impl TryFrom<I248> for I152;
+    impl TryFrom<U256> for I152;
+    impl TryFrom<I256> for I152;
+    impl From<u8> for U160;
+    impl From<u16> for U160;
+    impl From<u32> for U160;
+    impl From<u64> for U160;
+    impl From<u128> for U160;
+    impl From<usize> for U160;
+    impl TryFrom<i8> for U160;
+    impl TryFrom<i16> for U160;
+    impl TryFrom<i32> for U160;
+    impl TryFrom<i64> for U160;
+    impl TryFrom<i128> for U160;
+    impl TryFrom<isize> for U160;
+    impl From<U16> for U160;
+    impl TryFrom<I16> for U160;
+    impl From<U24> for U160;
+    impl TryFrom<I24> for U160;
+    impl From<U32> for U160;
+    impl TryFrom<I32> for U160;
+    impl From<U40> for U160;
+    impl TryFrom<I40> for U160;
+    impl From<U48> for U160;
+    impl TryFrom<I48> for U160;
+    impl From<U56> for U160;
+    impl TryFrom<I56> for U160;
+    impl From<U64> for U160;
+    impl TryFrom<I64> for U160;
+    impl From<U72> for U160;
+    impl TryFrom<I72> for U160;
+    impl From<U80> for U160;
+    impl TryFrom<I80> for U160;
+    impl From<U88> for U160;
+    impl TryFrom<I88> for U160;
+    impl From<U96> for U160;
+    impl TryFrom<I96> for U160;
+    impl From<U104> for U160;
+    impl TryFrom<I104> for U160;
+    impl From<U112> for U160;
+    impl TryFrom<I112> for U160;
+    impl From<U120> for U160;
+    impl TryFrom<I120> for U160;
+    impl From<U128> for U160;
+    impl TryFrom<I128> for U160;
+    impl From<U136> for U160;
+    impl TryFrom<I136> for U160;
+    impl From<U144> for U160;
+    impl TryFrom<I144> for U160;
+    impl From<U152> for U160;
+    impl TryFrom<I152> for U160;
+    impl TryFrom<I160> for U160;
+    impl TryFrom<U168> for U160;
+    impl TryFrom<I168> for U160;
+    impl TryFrom<U176> for U160;
+    impl TryFrom<I176> for U160;
+    impl TryFrom<U184> for U160;
+    impl TryFrom<I184> for U160;
+    impl TryFrom<U192> for U160;
+    impl TryFrom<I192> for U160;
+    impl TryFrom<U200> for U160;
+    impl TryFrom<I200> for U160;
+    impl TryFrom<U208> for U160;
+    impl TryFrom<I208> for U160;
+    impl TryFrom<U216> for U160;
+    impl TryFrom<I216> for U160;
+    impl TryFrom<U224> for U160;
+    impl TryFrom<I224> for U160;
+    impl TryFrom<U232> for U160;
+    impl TryFrom<I232> for U160;
+    impl TryFrom<U240> for U160;
+    impl TryFrom<I240> for U160;
+    impl TryFrom<U248> for U160;
+    impl TryFrom<I248> for U160;
+    impl TryFrom<U256> for U160;
+    impl TryFrom<I256> for U160;
+    impl From<u8> for I160;
+    impl From<u16> for I160;
+    impl From<u32> for I160;
+    impl From<u64> for I160;
+    impl From<u128> for I160;
+    impl From<usize> for I160;
+    impl From<i8> for I160;
+    impl From<i16> for I160;
+    impl From<i32> for I160;
+    impl From<i64> for I160;
+    impl From<i128> for I160;
+    impl From<isize> for I160;
+    impl From<U16> for I160;
+    impl From<I16> for I160;
+    impl From<U24> for I160;
+    impl From<I24> for I160;
+    impl From<U32> for I160;
+    impl From<I32> for I160;
+    impl From<U40> for I160;
+    impl From<I40> for I160;
+    impl From<U48> for I160;
+    impl From<I48> for I160;
+    impl From<U56> for I160;
+    impl From<I56> for I160;
+    impl From<U64> for I160;
+    impl From<I64> for I160;
+    impl From<U72> for I160;
+    impl From<I72> for I160;
+    impl From<U80> for I160;
+    impl From<I80> for I160;
+    impl From<U88> for I160;
+    impl From<I88> for I160;
+    impl From<U96> for I160;
+    impl From<I96> for I160;
+    impl From<U104> for I160;
+    impl From<I104> for I160;
+    impl From<U112> for I160;
+    impl From<I112> for I160;
+    impl From<U120> for I160;
+    impl From<I120> for I160;
+    impl From<U128> for I160;
+    impl From<I128> for I160;
+    impl From<U136> for I160;
+    impl From<I136> for I160;
+    impl From<U144> for I160;
+    impl From<I144> for I160;
+    impl From<U152> for I160;
+    impl From<I152> for I160;
+    impl TryFrom<U160> for I160;
+    impl TryFrom<U168> for I160;
+    impl TryFrom<I168> for I160;
+    impl TryFrom<U176> for I160;
+    impl TryFrom<I176> for I160;
+    impl TryFrom<U184> for I160;
+    impl TryFrom<I184> for I160;
+    impl TryFrom<U192> for I160;
+    impl TryFrom<I192> for I160;
+    impl TryFrom<U200> for I160;
+    impl TryFrom<I200> for I160;
+    impl TryFrom<U208> for I160;
+    impl TryFrom<I208> for I160;
+    impl TryFrom<U216> for I160;
+    impl TryFrom<I216> for I160;
+    impl TryFrom<U224> for I160;
+    impl TryFrom<I224> for I160;
+    impl TryFrom<U232> for I160;
+    impl TryFrom<I232> for I160;
+    impl TryFrom<U240> for I160;
+    impl TryFrom<I240> for I160;
+    impl TryFrom<U248> for I160;
+    impl TryFrom<I248> for I160;
+    impl TryFrom<U256> for I160;
+    impl TryFrom<I256> for I160;
+    impl From<u8> for U168;
+    impl From<u16> for U168;
+    impl From<u32> for U168;
+    impl From<u64> for U168;
+    impl From<u128> for U168;
+    impl From<usize> for U168;
+    impl TryFrom<i8> for U168;
+    impl TryFrom<i16> for U168;
+    impl TryFrom<i32> for U168;
+    impl TryFrom<i64> for U168;
+    impl TryFrom<i128> for U168;
+    impl TryFrom<isize> for U168;
+    impl From<U16> for U168;
+    impl TryFrom<I16> for U168;
+    impl From<U24> for U168;
+    impl TryFrom<I24> for U168;
+    impl From<U32> for U168;
+    impl TryFrom<I32> for U168;
+    impl From<U40> for U168;
+    impl TryFrom<I40> for U168;
+    impl From<U48> for U168;
+    impl TryFrom<I48> for U168;
+    impl From<U56> for U168;
+    impl TryFrom<I56> for U168;
+    impl From<U64> for U168;
+    impl TryFrom<I64> for U168;
+    impl From<U72> for U168;
+    impl TryFrom<I72> for U168;
+    impl From<U80> for U168;
+    impl TryFrom<I80> for U168;
+    impl From<U88> for U168;
+    impl TryFrom<I88> for U168;
+    impl From<U96> for U168;
+    impl TryFrom<I96> for U168;
+    impl From<U104> for U168;
+    impl TryFrom<I104> for U168;
+    impl From<U112> for U168;
+    impl TryFrom<I112> for U168;
+    impl From<U120> for U168;
+    impl TryFrom<I120> for U168;
+    impl From<U128> for U168;
+    impl TryFrom<I128> for U168;
+    impl From<U136> for U168;
+    impl TryFrom<I136> for U168;
+    impl From<U144> for U168;
+    impl TryFrom<I144> for U168;
+    impl From<U152> for U168;
+    impl TryFrom<I152> for U168;
+    impl From<U160> for U168;
+    impl TryFrom<I160> for U168;
+    impl TryFrom<I168> for U168;
+    impl TryFrom<U176> for U168;
+    impl TryFrom<I176> for U168;
+    impl TryFrom<U184> for U168;
+    impl TryFrom<I184> for U168;
+    impl TryFrom<U192> for U168;
+    impl TryFrom<I192> for U168;
+    impl TryFrom<U200> for U168;
+    impl TryFrom<I200> for U168;
+    impl TryFrom<U208> for U168;
+    impl TryFrom<I208> for U168;
+    impl TryFrom<U216> for U168;
+    impl TryFrom<I216> for U168;
+    impl TryFrom<U224> for U168;
+    impl TryFrom<I224> for U168;
+    impl TryFrom<U232> for U168;
+    impl TryFrom<I232> for U168;
+    impl TryFrom<U240> for U168;
+    impl TryFrom<I240> for U168;
+    impl TryFrom<U248> for U168;
+    impl TryFrom<I248> for U168;
+    impl TryFrom<U256> for U168;
+    impl TryFrom<I256> for U168;
+    impl From<u8> for I168;
+    impl From<u16> for I168;
+    impl From<u32> for I168;
+    impl From<u64> for I168;
+    impl From<u128> for I168;
+    impl From<usize> for I168;
+    impl From<i8> for I168;
+    impl From<i16> for I168;
+    impl From<i32> for I168;
+    impl From<i64> for I168;
+    impl From<i128> for I168;
+    impl From<isize> for I168;
+    impl From<U16> for I168;
+    impl From<I16> for I168;
+    impl From<U24> for I168;
+    impl From<I24> for I168;
+    impl From<U32> for I168;
+    impl From<I32> for I168;
+    impl From<U40> for I168;
+    impl From<I40> for I168;
+    impl From<U48> for I168;
+    impl From<I48> for I168;
+    impl From<U56> for I168;
+    impl From<I56> for I168;
+    impl From<U64> for I168;
+    impl From<I64> for I168;
+    impl From<U72> for I168;
+    impl From<I72> for I168;
+    impl From<U80> for I168;
+    impl From<I80> for I168;
+    impl From<U88> for I168;
+    impl From<I88> for I168;
+    impl From<U96> for I168;
+    impl From<I96> for I168;
+    impl From<U104> for I168;
+    impl From<I104> for I168;
+    impl From<U112> for I168;
+    impl From<I112> for I168;
+    impl From<U120> for I168;
+    impl From<I120> for I168;
+    impl From<U128> for I168;
+    impl From<I128> for I168;
+    impl From<U136> for I168;
+    impl From<I136> for I168;
+    impl From<U144> for I168;
+    impl From<I144> for I168;
+    impl From<U152> for I168;
+    impl From<I152> for I168;
+    impl From<U160> for I168;
+    impl From<I160> for I168;
+    impl TryFrom<U168> for I168;
+    impl TryFrom<U176> for I168;
+    impl TryFrom<I176> for I168;
+    impl TryFrom<U184> for I168;
+    impl TryFrom<I184> for I168;
+    impl TryFrom<U192> for I168;
+    impl TryFrom<I192> for I168;
+    impl TryFrom<U200> for I168;
+    impl TryFrom<I200> for I168;
+    impl TryFrom<U208> for I168;
+    impl TryFrom<I208> for I168;
+    impl TryFrom<U216> for I168;
+    impl TryFrom<I216> for I168;
+    impl TryFrom<U224> for I168;
+    impl TryFrom<I224> for I168;
+    impl TryFrom<U232> for I168;
+    impl TryFrom<I232> for I168;
+    impl TryFrom<U240> for I168;
+    impl TryFrom<I240> for I168;
+    impl TryFrom<U248> for I168;
+    impl TryFrom<I248> for I168;
+    impl TryFrom<U256> for I168;
+    impl TryFrom<I256> for I168;
+    impl From<u8> for U176;
+    impl From<u16> for U176;
+    impl From<u32> for U176;
+    impl From<u64> for U176;
+    impl From<u128> for U176;
+    impl From<usize> for U176;
+    impl TryFrom<i8> for U176;
+    impl TryFrom<i16> for U176;
+    impl TryFrom<i32> for U176;
+    impl TryFrom<i64> for U176;
+    impl TryFrom<i128> for U176;
+    impl TryFrom<isize> for U176;
+    impl From<U16> for U176;
+    impl TryFrom<I16> for U176;
+    impl From<U24> for U176;
+    impl TryFrom<I24> for U176;
+    impl From<U32> for U176;
+    impl TryFrom<I32> for U176;
+    impl From<U40> for U176;
+    impl TryFrom<I40> for U176;
+    impl From<U48> for U176;
+    impl TryFrom<I48> for U176;
+    impl From<U56> for U176;
+    impl TryFrom<I56> for U176;
+    impl From<U64> for U176;
+    impl TryFrom<I64> for U176;
+    impl From<U72> for U176;
+    impl TryFrom<I72> for U176;
+    impl From<U80> for U176;
+    impl TryFrom<I80> for U176;
+    impl From<U88> for U176;
+    impl TryFrom<I88> for U176;
+    impl From<U96> for U176;
+    impl TryFrom<I96> for U176;
+    impl From<U104> for U176;
+    impl TryFrom<I104> for U176;
+    impl From<U112> for U176;
+    impl TryFrom<I112> for U176;
+    impl From<U120> for U176;
+    impl TryFrom<I120> for U176;
+    impl From<U128> for U176;
+    impl TryFrom<I128> for U176;
+    impl From<U136> for U176;
+    impl TryFrom<I136> for U176;
+    impl From<U144> for U176;
+    impl TryFrom<I144> for U176;
+    impl From<U152> for U176;
+    impl TryFrom<I152> for U176;
+    impl From<U160> for U176;
+    impl TryFrom<I160> for U176;
+    impl From<U168> for U176;
+    impl TryFrom<I168> for U176;
+    impl TryFrom<I176> for U176;
+    impl TryFrom<U184> for U176;
+    impl TryFrom<I184> for U176;
+    impl TryFrom<U192> for U176;
+    impl TryFrom<I192> for U176;
+    impl TryFrom<U200> for U176;
+    impl TryFrom<I200> for U176;
+    impl TryFrom<U208> for U176;
+    impl TryFrom<I208> for U176;
+    impl TryFrom<U216> for U176;
+    impl TryFrom<I216> for U176;
+    impl TryFrom<U224> for U176;
+    impl TryFrom<I224> for U176;
+    impl TryFrom<U232> for U176;
+    impl TryFrom<I232> for U176;
+    impl TryFrom<U240> for U176;
+    impl TryFrom<I240> for U176;
+    impl TryFrom<U248> for U176;
+    impl TryFrom<I248> for U176;
+    impl TryFrom<U256> for U176;
+    impl TryFrom<I256> for U176;
+    impl From<u8> for I176;
+    impl From<u16> for I176;
+    impl From<u32> for I176;
+    impl From<u64> for I176;
+    impl From<u128> for I176;
+    impl From<usize> for I176;
+    impl From<i8> for I176;
+    impl From<i16> for I176;
+    impl From<i32> for I176;
+    impl From<i64> for I176;
+    impl From<i128> for I176;
+    impl From<isize> for I176;
+    impl From<U16> for I176;
+    impl From<I16> for I176;
+    impl From<U24> for I176;
+    impl From<I24> for I176;
+    impl From<U32> for I176;
+    impl From<I32> for I176;
+    impl From<U40> for I176;
+    impl From<I40> for I176;
+    impl From<U48> for I176;
+    impl From<I48> for I176;
+    impl From<U56> for I176;
+    impl From<I56> for I176;
+    impl From<U64> for I176;
+    impl From<I64> for I176;
+    impl From<U72> for I176;
+    impl From<I72> for I176;
+    impl From<U80> for I176;
+    impl From<I80> for I176;
+    impl From<U88> for I176;
+    impl From<I88> for I176;
+    impl From<U96> for I176;
+    impl From<I96> for I176;
+    impl From<U104> for I176;
+    impl From<I104> for I176;
+    impl From<U112> for I176;
+    impl From<I112> for I176;
+    impl From<U120> for I176;
+    impl From<I120> for I176;
+    impl From<U128> for I176;
+    impl From<I128> for I176;
+    impl From<U136> for I176;
+    impl From<I136> for I176;
+    impl From<U144> for I176;
+    impl From<I144> for I176;
+    impl From<U152> for I176;
+    impl From<I152> for I176;
+    impl From<U160> for I176;
+    impl From<I160> for I176;
+    impl From<U168> for I176;
+    impl From<I168> for I176;
+    impl TryFrom<U176> for I176;
+    impl TryFrom<U184> for I176;
+    impl TryFrom<I184> for I176;
+    impl TryFrom<U192> for I176;
+    impl TryFrom<I192> for I176;
+    impl TryFrom<U200> for I176;
+    impl TryFrom<I200> for I176;
+    impl TryFrom<U208> for I176;
+    impl TryFrom<I208> for I176;
+    impl TryFrom<U216> for I176;
+    impl TryFrom<I216> for I176;
+    impl TryFrom<U224> for I176;
+    impl TryFrom<I224> for I176;
+    impl TryFrom<U232> for I176;
+    impl TryFrom<I232> for I176;
+    impl TryFrom<U240> for I176;
+    impl TryFrom<I240> for I176;
+    impl TryFrom<U248> for I176;
+    impl TryFrom<I248> for I176;
+    impl TryFrom<U256> for I176;
+    impl TryFrom<I256> for I176;
+    impl From<u8> for U184;
+    impl From<u16> for U184;
+    impl From<u32> for U184;
+    impl From<u64> for U184;
+    impl From<u128> for U184;
+    impl From<usize> for U184;
+    impl TryFrom<i8> for U184;
+    impl TryFrom<i16> for U184;
+    impl TryFrom<i32> for U184;
+    impl TryFrom<i64> for U184;
+    impl TryFrom<i128> for U184;
+    impl TryFrom<isize> for U184;
+    impl From<U16> for U184;
+    impl TryFrom<I16> for U184;
+    impl From<U24> for U184;
+    impl TryFrom<I24> for U184;
+    impl From<U32> for U184;
+    impl TryFrom<I32> for U184;
+    impl From<U40> for U184;
+    impl TryFrom<I40> for U184;
+    impl From<U48> for U184;
+    impl TryFrom<I48> for U184;
+    impl From<U56> for U184;
+    impl TryFrom<I56> for U184;
+    impl From<U64> for U184;
+    impl TryFrom<I64> for U184;
+    impl From<U72> for U184;
+    impl TryFrom<I72> for U184;
+    impl From<U80> for U184;
+    impl TryFrom<I80> for U184;
+    impl From<U88> for U184;
+    impl TryFrom<I88> for U184;
+    impl From<U96> for U184;
+    impl TryFrom<I96> for U184;
+    impl From<U104> for U184;
+    impl TryFrom<I104> for U184;
+    impl From<U112> for U184;
+    impl TryFrom<I112> for U184;
+    impl From<U120> for U184;
+    impl TryFrom<I120> for U184;
+    impl From<U128> for U184;
+    impl TryFrom<I128> for U184;
+    impl From<U136> for U184;
+    impl TryFrom<I136> for U184;
+    impl From<U144> for U184;
+    impl TryFrom<I144> for U184;
+    impl From<U152> for U184;
+    impl TryFrom<I152> for U184;
+    impl From<U160> for U184;
+    impl TryFrom<I160> for U184;
+    impl From<U168> for U184;
+    impl TryFrom<I168> for U184;
+    impl From<U176> for U184;
+    impl TryFrom<I176> for U184;
+    impl TryFrom<I184> for U184;
+    impl TryFrom<U192> for U184;
+    impl TryFrom<I192> for U184;
+    impl TryFrom<U200> for U184;
+    impl TryFrom<I200> for U184;
+    impl TryFrom<U208> for U184;
+    impl TryFrom<I208> for U184;
+    impl TryFrom<U216> for U184;
+    impl TryFrom<I216> for U184;
+    impl TryFrom<U224> for U184;
+    impl TryFrom<I224> for U184;
+    impl TryFrom<U232> for U184;
+    impl TryFrom<I232> for U184;
+    impl TryFrom<U240> for U184;
+    impl TryFrom<I240> for U184;
+    impl TryFrom<U248> for U184;
+    impl TryFrom<I248> for U184;
+    impl TryFrom<U256> for U184;
+    impl TryFrom<I256> for U184;
+    impl From<u8> for I184;
+    impl From<u16> for I184;
+    impl From<u32> for I184;
+    impl From<u64> for I184;
+    impl From<u128> for I184;
+    impl From<usize> for I184;
+    impl From<i8> for I184;
+    impl From<i16> for I184;
+    impl From<i32> for I184;
+    impl From<i64> for I184;
+    impl From<i128> for I184;
+    impl From<isize> for I184;
+    impl From<U16> for I184;
+    impl From<I16> for I184;
+    impl From<U24> for I184;
+    impl From<I24> for I184;
+    impl From<U32> for I184;
+    impl From<I32> for I184;
+    impl From<U40> for I184;
+    impl From<I40> for I184;
+    impl From<U48> for I184;
+    impl From<I48> for I184;
+    impl From<U56> for I184;
+    impl From<I56> for I184;
+    impl From<U64> for I184;
+    impl From<I64> for I184;
+    impl From<U72> for I184;
+    impl From<I72> for I184;
+    impl From<U80> for I184;
+    impl From<I80> for I184;
+    impl From<U88> for I184;
+    impl From<I88> for I184;
+    impl From<U96> for I184;
+    impl From<I96> for I184;
+    impl From<U104> for I184;
+    impl From<I104> for I184;
+    impl From<U112> for I184;
+    impl From<I112> for I184;
+    impl From<U120> for I184;
+    impl From<I120> for I184;
+    impl From<U128> for I184;
+    impl From<I128> for I184;
+    impl From<U136> for I184;
+    impl From<I136> for I184;
+    impl From<U144> for I184;
+    impl From<I144> for I184;
+    impl From<U152> for I184;
+    impl From<I152> for I184;
+    impl From<U160> for I184;
+    impl From<I160> for I184;
+    impl From<U168> for I184;
+    impl From<I168> for I184;
+    impl From<U176> for I184;
+    impl From<I176> for I184;
+    impl TryFrom<U184> for I184;
+    impl TryFrom<U192> for I184;
+    impl TryFrom<I192> for I184;
+    impl TryFrom<U200> for I184;
+    impl TryFrom<I200> for I184;
+    impl TryFrom<U208> for I184;
+    impl TryFrom<I208> for I184;
+    impl TryFrom<U216> for I184;
+    impl TryFrom<I216> for I184;
+    impl TryFrom<U224> for I184;
+    impl TryFrom<I224> for I184;
+    impl TryFrom<U232> for I184;
+    impl TryFrom<I232> for I184;
+    impl TryFrom<U240> for I184;
+    impl TryFrom<I240> for I184;
+    impl TryFrom<U248> for I184;
+    impl TryFrom<I248> for I184;
+    impl TryFrom<U256> for I184;
+    impl TryFrom<I256> for I184;
+    impl From<u8> for U192;
+    impl From<u16> for U192;
+    impl From<u32> for U192;
+    impl From<u64> for U192;
+    impl From<u128> for U192;
+    impl From<usize> for U192;
+    impl TryFrom<i8> for U192;
+    impl TryFrom<i16> for U192;
+    impl TryFrom<i32> for U192;
+    impl TryFrom<i64> for U192;
+    impl TryFrom<i128> for U192;
+    impl TryFrom<isize> for U192;
+    impl From<U16> for U192;
+    impl TryFrom<I16> for U192;
+    impl From<U24> for U192;
+    impl TryFrom<I24> for U192;
+    impl From<U32> for U192;
+    impl TryFrom<I32> for U192;
+    impl From<U40> for U192;
+    impl TryFrom<I40> for U192;
+    impl From<U48> for U192;
+    impl TryFrom<I48> for U192;
+    impl From<U56> for U192;
+    impl TryFrom<I56> for U192;
+    impl From<U64> for U192;
+    impl TryFrom<I64> for U192;
+    impl From<U72> for U192;
+    impl TryFrom<I72> for U192;
+    impl From<U80> for U192;
+    impl TryFrom<I80> for U192;
+    impl From<U88> for U192;
+    impl TryFrom<I88> for U192;
+    impl From<U96> for U192;
+    impl TryFrom<I96> for U192;
+    impl From<U104> for U192;
+    impl TryFrom<I104> for U192;
+    impl From<U112> for U192;
+    impl TryFrom<I112> for U192;
+    impl From<U120> for U192;
+    impl TryFrom<I120> for U192;
+    impl From<U128> for U192;
+    impl TryFrom<I128> for U192;
+    impl From<U136> for U192;
+    impl TryFrom<I136> for U192;
+    impl From<U144> for U192;
+    impl TryFrom<I144> for U192;
+    impl From<U152> for U192;
+    impl TryFrom<I152> for U192;
+    impl From<U160> for U192;
+    impl TryFrom<I160> for U192;
+    impl From<U168> for U192;
+    impl TryFrom<I168> for U192;
+    impl From<U176> for U192;
+    impl TryFrom<I176> for U192;
+    impl From<U184> for U192;
+    impl TryFrom<I184> for U192;
+    impl TryFrom<I192> for U192;
+    impl TryFrom<U200> for U192;
+    impl TryFrom<I200> for U192;
+    impl TryFrom<U208> for U192;
+    impl TryFrom<I208> for U192;
+    impl TryFrom<U216> for U192;
+    impl TryFrom<I216> for U192;
+    impl TryFrom<U224> for U192;
+    impl TryFrom<I224> for U192;
+    impl TryFrom<U232> for U192;
+    impl TryFrom<I232> for U192;
+    impl TryFrom<U240> for U192;
+    impl TryFrom<I240> for U192;
+    impl TryFrom<U248> for U192;
+    impl TryFrom<I248> for U192;
+    impl TryFrom<U256> for U192;
+    impl TryFrom<I256> for U192;
+    impl From<u8> for I192;
+    impl From<u16> for I192;
+    impl From<u32> for I192;
+    impl From<u64> for I192;
+    impl From<u128> for I192;
+    impl From<usize> for I192;
+    impl From<i8> for I192;
+    impl From<i16> for I192;
+    impl From<i32> for I192;
+    impl From<i64> for I192;
+    impl From<i128> for I192;
+    impl From<isize> for I192;
+    impl From<U16> for I192;
+    impl From<I16> for I192;
+    impl From<U24> for I192;
+    impl From<I24> for I192;
+    impl From<U32> for I192;
+    impl From<I32> for I192;
+    impl From<U40> for I192;
+    impl From<I40> for I192;
+    impl From<U48> for I192;
+    impl From<I48> for I192;
+    impl From<U56> for I192;
+    impl From<I56> for I192;
+    impl From<U64> for I192;
+    impl From<I64> for I192;
+    impl From<U72> for I192;
+    impl From<I72> for I192;
+    impl From<U80> for I192;
+    impl From<I80> for I192;
+    impl From<U88> for I192;
+    impl From<I88> for I192;
+    impl From<U96> for I192;
+    impl From<I96> for I192;
+    impl From<U104> for I192;
+    impl From<I104> for I192;
+    impl From<U112> for I192;
+    impl From<I112> for I192;
+    impl From<U120> for I192;
+    impl From<I120> for I192;
+    impl From<U128> for I192;
+    impl From<I128> for I192;
+    impl From<U136> for I192;
+    impl From<I136> for I192;
+    impl From<U144> for I192;
+    impl From<I144> for I192;
+    impl From<U152> for I192;
+    impl From<I152> for I192;
+    impl From<U160> for I192;
+    impl From<I160> for I192;
+    impl From<U168> for I192;
+    impl From<I168> for I192;
+    impl From<U176> for I192;
+    impl From<I176> for I192;
+    impl From<U184> for I192;
+    impl From<I184> for I192;
+    impl TryFrom<U192> for I192;
+    impl TryFrom<U200> for I192;
+    impl TryFrom<I200> for I192;
+    impl TryFrom<U208> for I192;
+    impl TryFrom<I208> for I192;
+    impl TryFrom<U216> for I192;
+    impl TryFrom<I216> for I192;
+    impl TryFrom<U224> for I192;
+    impl TryFrom<I224> for I192;
+    impl TryFrom<U232> for I192;
+    impl TryFrom<I232> for I192;
+    impl TryFrom<U240> for I192;
+    impl TryFrom<I240> for I192;
+    impl TryFrom<U248> for I192;
+    impl TryFrom<I248> for I192;
+    impl TryFrom<U256> for I192;
+    impl TryFrom<I256> for I192;
+    impl From<u8> for U200;
+    impl From<u16> for U200;
+    impl From<u32> for U200;
+    impl From<u64> for U200;
+    impl From<u128> for U200;
+    impl From<usize> for U200;
+    impl TryFrom<i8> for U200;
+    impl TryFrom<i16> for U200;
+    impl TryFrom<i32> for U200;
+    impl TryFrom<i64> for U200;
+    impl TryFrom<i128> for U200;
+    impl TryFrom<isize> for U200;
+    impl From<U16> for U200;
+    impl TryFrom<I16> for U200;
+    impl From<U24> for U200;
+    impl TryFrom<I24> for U200;
+    impl From<U32> for U200;
+    impl TryFrom<I32> for U200;
+    impl From<U40> for U200;
+    impl TryFrom<I40> for U200;
+    impl From<U48> for U200;
+    impl TryFrom<I48> for U200;
+    impl From<U56> for U200;
+    impl TryFrom<I56> for U200;
+    impl From<U64> for U200;
+    impl TryFrom<I64> for U200;
+    impl From<U72> for U200;
+    impl TryFrom<I72> for U200;
+    impl From<U80> for U200;
+    impl TryFrom<I80> for U200;
+    impl From<U88> for U200;
+    impl TryFrom<I88> for U200;
+    impl From<U96> for U200;
+    impl TryFrom<I96> for U200;
+    impl From<U104> for U200;
+    impl TryFrom<I104> for U200;
+    impl From<U112> for U200;
+    impl TryFrom<I112> for U200;
+    impl From<U120> for U200;
+    impl TryFrom<I120> for U200;
+    impl From<U128> for U200;
+    impl TryFrom<I128> for U200;
+    impl From<U136> for U200;
+    impl TryFrom<I136> for U200;
+    impl From<U144> for U200;
+    impl TryFrom<I144> for U200;
+    impl From<U152> for U200;
+    impl TryFrom<I152> for U200;
+    impl From<U160> for U200;
+    impl TryFrom<I160> for U200;
+    impl From<U168> for U200;
+    impl TryFrom<I168> for U200;
+    impl From<U176> for U200;
+    impl TryFrom<I176> for U200;
+    impl From<U184> for U200;
+    impl TryFrom<I184> for U200;
+    impl From<U192> for U200;
+    impl TryFrom<I192> for U200;
+    impl TryFrom<I200> for U200;
+    impl TryFrom<U208> for U200;
+    impl TryFrom<I208> for U200;
+    impl TryFrom<U216> for U200;
+    impl TryFrom<I216> for U200;
+    impl TryFrom<U224> for U200;
+    impl TryFrom<I224> for U200;
+    impl TryFrom<U232> for U200;
+    impl TryFrom<I232> for U200;
+    impl TryFrom<U240> for U200;
+    impl TryFrom<I240> for U200;
+    impl TryFrom<U248> for U200;
+    impl TryFrom<I248> for U200;
+    impl TryFrom<U256> for U200;
+    impl TryFrom<I256> for U200;
+    impl From<u8> for I200;
+    impl From<u16> for I200;
+    impl From<u32> for I200;
+    impl From<u64> for I200;
+    impl From<u128> for I200;
+    impl From<usize> for I200;
+    impl From<i8> for I200;
+    impl From<i16> for I200;
+    impl From<i32> for I200;
+    impl From<i64> for I200;
+    impl From<i128> for I200;
+    impl From<isize> for I200;
+    impl From<U16> for I200;
+    impl From<I16> for I200;
+    impl From<U24> for I200;
+    impl From<I24> for I200;
+    impl From<U32> for I200;
+    impl From<I32> for I200;
+    impl From<U40> for I200;
+    impl From<I40> for I200;
+    impl From<U48> for I200;
+    impl From<I48> for I200;
+    impl From<U56> for I200;
+    impl From<I56> for I200;
+    impl From<U64> for I200;
+    impl From<I64> for I200;
+    impl From<U72> for I200;
+    impl From<I72> for I200;
+    impl From<U80> for I200;
+    impl From<I80> for I200;
+    impl From<U88> for I200;
+    impl From<I88> for I200;
+    impl From<U96> for I200;
+    impl From<I96> for I200;
+    impl From<U104> for I200;
+    impl From<I104> for I200;
+    impl From<U112> for I200;
+    impl From<I112> for I200;
+    impl From<U120> for I200;
+    impl From<I120> for I200;
+    impl From<U128> for I200;
+    impl From<I128> for I200;
+    impl From<U136> for I200;
+    impl From<I136> for I200;
+    impl From<U144> for I200;
+    impl From<I144> for I200;
+    impl From<U152> for I200;
+    impl From<I152> for I200;
+    impl From<U160> for I200;
+    impl From<I160> for I200;
+    impl From<U168> for I200;
+    impl From<I168> for I200;
+    impl From<U176> for I200;
+    impl From<I176> for I200;
+    impl From<U184> for I200;
+    impl From<I184> for I200;
+    impl From<U192> for I200;
+    impl From<I192> for I200;
+    impl TryFrom<U200> for I200;
+    impl TryFrom<U208> for I200;
+    impl TryFrom<I208> for I200;
+    impl TryFrom<U216> for I200;
+    impl TryFrom<I216> for I200;
+    impl TryFrom<U224> for I200;
+    impl TryFrom<I224> for I200;
+    impl TryFrom<U232> for I200;
+    impl TryFrom<I232> for I200;
+    impl TryFrom<U240> for I200;
+    impl TryFrom<I240> for I200;
+    impl TryFrom<U248> for I200;
+    impl TryFrom<I248> for I200;
+    impl TryFrom<U256> for I200;
+    impl TryFrom<I256> for I200;
+    impl From<u8> for U208;
+    impl From<u16> for U208;
+    impl From<u32> for U208;
+    impl From<u64> for U208;
+    impl From<u128> for U208;
+    impl From<usize> for U208;
+    impl TryFrom<i8> for U208;
+    impl TryFrom<i16> for U208;
+    impl TryFrom<i32> for U208;
+    impl TryFrom<i64> for U208;
+    impl TryFrom<i128> for U208;
+    impl TryFrom<isize> for U208;
+    impl From<U16> for U208;
+    impl TryFrom<I16> for U208;
+    impl From<U24> for U208;
+    impl TryFrom<I24> for U208;
+    impl From<U32> for U208;
+    impl TryFrom<I32> for U208;
+    impl From<U40> for U208;
+    impl TryFrom<I40> for U208;
+    impl From<U48> for U208;
+    impl TryFrom<I48> for U208;
+    impl From<U56> for U208;
+    impl TryFrom<I56> for U208;
+    impl From<U64> for U208;
+    impl TryFrom<I64> for U208;
+    impl From<U72> for U208;
+    impl TryFrom<I72> for U208;
+    impl From<U80> for U208;
+    impl TryFrom<I80> for U208;
+    impl From<U88> for U208;
+    impl TryFrom<I88> for U208;
+    impl From<U96> for U208;
+    impl TryFrom<I96> for U208;
+    impl From<U104> for U208;
+    impl TryFrom<I104> for U208;
+    impl From<U112> for U208;
+    impl TryFrom<I112> for U208;
+    impl From<U120> for U208;
+    impl TryFrom<I120> for U208;
+    impl From<U128> for U208;
+    impl TryFrom<I128> for U208;
+    impl From<U136> for U208;
+    impl TryFrom<I136> for U208;
+    impl From<U144> for U208;
+    impl TryFrom<I144> for U208;
+    impl From<U152> for U208;
+    impl TryFrom<I152> for U208;
+    impl From<U160> for U208;
+    impl TryFrom<I160> for U208;
+    impl From<U168> for U208;
+    impl TryFrom<I168> for U208;
+    impl From<U176> for U208;
+    impl TryFrom<I176> for U208;
+    impl From<U184> for U208;
+    impl TryFrom<I184> for U208;
+    impl From<U192> for U208;
+    impl TryFrom<I192> for U208;
+    impl From<U200> for U208;
+    impl TryFrom<I200> for U208;
+    impl TryFrom<I208> for U208;
+    impl TryFrom<U216> for U208;
+    impl TryFrom<I216> for U208;
+    impl TryFrom<U224> for U208;
+    impl TryFrom<I224> for U208;
+    impl TryFrom<U232> for U208;
+    impl TryFrom<I232> for U208;
+    impl TryFrom<U240> for U208;
+    impl TryFrom<I240> for U208;
+    impl TryFrom<U248> for U208;
+    impl TryFrom<I248> for U208;
+    impl TryFrom<U256> for U208;
+    impl TryFrom<I256> for U208;
+    impl From<u8> for I208;
+    impl From<u16> for I208;
+    impl From<u32> for I208;
+    impl From<u64> for I208;
+    impl From<u128> for I208;
+    impl From<usize> for I208;
+    impl From<i8> for I208;
+    impl From<i16> for I208;
+    impl From<i32> for I208;
+    impl From<i64> for I208;
+    impl From<i128> for I208;
+    impl From<isize> for I208;
+    impl From<U16> for I208;
+    impl From<I16> for I208;
+    impl From<U24> for I208;
+    impl From<I24> for I208;
+    impl From<U32> for I208;
+    impl From<I32> for I208;
+    impl From<U40> for I208;
+    impl From<I40> for I208;
+    impl From<U48> for I208;
+    impl From<I48> for I208;
+    impl From<U56> for I208;
+    impl From<I56> for I208;
+    impl From<U64> for I208;
+    impl From<I64> for I208;
+    impl From<U72> for I208;
+    impl From<I72> for I208;
+    impl From<U80> for I208;
+    impl From<I80> for I208;
+    impl From<U88> for I208;
+    impl From<I88> for I208;
+    impl From<U96> for I208;
+    impl From<I96> for I208;
+    impl From<U104> for I208;
+    impl From<I104> for I208;
+    impl From<U112> for I208;
+    impl From<I112> for I208;
+    impl From<U120> for I208;
+    impl From<I120> for I208;
+    impl From<U128> for I208;
+    impl From<I128> for I208;
+    impl From<U136> for I208;
+    impl From<I136> for I208;
+    impl From<U144> for I208;
+    impl From<I144> for I208;
+    impl From<U152> for I208;
+    impl From<I152> for I208;
+    impl From<U160> for I208;
+    impl From<I160> for I208;
+    impl From<U168> for I208;
+    impl From<I168> for I208;
+    impl From<U176> for I208;
+    impl From<I176> for I208;
+    impl From<U184> for I208;
+    impl From<I184> for I208;
+    impl From<U192> for I208;
+    impl From<I192> for I208;
+    impl From<U200> for I208;
+    impl From<I200> for I208;
+    impl TryFrom<U208> for I208;
+    impl TryFrom<U216> for I208;
+    impl TryFrom<I216> for I208;
+    impl TryFrom<U224> for I208;
+    impl TryFrom<I224> for I208;
+    impl TryFrom<U232> for I208;
+    impl TryFrom<I232> for I208;
+    impl TryFrom<U240> for I208;
+    impl TryFrom<I240> for I208;
+    impl TryFrom<U248> for I208;
+    impl TryFrom<I248> for I208;
+    impl TryFrom<U256> for I208;
+    impl TryFrom<I256> for I208;
+    impl From<u8> for U216;
+    impl From<u16> for U216;
+    impl From<u32> for U216;
+    impl From<u64> for U216;
+    impl From<u128> for U216;
+    impl From<usize> for U216;
+    impl TryFrom<i8> for U216;
+    impl TryFrom<i16> for U216;
+    impl TryFrom<i32> for U216;
+    impl TryFrom<i64> for U216;
+    impl TryFrom<i128> for U216;
+    impl TryFrom<isize> for U216;
+    impl From<U16> for U216;
+    impl TryFrom<I16> for U216;
+    impl From<U24> for U216;
+    impl TryFrom<I24> for U216;
+    impl From<U32> for U216;
+    impl TryFrom<I32> for U216;
+    impl From<U40> for U216;
+    impl TryFrom<I40> for U216;
+    impl From<U48> for U216;
+    impl TryFrom<I48> for U216;
+    impl From<U56> for U216;
+    impl TryFrom<I56> for U216;
+    impl From<U64> for U216;
+    impl TryFrom<I64> for U216;
+    impl From<U72> for U216;
+    impl TryFrom<I72> for U216;
+    impl From<U80> for U216;
+    impl TryFrom<I80> for U216;
+    impl From<U88> for U216;
+    impl TryFrom<I88> for U216;
+    impl From<U96> for U216;
+    impl TryFrom<I96> for U216;
+    impl From<U104> for U216;
+    impl TryFrom<I104> for U216;
+    impl From<U112> for U216;
+    impl TryFrom<I112> for U216;
+    impl From<U120> for U216;
+    impl TryFrom<I120> for U216;
+    impl From<U128> for U216;
+    impl TryFrom<I128> for U216;
+    impl From<U136> for U216;
+    impl TryFrom<I136> for U216;
+    impl From<U144> for U216;
+    impl TryFrom<I144> for U216;
+    impl From<U152> for U216;
+    impl TryFrom<I152> for U216;
+    impl From<U160> for U216;
+    impl TryFrom<I160> for U216;
+    impl From<U168> for U216;
+    impl TryFrom<I168> for U216;
+    impl From<U176> for U216;
+    impl TryFrom<I176> for U216;
+    impl From<U184> for U216;
+    impl TryFrom<I184> for U216;
+    impl From<U192> for U216;
+    impl TryFrom<I192> for U216;
+    impl From<U200> for U216;
+    impl TryFrom<I200> for U216;
+    impl From<U208> for U216;
+    impl TryFrom<I208> for U216;
+    impl TryFrom<I216> for U216;
+    impl TryFrom<U224> for U216;
+    impl TryFrom<I224> for U216;
+    impl TryFrom<U232> for U216;
+    impl TryFrom<I232> for U216;
+    impl TryFrom<U240> for U216;
+    impl TryFrom<I240> for U216;
+    impl TryFrom<U248> for U216;
+    impl TryFrom<I248> for U216;
+    impl TryFrom<U256> for U216;
+    impl TryFrom<I256> for U216;
+    impl From<u8> for I216;
+    impl From<u16> for I216;
+    impl From<u32> for I216;
+    impl From<u64> for I216;
+    impl From<u128> for I216;
+    impl From<usize> for I216;
+    impl From<i8> for I216;
+    impl From<i16> for I216;
+    impl From<i32> for I216;
+    impl From<i64> for I216;
+    impl From<i128> for I216;
+    impl From<isize> for I216;
+    impl From<U16> for I216;
+    impl From<I16> for I216;
+    impl From<U24> for I216;
+    impl From<I24> for I216;
+    impl From<U32> for I216;
+    impl From<I32> for I216;
+    impl From<U40> for I216;
+    impl From<I40> for I216;
+    impl From<U48> for I216;
+    impl From<I48> for I216;
+    impl From<U56> for I216;
+    impl From<I56> for I216;
+    impl From<U64> for I216;
+    impl From<I64> for I216;
+    impl From<U72> for I216;
+    impl From<I72> for I216;
+    impl From<U80> for I216;
+    impl From<I80> for I216;
+    impl From<U88> for I216;
+    impl From<I88> for I216;
+    impl From<U96> for I216;
+    impl From<I96> for I216;
+    impl From<U104> for I216;
+    impl From<I104> for I216;
+    impl From<U112> for I216;
+    impl From<I112> for I216;
+    impl From<U120> for I216;
+    impl From<I120> for I216;
+    impl From<U128> for I216;
+    impl From<I128> for I216;
+    impl From<U136> for I216;
+    impl From<I136> for I216;
+    impl From<U144> for I216;
+    impl From<I144> for I216;
+    impl From<U152> for I216;
+    impl From<I152> for I216;
+    impl From<U160> for I216;
+    impl From<I160> for I216;
+    impl From<U168> for I216;
+    impl From<I168> for I216;
+    impl From<U176> for I216;
+    impl From<I176> for I216;
+    impl From<U184> for I216;
+    impl From<I184> for I216;
+    impl From<U192> for I216;
+    impl From<I192> for I216;
+    impl From<U200> for I216;
+    impl From<I200> for I216;
+    impl From<U208> for I216;
+    impl From<I208> for I216;
+    impl TryFrom<U216> for I216;
+    impl TryFrom<U224> for I216;
+    impl TryFrom<I224> for I216;
+    impl TryFrom<U232> for I216;
+    impl TryFrom<I232> for I216;
+    impl TryFrom<U240> for I216;
+    impl TryFrom<I240> for I216;
+    impl TryFrom<U248> for I216;
+    impl TryFrom<I248> for I216;
+    impl TryFrom<U256> for I216;
+    impl TryFrom<I256> for I216;
+    impl From<u8> for U224;
+    impl From<u16> for U224;
+    impl From<u32> for U224;
+    impl From<u64> for U224;
+    impl From<u128> for U224;
+    impl From<usize> for U224;
+    impl TryFrom<i8> for U224;
+    impl TryFrom<i16> for U224;
+    impl TryFrom<i32> for U224;
+    impl TryFrom<i64> for U224;
+    impl TryFrom<i128> for U224;
+    impl TryFrom<isize> for U224;
+    impl From<U16> for U224;
+    impl TryFrom<I16> for U224;
+    impl From<U24> for U224;
+    impl TryFrom<I24> for U224;
+    impl From<U32> for U224;
+    impl TryFrom<I32> for U224;
+    impl From<U40> for U224;
+    impl TryFrom<I40> for U224;
+    impl From<U48> for U224;
+    impl TryFrom<I48> for U224;
+    impl From<U56> for U224;
+    impl TryFrom<I56> for U224;
+    impl From<U64> for U224;
+    impl TryFrom<I64> for U224;
+    impl From<U72> for U224;
+    impl TryFrom<I72> for U224;
+    impl From<U80> for U224;
+    impl TryFrom<I80> for U224;
+    impl From<U88> for U224;
+    impl TryFrom<I88> for U224;
+    impl From<U96> for U224;
+    impl TryFrom<I96> for U224;
+    impl From<U104> for U224;
+    impl TryFrom<I104> for U224;
+    impl From<U112> for U224;
+    impl TryFrom<I112> for U224;
+    impl From<U120> for U224;
+    impl TryFrom<I120> for U224;
+    impl From<U128> for U224;
+    impl TryFrom<I128> for U224;
+    impl From<U136> for U224;
+    impl TryFrom<I136> for U224;
+    impl From<U144> for U224;
+    impl TryFrom<I144> for U224;
+    impl From<U152> for U224;
+    impl TryFrom<I152> for U224;
+    impl From<U160> for U224;
+    impl TryFrom<I160> for U224;
+    impl From<U168> for U224;
+    impl TryFrom<I168> for U224;
+    impl From<U176> for U224;
+    impl TryFrom<I176> for U224;
+    impl From<U184> for U224;
+    impl TryFrom<I184> for U224;
+    impl From<U192> for U224;
+    impl TryFrom<I192> for U224;
+    impl From<U200> for U224;
+    impl TryFrom<I200> for U224;
+    impl From<U208> for U224;
+    impl TryFrom<I208> for U224;
+    impl From<U216> for U224;
+    impl TryFrom<I216> for U224;
+    impl TryFrom<I224> for U224;
+    impl TryFrom<U232> for U224;
+    impl TryFrom<I232> for U224;
+    impl TryFrom<U240> for U224;
+    impl TryFrom<I240> for U224;
+    impl TryFrom<U248> for U224;
+    impl TryFrom<I248> for U224;
+    impl TryFrom<U256> for U224;
+    impl TryFrom<I256> for U224;
+    impl From<u8> for I224;
+    impl From<u16> for I224;
+    impl From<u32> for I224;
+    impl From<u64> for I224;
+    impl From<u128> for I224;
+    impl From<usize> for I224;
+    impl From<i8> for I224;
+    impl From<i16> for I224;
+    impl From<i32> for I224;
+    impl From<i64> for I224;
+    impl From<i128> for I224;
+    impl From<isize> for I224;
+    impl From<U16> for I224;
+    impl From<I16> for I224;
+    impl From<U24> for I224;
+    impl From<I24> for I224;
+    impl From<U32> for I224;
+    impl From<I32> for I224;
+    impl From<U40> for I224;
+    impl From<I40> for I224;
+    impl From<U48> for I224;
+    impl From<I48> for I224;
+    impl From<U56> for I224;
+    impl From<I56> for I224;
+    impl From<U64> for I224;
+    impl From<I64> for I224;
+    impl From<U72> for I224;
+    impl From<I72> for I224;
+    impl From<U80> for I224;
+    impl From<I80> for I224;
+    impl From<U88> for I224;
+    impl From<I88> for I224;
+    impl From<U96> for I224;
+    impl From<I96> for I224;
+    impl From<U104> for I224;
+    impl From<I104> for I224;
+    impl From<U112> for I224;
+    impl From<I112> for I224;
+    impl From<U120> for I224;
+    impl From<I120> for I224;
+    impl From<U128> for I224;
+    impl From<I128> for I224;
+    impl From<U136> for I224;
+    impl From<I136> for I224;
+    impl From<U144> for I224;
+    impl From<I144> for I224;
+    impl From<U152> for I224;
+    impl From<I152> for I224;
+    impl From<U160> for I224;
+    impl From<I160> for I224;
+    impl From<U168> for I224;
+    impl From<I168> for I224;
+    impl From<U176> for I224;
+    impl From<I176> for I224;
+    impl From<U184> for I224;
+    impl From<I184> for I224;
+    impl From<U192> for I224;
+    impl From<I192> for I224;
+    impl From<U200> for I224;
+    impl From<I200> for I224;
+    impl From<U208> for I224;
+    impl From<I208> for I224;
+    impl From<U216> for I224;
+    impl From<I216> for I224;
+    impl TryFrom<U224> for I224;
+    impl TryFrom<U232> for I224;
+    impl TryFrom<I232> for I224;
+    impl TryFrom<U240> for I224;
+    impl TryFrom<I240> for I224;
+    impl TryFrom<U248> for I224;
+    impl TryFrom<I248> for I224;
+    impl TryFrom<U256> for I224;
+    impl TryFrom<I256> for I224;
+    impl From<u8> for U232;
+    impl From<u16> for U232;
+    impl From<u32> for U232;
+    impl From<u64> for U232;
+    impl From<u128> for U232;
+    impl From<usize> for U232;
+    impl TryFrom<i8> for U232;
+    impl TryFrom<i16> for U232;
+    impl TryFrom<i32> for U232;
+    impl TryFrom<i64> for U232;
+    impl TryFrom<i128> for U232;
+    impl TryFrom<isize> for U232;
+    impl From<U16> for U232;
+    impl TryFrom<I16> for U232;
+    impl From<U24> for U232;
+    impl TryFrom<I24> for U232;
+    impl From<U32> for U232;
+    impl TryFrom<I32> for U232;
+    impl From<U40> for U232;
+    impl TryFrom<I40> for U232;
+    impl From<U48> for U232;
+    impl TryFrom<I48> for U232;
+    impl From<U56> for U232;
+    impl TryFrom<I56> for U232;
+    impl From<U64> for U232;
+    impl TryFrom<I64> for U232;
+    impl From<U72> for U232;
+    impl TryFrom<I72> for U232;
+    impl From<U80> for U232;
+    impl TryFrom<I80> for U232;
+    impl From<U88> for U232;
+    impl TryFrom<I88> for U232;
+    impl From<U96> for U232;
+    impl TryFrom<I96> for U232;
+    impl From<U104> for U232;
+    impl TryFrom<I104> for U232;
+    impl From<U112> for U232;
+    impl TryFrom<I112> for U232;
+    impl From<U120> for U232;
+    impl TryFrom<I120> for U232;
+    impl From<U128> for U232;
+    impl TryFrom<I128> for U232;
+    impl From<U136> for U232;
+    impl TryFrom<I136> for U232;
+    impl From<U144> for U232;
+    impl TryFrom<I144> for U232;
+    impl From<U152> for U232;
+    impl TryFrom<I152> for U232;
+    impl From<U160> for U232;
+    impl TryFrom<I160> for U232;
+    impl From<U168> for U232;
+    impl TryFrom<I168> for U232;
+    impl From<U176> for U232;
+    impl TryFrom<I176> for U232;
+    impl From<U184> for U232;
+    impl TryFrom<I184> for U232;
+    impl From<U192> for U232;
+    impl TryFrom<I192> for U232;
+    impl From<U200> for U232;
+    impl TryFrom<I200> for U232;
+    impl From<U208> for U232;
+    impl TryFrom<I208> for U232;
+    impl From<U216> for U232;
+    impl TryFrom<I216> for U232;
+    impl From<U224> for U232;
+    impl TryFrom<I224> for U232;
+    impl TryFrom<I232> for U232;
+    impl TryFrom<U240> for U232;
+    impl TryFrom<I240> for U232;
+    impl TryFrom<U248> for U232;
+    impl TryFrom<I248> for U232;
+    impl TryFrom<U256> for U232;
+    impl TryFrom<I256> for U232;
+    impl From<u8> for I232;
+    impl From<u16> for I232;
+    impl From<u32> for I232;
+    impl From<u64> for I232;
+    impl From<u128> for I232;
+    impl From<usize> for I232;
+    impl From<i8> for I232;
+    impl From<i16> for I232;
+    impl From<i32> for I232;
+    impl From<i64> for I232;
+    impl From<i128> for I232;
+    impl From<isize> for I232;
+    impl From<U16> for I232;
+    impl From<I16> for I232;
+    impl From<U24> for I232;
+    impl From<I24> for I232;
+    impl From<U32> for I232;
+    impl From<I32> for I232;
+    impl From<U40> for I232;
+    impl From<I40> for I232;
+    impl From<U48> for I232;
+    impl From<I48> for I232;
+    impl From<U56> for I232;
+    impl From<I56> for I232;
+    impl From<U64> for I232;
+    impl From<I64> for I232;
+    impl From<U72> for I232;
+    impl From<I72> for I232;
+    impl From<U80> for I232;
+    impl From<I80> for I232;
+    impl From<U88> for I232;
+    impl From<I88> for I232;
+    impl From<U96> for I232;
+    impl From<I96> for I232;
+    impl From<U104> for I232;
+    impl From<I104> for I232;
+    impl From<U112> for I232;
+    impl From<I112> for I232;
+    impl From<U120> for I232;
+    impl From<I120> for I232;
+    impl From<U128> for I232;
+    impl From<I128> for I232;
+    impl From<U136> for I232;
+    impl From<I136> for I232;
+    impl From<U144> for I232;
+    impl From<I144> for I232;
+    impl From<U152> for I232;
+    impl From<I152> for I232;
+    impl From<U160> for I232;
+    impl From<I160> for I232;
+    impl From<U168> for I232;
+    impl From<I168> for I232;
+    impl From<U176> for I232;
+    impl From<I176> for I232;
+    impl From<U184> for I232;
+    impl From<I184> for I232;
+    impl From<U192> for I232;
+    impl From<I192> for I232;
+    impl From<U200> for I232;
+    impl From<I200> for I232;
+    impl From<U208> for I232;
+    impl From<I208> for I232;
+    impl From<U216> for I232;
+    impl From<I216> for I232;
+    impl From<U224> for I232;
+    impl From<I224> for I232;
+    impl TryFrom<U232> for I232;
+    impl TryFrom<U240> for I232;
+    impl TryFrom<I240> for I232;
+    impl TryFrom<U248> for I232;
+    impl TryFrom<I248> for I232;
+    impl TryFrom<U256> for I232;
+    impl TryFrom<I256> for I232;
+    impl From<u8> for U240;
+    impl From<u16> for U240;
+    impl From<u32> for U240;
+    impl From<u64> for U240;
+    impl From<u128> for U240;
+    impl From<usize> for U240;
+    impl TryFrom<i8> for U240;
+    impl TryFrom<i16> for U240;
+    impl TryFrom<i32> for U240;
+    impl TryFrom<i64> for U240;
+    impl TryFrom<i128> for U240;
+    impl TryFrom<isize> for U240;
+    impl From<U16> for U240;
+    impl TryFrom<I16> for U240;
+    impl From<U24> for U240;
+    impl TryFrom<I24> for U240;
+    impl From<U32> for U240;
+    impl TryFrom<I32> for U240;
+    impl From<U40> for U240;
+    impl TryFrom<I40> for U240;
+    impl From<U48> for U240;
+    impl TryFrom<I48> for U240;
+    impl From<U56> for U240;
+    impl TryFrom<I56> for U240;
+    impl From<U64> for U240;
+    impl TryFrom<I64> for U240;
+    impl From<U72> for U240;
+    impl TryFrom<I72> for U240;
+    impl From<U80> for U240;
+    impl TryFrom<I80> for U240;
+    impl From<U88> for U240;
+    impl TryFrom<I88> for U240;
+    impl From<U96> for U240;
+    impl TryFrom<I96> for U240;
+    impl From<U104> for U240;
+    impl TryFrom<I104> for U240;
+    impl From<U112> for U240;
+    impl TryFrom<I112> for U240;
+    impl From<U120> for U240;
+    impl TryFrom<I120> for U240;
+    impl From<U128> for U240;
+    impl TryFrom<I128> for U240;
+    impl From<U136> for U240;
+    impl TryFrom<I136> for U240;
+    impl From<U144> for U240;
+    impl TryFrom<I144> for U240;
+    impl From<U152> for U240;
+    impl TryFrom<I152> for U240;
+    impl From<U160> for U240;
+    impl TryFrom<I160> for U240;
+    impl From<U168> for U240;
+    impl TryFrom<I168> for U240;
+    impl From<U176> for U240;
+    impl TryFrom<I176> for U240;
+    impl From<U184> for U240;
+    impl TryFrom<I184> for U240;
+    impl From<U192> for U240;
+    impl TryFrom<I192> for U240;
+    impl From<U200> for U240;
+    impl TryFrom<I200> for U240;
+    impl From<U208> for U240;
+    impl TryFrom<I208> for U240;
+    impl From<U216> for U240;
+    impl TryFrom<I216> for U240;
+    impl From<U224> for U240;
+    impl TryFrom<I224> for U240;
+    impl From<U232> for U240;
+    impl TryFrom<I232> for U240;
+    impl TryFrom<I240> for U240;
+    impl TryFrom<U248> for U240;
+    impl TryFrom<I248> for U240;
+    impl TryFrom<U256> for U240;
+    impl TryFrom<I256> for U240;
+    impl From<u8> for I240;
+    impl From<u16> for I240;
+    impl From<u32> for I240;
+    impl From<u64> for I240;
+    impl From<u128> for I240;
+    impl From<usize> for I240;
+    impl From<i8> for I240;
+    impl From<i16> for I240;
+    impl From<i32> for I240;
+    impl From<i64> for I240;
+    impl From<i128> for I240;
+    impl From<isize> for I240;
+    impl From<U16> for I240;
+    impl From<I16> for I240;
+    impl From<U24> for I240;
+    impl From<I24> for I240;
+    impl From<U32> for I240;
+    impl From<I32> for I240;
+    impl From<U40> for I240;
+    impl From<I40> for I240;
+    impl From<U48> for I240;
+    impl From<I48> for I240;
+    impl From<U56> for I240;
+    impl From<I56> for I240;
+    impl From<U64> for I240;
+    impl From<I64> for I240;
+    impl From<U72> for I240;
+    impl From<I72> for I240;
+    impl From<U80> for I240;
+    impl From<I80> for I240;
+    impl From<U88> for I240;
+    impl From<I88> for I240;
+    impl From<U96> for I240;
+    impl From<I96> for I240;
+    impl From<U104> for I240;
+    impl From<I104> for I240;
+    impl From<U112> for I240;
+    impl From<I112> for I240;
+    impl From<U120> for I240;
+    impl From<I120> for I240;
+    impl From<U128> for I240;
+    impl From<I128> for I240;
+    impl From<U136> for I240;
+    impl From<I136> for I240;
+    impl From<U144> for I240;
+    impl From<I144> for I240;
+    impl From<U152> for I240;
+    impl From<I152> for I240;
+    impl From<U160> for I240;
+    impl From<I160> for I240;
+    impl From<U168> for I240;
+    impl From<I168> for I240;
+    impl From<U176> for I240;
+    impl From<I176> for I240;
+    impl From<U184> for I240;
+    impl From<I184> for I240;
+    impl From<U192> for I240;
+    impl From<I192> for I240;
+    impl From<U200> for I240;
+    impl From<I200> for I240;
+    impl From<U208> for I240;
+    impl From<I208> for I240;
+    impl From<U216> for I240;
+    impl From<I216> for I240;
+    impl From<U224> for I240;
+    impl From<I224> for I240;
+    impl From<U232> for I240;
+    impl From<I232> for I240;
+    impl TryFrom<U240> for I240;
+    impl TryFrom<U248> for I240;
+    impl TryFrom<I248> for I240;
+    impl TryFrom<U256> for I240;
+    impl TryFrom<I256> for I240;
+    impl From<u8> for U248;
+    impl From<u16> for U248;
+    impl From<u32> for U248;
+    impl From<u64> for U248;
+    impl From<u128> for U248;
+    impl From<usize> for U248;
+    impl TryFrom<i8> for U248;
+    impl TryFrom<i16> for U248;
+    impl TryFrom<i32> for U248;
+    impl TryFrom<i64> for U248;
+    impl TryFrom<i128> for U248;
+    impl TryFrom<isize> for U248;
+    impl From<U16> for U248;
+    impl TryFrom<I16> for U248;
+    impl From<U24> for U248;
+    impl TryFrom<I24> for U248;
+    impl From<U32> for U248;
+    impl TryFrom<I32> for U248;
+    impl From<U40> for U248;
+    impl TryFrom<I40> for U248;
+    impl From<U48> for U248;
+    impl TryFrom<I48> for U248;
+    impl From<U56> for U248;
+    impl TryFrom<I56> for U248;
+    impl From<U64> for U248;
+    impl TryFrom<I64> for U248;
+    impl From<U72> for U248;
+    impl TryFrom<I72> for U248;
+    impl From<U80> for U248;
+    impl TryFrom<I80> for U248;
+    impl From<U88> for U248;
+    impl TryFrom<I88> for U248;
+    impl From<U96> for U248;
+    impl TryFrom<I96> for U248;
+    impl From<U104> for U248;
+    impl TryFrom<I104> for U248;
+    impl From<U112> for U248;
+    impl TryFrom<I112> for U248;
+    impl From<U120> for U248;
+    impl TryFrom<I120> for U248;
+    impl From<U128> for U248;
+    impl TryFrom<I128> for U248;
+    impl From<U136> for U248;
+    impl TryFrom<I136> for U248;
+    impl From<U144> for U248;
+    impl TryFrom<I144> for U248;
+    impl From<U152> for U248;
+    impl TryFrom<I152> for U248;
+    impl From<U160> for U248;
+    impl TryFrom<I160> for U248;
+    impl From<U168> for U248;
+    impl TryFrom<I168> for U248;
+    impl From<U176> for U248;
+    impl TryFrom<I176> for U248;
+    impl From<U184> for U248;
+    impl TryFrom<I184> for U248;
+    impl From<U192> for U248;
+    impl TryFrom<I192> for U248;
+    impl From<U200> for U248;
+    impl TryFrom<I200> for U248;
+    impl From<U208> for U248;
+    impl TryFrom<I208> for U248;
+    impl From<U216> for U248;
+    impl TryFrom<I216> for U248;
+    impl From<U224> for U248;
+    impl TryFrom<I224> for U248;
+    impl From<U232> for U248;
+    impl TryFrom<I232> for U248;
+    impl From<U240> for U248;
+    impl TryFrom<I240> for U248;
+    impl TryFrom<I248> for U248;
+    impl TryFrom<U256> for U248;
+    impl TryFrom<I256> for U248;
+    impl From<u8> for I248;
+    impl From<u16> for I248;
+    impl From<u32> for I248;
+    impl From<u64> for I248;
+    impl From<u128> for I248;
+    impl From<usize> for I248;
+    impl From<i8> for I248;
+    impl From<i16> for I248;
+    impl From<i32> for I248;
+    impl From<i64> for I248;
+    impl From<i128> for I248;
+    impl From<isize> for I248;
+    impl From<U16> for I248;
+    impl From<I16> for I248;
+    impl From<U24> for I248;
+    impl From<I24> for I248;
+    impl From<U32> for I248;
+    impl From<I32> for I248;
+    impl From<U40> for I248;
+    impl From<I40> for I248;
+    impl From<U48> for I248;
+    impl From<I48> for I248;
+    impl From<U56> for I248;
+    impl From<I56> for I248;
+    impl From<U64> for I248;
+    impl From<I64> for I248;
+    impl From<U72> for I248;
+    impl From<I72> for I248;
+    impl From<U80> for I248;
+    impl From<I80> for I248;
+    impl From<U88> for I248;
+    impl From<I88> for I248;
+    impl From<U96> for I248;
+    impl From<I96> for I248;
+    impl From<U104> for I248;
+    impl From<I104> for I248;
+    impl From<U112> for I248;
+    impl From<I112> for I248;
+    impl From<U120> for I248;
+    impl From<I120> for I248;
+    impl From<U128> for I248;
+    impl From<I128> for I248;
+    impl From<U136> for I248;
+    impl From<I136> for I248;
+    impl From<U144> for I248;
+    impl From<I144> for I248;
+    impl From<U152> for I248;
+    impl From<I152> for I248;
+    impl From<U160> for I248;
+    impl From<I160> for I248;
+    impl From<U168> for I248;
+    impl From<I168> for I248;
+    impl From<U176> for I248;
+    impl From<I176> for I248;
+    impl From<U184> for I248;
+    impl From<I184> for I248;
+    impl From<U192> for I248;
+    impl From<I192> for I248;
+    impl From<U200> for I248;
+    impl From<I200> for I248;
+    impl From<U208> for I248;
+    impl From<I208> for I248;
+    impl From<U216> for I248;
+    impl From<I216> for I248;
+    impl From<U224> for I248;
+    impl From<I224> for I248;
+    impl From<U232> for I248;
+    impl From<I232> for I248;
+    impl From<U240> for I248;
+    impl From<I240> for I248;
+    impl TryFrom<U248> for I248;
+    impl TryFrom<U256> for I248;
+    impl TryFrom<I256> for I248;
+    impl From<u8> for U256;
+    impl From<u16> for U256;
+    impl From<u32> for U256;
+    impl From<u64> for U256;
+    impl From<u128> for U256;
+    impl From<usize> for U256;
+    impl TryFrom<i8> for U256;
+    impl TryFrom<i16> for U256;
+    impl TryFrom<i32> for U256;
+    impl TryFrom<i64> for U256;
+    impl TryFrom<i128> for U256;
+    impl TryFrom<isize> for U256;
+    impl From<U16> for U256;
+    impl TryFrom<I16> for U256;
+    impl From<U24> for U256;
+    impl TryFrom<I24> for U256;
+    impl From<U32> for U256;
+    impl TryFrom<I32> for U256;
+    impl From<U40> for U256;
+    impl TryFrom<I40> for U256;
+    impl From<U48> for U256;
+    impl TryFrom<I48> for U256;
+    impl From<U56> for U256;
+    impl TryFrom<I56> for U256;
+    impl From<U64> for U256;
+    impl TryFrom<I64> for U256;
+    impl From<U72> for U256;
+    impl TryFrom<I72> for U256;
+    impl From<U80> for U256;
+    impl TryFrom<I80> for U256;
+    impl From<U88> for U256;
+    impl TryFrom<I88> for U256;
+    impl From<U96> for U256;
+    impl TryFrom<I96> for U256;
+    impl From<U104> for U256;
+    impl TryFrom<I104> for U256;
+    impl From<U112> for U256;
+    impl TryFrom<I112> for U256;
+    impl From<U120> for U256;
+    impl TryFrom<I120> for U256;
+    impl From<U128> for U256;
+    impl TryFrom<I128> for U256;
+    impl From<U136> for U256;
+    impl TryFrom<I136> for U256;
+    impl From<U144> for U256;
+    impl TryFrom<I144> for U256;
+    impl From<U152> for U256;
+    impl TryFrom<I152> for U256;
+    impl From<U160> for U256;
+    impl TryFrom<I160> for U256;
+    impl From<U168> for U256;
+    impl TryFrom<I168> for U256;
+    impl From<U176> for U256;
+    impl TryFrom<I176> for U256;
+    impl From<U184> for U256;
+    impl TryFrom<I184> for U256;
+    impl From<U192> for U256;
+    impl TryFrom<I192> for U256;
+    impl From<U200> for U256;
+    impl TryFrom<I200> for U256;
+    impl From<U208> for U256;
+    impl TryFrom<I208> for U256;
+    impl From<U216> for U256;
+    impl TryFrom<I216> for U256;
+    impl From<U224> for U256;
+    impl TryFrom<I224> for U256;
+    impl From<U232> for U256;
+    impl TryFrom<I232> for U256;
+    impl From<U240> for U256;
+    impl TryFrom<I240> for U256;
+    impl From<U248> for U256;
+    impl TryFrom<I248> for U256;
+    impl TryFrom<I256> for U256;
+    impl From<u8> for I256;
+    impl From<u16> for I256;
+    impl From<u32> for I256;
+    impl From<u64> for I256;
+    impl From<u128> for I256;
+    impl From<usize> for I256;
+    impl From<i8> for I256;
+    impl From<i16> for I256;
+    impl From<i32> for I256;
+    impl From<i64> for I256;
+    impl From<i128> for I256;
+    impl From<isize> for I256;
+    impl From<U16> for I256;
+    impl From<I16> for I256;
+    impl From<U24> for I256;
+    impl From<I24> for I256;
+    impl From<U32> for I256;
+    impl From<I32> for I256;
+    impl From<U40> for I256;
+    impl From<I40> for I256;
+    impl From<U48> for I256;
+    impl From<I48> for I256;
+    impl From<U56> for I256;
+    impl From<I56> for I256;
+    impl From<U64> for I256;
+    impl From<I64> for I256;
+    impl From<U72> for I256;
+    impl From<I72> for I256;
+    impl From<U80> for I256;
+    impl From<I80> for I256;
+    impl From<U88> for I256;
+    impl From<I88> for I256;
+    impl From<U96> for I256;
+    impl From<I96> for I256;
+    impl From<U104> for I256;
+    impl From<I104> for I256;
+    impl From<U112> for I256;
+    impl From<I112> for I256;
+    impl From<U120> for I256;
+    impl From<I120> for I256;
+    impl From<U128> for I256;
+    impl From<I128> for I256;
+    impl From<U136> for I256;
+    impl From<I136> for I256;
+    impl From<U144> for I256;
+    impl From<I144> for I256;
+    impl From<U152> for I256;
+    impl From<I152> for I256;
+    impl From<U160> for I256;
+    impl From<I160> for I256;
+    impl From<U168> for I256;
+    impl From<I168> for I256;
+    impl From<U176> for I256;
+    impl From<I176> for I256;
+    impl From<U184> for I256;
+    impl From<I184> for I256;
+    impl From<U192> for I256;
+    impl From<I192> for I256;
+    impl From<U200> for I256;
+    impl From<I200> for I256;
+    impl From<U208> for I256;
+    impl From<I208> for I256;
+    impl From<U216> for I256;
+    impl From<I216> for I256;
+    impl From<U224> for I256;
+    impl From<I224> for I256;
+    impl From<U232> for I256;
+    impl From<I232> for I256;
+    impl From<U240> for I256;
+    impl From<I240> for I256;
+    impl From<U248> for I256;
+    impl From<I248> for I256;
+    impl TryFrom<U256> for I256;
+}
+impl_wide_conversions! {
+    impl TryFrom<U136> for u8 as primitive;
+    impl TryFrom<U136> for u16 as primitive;
+    impl TryFrom<U136> for u32 as primitive;
+    impl TryFrom<U136> for u64 as primitive;
+    impl TryFrom<U136> for u128 as primitive;
+    impl TryFrom<U136> for usize as primitive;
+    impl TryFrom<U136> for i8 as primitive;
+    impl TryFrom<U136> for i16 as primitive;
+    impl TryFrom<U136> for i32 as primitive;
+    impl TryFrom<U136> for i64 as primitive;
+    impl TryFrom<U136> for i128 as primitive;
+    impl TryFrom<U136> for isize as primitive;
+    impl TryFrom<U136> for U16 as unaligned;
+    impl TryFrom<U136> for I16 as unaligned;
+    impl TryFrom<U136> for U24 as unaligned;
+    impl TryFrom<U136> for I24 as unaligned;
+    impl TryFrom<U136> for U32 as unaligned;
+    impl TryFrom<U136> for I32 as unaligned;
+    impl TryFrom<U136> for U40 as unaligned;
+    impl TryFrom<U136> for I40 as unaligned;
+    impl TryFrom<U136> for U48 as unaligned;
+    impl TryFrom<U136> for I48 as unaligned;
+    impl TryFrom<U136> for U56 as unaligned;
+    impl TryFrom<U136> for I56 as unaligned;
+    impl TryFrom<U136> for U64 as unaligned;
+    impl TryFrom<U136> for I64 as unaligned;
+    impl TryFrom<U136> for U72 as unaligned;
+    impl TryFrom<U136> for I72 as unaligned;
+    impl TryFrom<U136> for U80 as unaligned;
+    impl TryFrom<U136> for I80 as unaligned;
+    impl TryFrom<U136> for U88 as unaligned;
+    impl TryFrom<U136> for I88 as unaligned;
+    impl TryFrom<U136> for U96 as unaligned;
+    impl TryFrom<U136> for I96 as unaligned;
+    impl TryFrom<U136> for U104 as unaligned;
+    impl TryFrom<U136> for I104 as unaligned;
+    impl TryFrom<U136> for U112 as unaligned;
+    impl TryFrom<U136> for I112 as unaligned;
+    impl TryFrom<U136> for U120 as unaligned;
+    impl TryFrom<U136> for I120 as unaligned;
+    impl TryFrom<U136> for U128 as unaligned;
+    impl TryFrom<U136> for I128 as unaligned;
+    impl TryFrom<I136> for u8 as primitive;
+    impl TryFrom<I136> for u16 as primitive;
+    impl TryFrom<I136> for u32 as primitive;
+    impl TryFrom<I136> for u64 as primitive;
+    impl TryFrom<I136> for u128 as primitive;
+    impl TryFrom<I136> for usize as primitive;
+    impl TryFrom<I136> for i8 as primitive;
+    impl TryFrom<I136> for i16 as primitive;
+    impl TryFrom<I136> for i32 as primitive;
+    impl TryFrom<I136> for i64 as primitive;
+    impl TryFrom<I136> for i128 as primitive;
+    impl TryFrom<I136> for isize as primitive;
+    impl TryFrom<I136> for U16 as unaligned;
+    impl TryFrom<I136> for I16 as unaligned;
+    impl TryFrom<I136> for U24 as unaligned;
+    impl TryFrom<I136> for I24 as unaligned;
+    impl TryFrom<I136> for U32 as unaligned;
+    impl TryFrom<I136> for I32 as unaligned;
+    impl TryFrom<I136> for U40 as unaligned;
+    impl TryFrom<I136> for I40 as unaligned;
+    impl TryFrom<I136> for U48 as unaligned;
+    impl TryFrom<I136> for I48 as unaligned;
+    impl TryFrom<I136> for U56 as unaligned;
+    impl TryFrom<I136> for I56 as unaligned;
+    impl TryFrom<I136> for U64 as unaligned;
+    impl TryFrom<I136> for I64 as unaligned;
+    impl TryFrom<I136> for U72 as unaligned;
+    impl TryFrom<I136> for I72 as unaligned;
+    impl TryFrom<I136> for U80 as unaligned;
+    impl TryFrom<I136> for I80 as unaligned;
+    impl TryFrom<I136> for U88 as unaligned;
+    impl TryFrom<I136> for I88 as unaligned;
+    impl TryFrom<I136> for U96 as unaligned;
+    impl TryFrom<I136> for I96 as unaligned;
+    impl TryFrom<I136> for U104 as unaligned;
+    impl TryFrom<I136> for I104 as unaligned;
+    impl TryFrom<I136> for U112 as unaligned;
+    impl TryFrom<I136> for I112 as unaligned;
+    impl TryFrom<I136> for U120 as unaligned;
+    impl TryFrom<I136> for I120 as unaligned;
+    impl TryFrom<I136> for U128 as unaligned;
+    impl TryFrom<I136> for I128 as unaligned;
+    impl TryFrom<U144> for u8 as primitive;
+    impl TryFrom<U144> for u16 as primitive;
+    impl TryFrom<U144> for u32 as primitive;
+    impl TryFrom<U144> for u64 as primitive;
+    impl TryFrom<U144> for u128 as primitive;
+    impl TryFrom<U144> for usize as primitive;
+    impl TryFrom<U144> for i8 as primitive;
+    impl TryFrom<U144> for i16 as primitive;
+    impl TryFrom<U144> for i32 as primitive;
+    impl TryFrom<U144> for i64 as primitive;
+    impl TryFrom<U144> for i128 as primitive;
+    impl TryFrom<U144> for isize as primitive;
+    impl TryFrom<U144> for U16 as unaligned;
+    impl TryFrom<U144> for I16 as unaligned;
+    impl TryFrom<U144> for U24 as unaligned;
+    impl TryFrom<U144> for I24 as unaligned;
+    impl TryFrom<U144> for U32 as unaligned;
+    impl TryFrom<U144> for I32 as unaligned;
+    impl TryFrom<U144> for U40 as unaligned;
+    impl TryFrom<U144> for I40 as unaligned;
+    impl TryFrom<U144> for U48 as unaligned;
+    impl TryFrom<U144> for I48 as unaligned;
+    impl TryFrom<U144> for U56 as unaligned;
+    impl TryFrom<U144> for I56 as unaligned;
+    impl TryFrom<U144> for U64 as unaligned;
+    impl TryFrom<U144> for I64 as unaligned;
+    impl TryFrom<U144> for U72 as unaligned;
+    impl TryFrom<U144> for I72 as unaligned;
+    impl TryFrom<U144> for U80 as unaligned;
+    impl TryFrom<U144> for I80 as unaligned;
+    impl TryFrom<U144> for U88 as unaligned;
+    impl TryFrom<U144> for I88 as unaligned;
+    impl TryFrom<U144> for U96 as unaligned;
+    impl TryFrom<U144> for I96 as unaligned;
+    impl TryFrom<U144> for U104 as unaligned;
+    impl TryFrom<U144> for I104 as unaligned;
+    impl TryFrom<U144> for U112 as unaligned;
+    impl TryFrom<U144> for I112 as unaligned;
+    impl TryFrom<U144> for U120 as unaligned;
+    impl TryFrom<U144> for I120 as unaligned;
+    impl TryFrom<U144> for U128 as unaligned;
+    impl TryFrom<U144> for I128 as unaligned;
+    impl TryFrom<I144> for u8 as primitive;
+    impl TryFrom<I144> for u16 as primitive;
+    impl TryFrom<I144> for u32 as primitive;
+    impl TryFrom<I144> for u64 as primitive;
+    impl TryFrom<I144> for u128 as primitive;
+    impl TryFrom<I144> for usize as primitive;
+    impl TryFrom<I144> for i8 as primitive;
+    impl TryFrom<I144> for i16 as primitive;
+    impl TryFrom<I144> for i32 as primitive;
+    impl TryFrom<I144> for i64 as primitive;
+    impl TryFrom<I144> for i128 as primitive;
+    impl TryFrom<I144> for isize as primitive;
+    impl TryFrom<I144> for U16 as unaligned;
+    impl TryFrom<I144> for I16 as unaligned;
+    impl TryFrom<I144> for U24 as unaligned;
+    impl TryFrom<I144> for I24 as unaligned;
+    impl TryFrom<I144> for U32 as unaligned;
+    impl TryFrom<I144> for I32 as unaligned;
+    impl TryFrom<I144> for U40 as unaligned;
+    impl TryFrom<I144> for I40 as unaligned;
+    impl TryFrom<I144> for U48 as unaligned;
+    impl TryFrom<I144> for I48 as unaligned;
+    impl TryFrom<I144> for U56 as unaligned;
+    impl TryFrom<I144> for I56 as unaligned;
+    impl TryFrom<I144> for U64 as unaligned;
+    impl TryFrom<I144> for I64 as unaligned;
+    impl TryFrom<I144> for U72 as unaligned;
+    impl TryFrom<I144> for I72 as unaligned;
+    impl TryFrom<I144> for U80 as unaligned;
+    impl TryFrom<I144> for I80 as unaligned;
+    impl TryFrom<I144> for U88 as unaligned;
+    impl TryFrom<I144> for I88 as unaligned;
+    impl TryFrom<I144> for U96 as unaligned;
+    impl TryFrom<I144> for I96 as unaligned;
+    impl TryFrom<I144> for U104 as unaligned;
+    impl TryFrom<I144> for I104 as unaligned;
+    impl TryFrom<I144> for U112 as unaligned;
+    impl TryFrom<I144> for I112 as unaligned;
+    impl TryFrom<I144> for U120 as unaligned;
+    impl TryFrom<I144> for I120 as unaligned;
+    impl TryFrom<I144> for U128 as unaligned;
+    impl TryFrom<I144> for I128 as unaligned;
+    impl TryFrom<U152> for u8 as primitive;
+    impl TryFrom<U152> for u16 as primitive;
+    impl TryFrom<U152> for u32 as primitive;
+    impl TryFrom<U152> for u64 as primitive;
+    impl TryFrom<U152> for u128 as primitive;
+    impl TryFrom<U152> for usize as primitive;
+    impl TryFrom<U152> for i8 as primitive;
+    impl TryFrom<U152> for i16 as primitive;
+    impl TryFrom<U152> for i32 as primitive;
+    impl TryFrom<U152> for i64 as primitive;
+    impl TryFrom<U152> for i128 as primitive;
+    impl TryFrom<U152> for isize as primitive;
+    impl TryFrom<U152> for U16 as unaligned;
+    impl TryFrom<U152> for I16 as unaligned;
+    impl TryFrom<U152> for U24 as unaligned;
+    impl TryFrom<U152> for I24 as unaligned;
+    impl TryFrom<U152> for U32 as unaligned;
+    impl TryFrom<U152> for I32 as unaligned;
+    impl TryFrom<U152> for U40 as unaligned;
+    impl TryFrom<U152> for I40 as unaligned;
+    impl TryFrom<U152> for U48 as unaligned;
+    impl TryFrom<U152> for I48 as unaligned;
+    impl TryFrom<U152> for U56 as unaligned;
+    impl TryFrom<U152> for I56 as unaligned;
+    impl TryFrom<U152> for U64 as unaligned;
+    impl TryFrom<U152> for I64 as unaligned;
+    impl TryFrom<U152> for U72 as unaligned;
+    impl TryFrom<U152> for I72 as unaligned;
+    impl TryFrom<U152> for U80 as unaligned;
+    impl TryFrom<U152> for I80 as unaligned;
+    impl TryFrom<U152> for U88 as unaligned;
+    impl TryFrom<U152> for I88 as unaligned;
+    impl TryFrom<U152> for U96 as unaligned;
+    impl TryFrom<U152> for I96 as unaligned;
+    impl TryFrom<U152> for U104 as unaligned;
+    impl TryFrom<U152> for I104 as unaligned;
+    impl TryFrom<U152> for U112 as unaligned;
+    impl TryFrom<U152> for I112 as unaligned;
+    impl TryFrom<U152> for U120 as unaligned;
+    impl TryFrom<U152> for I120 as unaligned;
+    impl TryFrom<U152> for U128 as unaligned;
+    impl TryFrom<U152> for I128 as unaligned;
+    impl TryFrom<I152> for u8 as primitive;
+    impl TryFrom<I152> for u16 as primitive;
+    impl TryFrom<I152> for u32 as primitive;
+    impl TryFrom<I152> for u64 as primitive;
+    impl TryFrom<I152> for u128 as primitive;
+    impl TryFrom<I152> for usize as primitive;
+    impl TryFrom<I152> for i8 as primitive;
+    impl TryFrom<I152> for i16 as primitive;
+    impl TryFrom<I152> for i32 as primitive;
+    impl TryFrom<I152> for i64 as primitive;
+    impl TryFrom<I152> for i128 as primitive;
+    impl TryFrom<I152> for isize as primitive;
+    impl TryFrom<I152> for U16 as unaligned;
+    impl TryFrom<I152> for I16 as unaligned;
+    impl TryFrom<I152> for U24 as unaligned;
+    impl TryFrom<I152> for I24 as unaligned;
+    impl TryFrom<I152> for U32 as unaligned;
+    impl TryFrom<I152> for I32 as unaligned;
+    impl TryFrom<I152> for U40 as unaligned;
+    impl TryFrom<I152> for I40 as unaligned;
+    impl TryFrom<I152> for U48 as unaligned;
+    impl TryFrom<I152> for I48 as unaligned;
+    impl TryFrom<I152> for U56 as unaligned;
+    impl TryFrom<I152> for I56 as unaligned;
+    impl TryFrom<I152> for U64 as unaligned;
+    impl TryFrom<I152> for I64 as unaligned;
+    impl TryFrom<I152> for U72 as unaligned;
+    impl TryFrom<I152> for I72 as unaligned;
+    impl TryFrom<I152> for U80 as unaligned;
+    impl TryFrom<I152> for I80 as unaligned;
+    impl TryFrom<I152> for U88 as unaligned;
+    impl TryFrom<I152> for I88 as unaligned;
+    impl TryFrom<I152> for U96 as unaligned;
+    impl TryFrom<I152> for I96 as unaligned;
+    impl TryFrom<I152> for U104 as unaligned;
+    impl TryFrom<I152> for I104 as unaligned;
+    impl TryFrom<I152> for U112 as unaligned;
+    impl TryFrom<I152> for I112 as unaligned;
+    impl TryFrom<I152> for U120 as unaligned;
+    impl TryFrom<I152> for I120 as unaligned;
+    impl TryFrom<I152> for U128 as unaligned;
+    impl TryFrom<I152> for I128 as unaligned;
+    impl TryFrom<U160> for u8 as primitive;
+    impl TryFrom<U160> for u16 as primitive;
+    impl TryFrom<U160> for u32 as primitive;
+    impl TryFrom<U160> for u64 as primitive;
+    impl TryFrom<U160> for u128 as primitive;
+    impl TryFrom<U160> for usize as primitive;
+    impl TryFrom<U160> for i8 as primitive;
+    impl TryFrom<U160> for i16 as primitive;
+    impl TryFrom<U160> for i32 as primitive;
+    impl TryFrom<U160> for i64 as primitive;
+    impl TryFrom<U160> for i128 as primitive;
+    impl TryFrom<U160> for isize as primitive;
+    impl TryFrom<U160> for U16 as unaligned;
+    impl TryFrom<U160> for I16 as unaligned;
+    impl TryFrom<U160> for U24 as unaligned;
+    impl TryFrom<U160> for I24 as unaligned;
+    impl TryFrom<U160> for U32 as unaligned;
+    impl TryFrom<U160> for I32 as unaligned;
+    impl TryFrom<U160> for U40 as unaligned;
+    impl TryFrom<U160> for I40 as unaligned;
+    impl TryFrom<U160> for U48 as unaligned;
+    impl TryFrom<U160> for I48 as unaligned;
+    impl TryFrom<U160> for U56 as unaligned;
+    impl TryFrom<U160> for I56 as unaligned;
+    impl TryFrom<U160> for U64 as unaligned;
+    impl TryFrom<U160> for I64 as unaligned;
+    impl TryFrom<U160> for U72 as unaligned;
+    impl TryFrom<U160> for I72 as unaligned;
+    impl TryFrom<U160> for U80 as unaligned;
+    impl TryFrom<U160> for I80 as unaligned;
+    impl TryFrom<U160> for U88 as unaligned;
+    impl TryFrom<U160> for I88 as unaligned;
+    impl TryFrom<U160> for U96 as unaligned;
+    impl TryFrom<U160> for I96 as unaligned;
+    impl TryFrom<U160> for U104 as unaligned;
+    impl TryFrom<U160> for I104 as unaligned;
+    impl TryFrom<U160> for U112 as unaligned;
+    impl TryFrom<U160> for I112 as unaligned;
+    impl TryFrom<U160> for U120 as unaligned;
+    impl TryFrom<U160> for I120 as unaligned;
+    impl TryFrom<U160> for U128 as unaligned;
+    impl TryFrom<U160> for I128 as unaligned;
+    impl TryFrom<I160> for u8 as primitive;
+    impl TryFrom<I160> for u16 as primitive;
+    impl TryFrom<I160> for u32 as primitive;
+    impl TryFrom<I160> for u64 as primitive;
+    impl TryFrom<I160> for u128 as primitive;
+    impl TryFrom<I160> for usize as primitive;
+    impl TryFrom<I160> for i8 as primitive;
+    impl TryFrom<I160> for i16 as primitive;
+    impl TryFrom<I160> for i32 as primitive;
+    impl TryFrom<I160> for i64 as primitive;
+    impl TryFrom<I160> for i128 as primitive;
+    impl TryFrom<I160> for isize as primitive;
+    impl TryFrom<I160> for U16 as unaligned;
+    impl TryFrom<I160> for I16 as unaligned;
+    impl TryFrom<I160> for U24 as unaligned;
+    impl TryFrom<I160> for I24 as unaligned;
+    impl TryFrom<I160> for U32 as unaligned;
+    impl TryFrom<I160> for I32 as unaligned;
+    impl TryFrom<I160> for U40 as unaligned;
+    impl TryFrom<I160> for I40 as unaligned;
+    impl TryFrom<I160> for U48 as unaligned;
+    impl TryFrom<I160> for I48 as unaligned;
+    impl TryFrom<I160> for U56 as unaligned;
+    impl TryFrom<I160> for I56 as unaligned;
+    impl TryFrom<I160> for U64 as unaligned;
+    impl TryFrom<I160> for I64 as unaligned;
+    impl TryFrom<I160> for U72 as unaligned;
+    impl TryFrom<I160> for I72 as unaligned;
+    impl TryFrom<I160> for U80 as unaligned;
+    impl TryFrom<I160> for I80 as unaligned;
+    impl TryFrom<I160> for U88 as unaligned;
+    impl TryFrom<I160> for I88 as unaligned;
+    impl TryFrom<I160> for U96 as unaligned;
+    impl TryFrom<I160> for I96 as unaligned;
+    impl TryFrom<I160> for U104 as unaligned;
+    impl TryFrom<I160> for I104 as unaligned;
+    impl TryFrom<I160> for U112 as unaligned;
+    impl TryFrom<I160> for I112 as unaligned;
+    impl TryFrom<I160> for U120 as unaligned;
+    impl TryFrom<I160> for I120 as unaligned;
+    impl TryFrom<I160> for U128 as unaligned;
+    impl TryFrom<I160> for I128 as unaligned;
+    impl TryFrom<U168> for u8 as primitive;
+    impl TryFrom<U168> for u16 as primitive;
+    impl TryFrom<U168> for u32 as primitive;
+    impl TryFrom<U168> for u64 as primitive;
+    impl TryFrom<U168> for u128 as primitive;
+    impl TryFrom<U168> for usize as primitive;
+    impl TryFrom<U168> for i8 as primitive;
+    impl TryFrom<U168> for i16 as primitive;
+    impl TryFrom<U168> for i32 as primitive;
+    impl TryFrom<U168> for i64 as primitive;
+    impl TryFrom<U168> for i128 as primitive;
+    impl TryFrom<U168> for isize as primitive;
+    impl TryFrom<U168> for U16 as unaligned;
+    impl TryFrom<U168> for I16 as unaligned;
+    impl TryFrom<U168> for U24 as unaligned;
+    impl TryFrom<U168> for I24 as unaligned;
+    impl TryFrom<U168> for U32 as unaligned;
+    impl TryFrom<U168> for I32 as unaligned;
+    impl TryFrom<U168> for U40 as unaligned;
+    impl TryFrom<U168> for I40 as unaligned;
+    impl TryFrom<U168> for U48 as unaligned;
+    impl TryFrom<U168> for I48 as unaligned;
+    impl TryFrom<U168> for U56 as unaligned;
+    impl TryFrom<U168> for I56 as unaligned;
+    impl TryFrom<U168> for U64 as unaligned;
+    impl TryFrom<U168> for I64 as unaligned;
+    impl TryFrom<U168> for U72 as unaligned;
+    impl TryFrom<U168> for I72 as unaligned;
+    impl TryFrom<U168> for U80 as unaligned;
+    impl TryFrom<U168> for I80 as unaligned;
+    impl TryFrom<U168> for U88 as unaligned;
+    impl TryFrom<U168> for I88 as unaligned;
+    impl TryFrom<U168> for U96 as unaligned;
+    impl TryFrom<U168> for I96 as unaligned;
+    impl TryFrom<U168> for U104 as unaligned;
+    impl TryFrom<U168> for I104 as unaligned;
+    impl TryFrom<U168> for U112 as unaligned;
+    impl TryFrom<U168> for I112 as unaligned;
+    impl TryFrom<U168> for U120 as unaligned;
+    impl TryFrom<U168> for I120 as unaligned;
+    impl TryFrom<U168> for U128 as unaligned;
+    impl TryFrom<U168> for I128 as unaligned;
+    impl TryFrom<I168> for u8 as primitive;
+    impl TryFrom<I168> for u16 as primitive;
+    impl TryFrom<I168> for u32 as primitive;
+    impl TryFrom<I168> for u64 as primitive;
+    impl TryFrom<I168> for u128 as primitive;
+    impl TryFrom<I168> for usize as primitive;
+    impl TryFrom<I168> for i8 as primitive;
+    impl TryFrom<I168> for i16 as primitive;
+    impl TryFrom<I168> for i32 as primitive;
+    impl TryFrom<I168> for i64 as primitive;
+    impl TryFrom<I168> for i128 as primitive;
+    impl TryFrom<I168> for isize as primitive;
+    impl TryFrom<I168> for U16 as unaligned;
+    impl TryFrom<I168> for I16 as unaligned;
+    impl TryFrom<I168> for U24 as unaligned;
+    impl TryFrom<I168> for I24 as unaligned;
+    impl TryFrom<I168> for U32 as unaligned;
+    impl TryFrom<I168> for I32 as unaligned;
+    impl TryFrom<I168> for U40 as unaligned;
+    impl TryFrom<I168> for I40 as unaligned;
+    impl TryFrom<I168> for U48 as unaligned;
+    impl TryFrom<I168> for I48 as unaligned;
+    impl TryFrom<I168> for U56 as unaligned;
+    impl TryFrom<I168> for I56 as unaligned;
+    impl TryFrom<I168> for U64 as unaligned;
+    impl TryFrom<I168> for I64 as unaligned;
+    impl TryFrom<I168> for U72 as unaligned;
+    impl TryFrom<I168> for I72 as unaligned;
+    impl TryFrom<I168> for U80 as unaligned;
+    impl TryFrom<I168> for I80 as unaligned;
+    impl TryFrom<I168> for U88 as unaligned;
+    impl TryFrom<I168> for I88 as unaligned;
+    impl TryFrom<I168> for U96 as unaligned;
+    impl TryFrom<I168> for I96 as unaligned;
+    impl TryFrom<I168> for U104 as unaligned;
+    impl TryFrom<I168> for I104 as unaligned;
+    impl TryFrom<I168> for U112 as unaligned;
+    impl TryFrom<I168> for I112 as unaligned;
+    impl TryFrom<I168> for U120 as unaligned;
+    impl TryFrom<I168> for I120 as unaligned;
+    impl TryFrom<I168> for U128 as unaligned;
+    impl TryFrom<I168> for I128 as unaligned;
+    impl TryFrom<U176> for u8 as primitive;
+    impl TryFrom<U176> for u16 as primitive;
+    impl TryFrom<U176> for u32 as primitive;
+    impl TryFrom<U176> for u64 as primitive;
+    impl TryFrom<U176> for u128 as primitive;
+    impl TryFrom<U176> for usize as primitive;
+    impl TryFrom<U176> for i8 as primitive;
+    impl TryFrom<U176> for i16 as primitive;
+    impl TryFrom<U176> for i32 as primitive;
+    impl TryFrom<U176> for i64 as primitive;
+    impl TryFrom<U176> for i128 as primitive;
+    impl TryFrom<U176> for isize as primitive;
+    impl TryFrom<U176> for U16 as unaligned;
+    impl TryFrom<U176> for I16 as unaligned;
+    impl TryFrom<U176> for U24 as unaligned;
+    impl TryFrom<U176> for I24 as unaligned;
+    impl TryFrom<U176> for U32 as unaligned;
+    impl TryFrom<U176> for I32 as unaligned;
+    impl TryFrom<U176> for U40 as unaligned;
+    impl TryFrom<U176> for I40 as unaligned;
+    impl TryFrom<U176> for U48 as unaligned;
+    impl TryFrom<U176> for I48 as unaligned;
+    impl TryFrom<U176> for U56 as unaligned;
+    impl TryFrom<U176> for I56 as unaligned;
+    impl TryFrom<U176> for U64 as unaligned;
+    impl TryFrom<U176> for I64 as unaligned;
+    impl TryFrom<U176> for U72 as unaligned;
+    impl TryFrom<U176> for I72 as unaligned;
+    impl TryFrom<U176> for U80 as unaligned;
+    impl TryFrom<U176> for I80 as unaligned;
+    impl TryFrom<U176> for U88 as unaligned;
+    impl TryFrom<U176> for I88 as unaligned;
+    impl TryFrom<U176> for U96 as unaligned;
+    impl TryFrom<U176> for I96 as unaligned;
+    impl TryFrom<U176> for U104 as unaligned;
+    impl TryFrom<U176> for I104 as unaligned;
+    impl TryFrom<U176> for U112 as unaligned;
+    impl TryFrom<U176> for I112 as unaligned;
+    impl TryFrom<U176> for U120 as unaligned;
+    impl TryFrom<U176> for I120 as unaligned;
+    impl TryFrom<U176> for U128 as unaligned;
+    impl TryFrom<U176> for I128 as unaligned;
+    impl TryFrom<I176> for u8 as primitive;
+    impl TryFrom<I176> for u16 as primitive;
+    impl TryFrom<I176> for u32 as primitive;
+    impl TryFrom<I176> for u64 as primitive;
+    impl TryFrom<I176> for u128 as primitive;
+    impl TryFrom<I176> for usize as primitive;
+    impl TryFrom<I176> for i8 as primitive;
+    impl TryFrom<I176> for i16 as primitive;
+    impl TryFrom<I176> for i32 as primitive;
+    impl TryFrom<I176> for i64 as primitive;
+    impl TryFrom<I176> for i128 as primitive;
+    impl TryFrom<I176> for isize as primitive;
+    impl TryFrom<I176> for U16 as unaligned;
+    impl TryFrom<I176> for I16 as unaligned;
+    impl TryFrom<I176> for U24 as unaligned;
+    impl TryFrom<I176> for I24 as unaligned;
+    impl TryFrom<I176> for U32 as unaligned;
+    impl TryFrom<I176> for I32 as unaligned;
+    impl TryFrom<I176> for U40 as unaligned;
+    impl TryFrom<I176> for I40 as unaligned;
+    impl TryFrom<I176> for U48 as unaligned;
+    impl TryFrom<I176> for I48 as unaligned;
+    impl TryFrom<I176> for U56 as unaligned;
+    impl TryFrom<I176> for I56 as unaligned;
+    impl TryFrom<I176> for U64 as unaligned;
+    impl TryFrom<I176> for I64 as unaligned;
+    impl TryFrom<I176> for U72 as unaligned;
+    impl TryFrom<I176> for I72 as unaligned;
+    impl TryFrom<I176> for U80 as unaligned;
+    impl TryFrom<I176> for I80 as unaligned;
+    impl TryFrom<I176> for U88 as unaligned;
+    impl TryFrom<I176> for I88 as unaligned;
+    impl TryFrom<I176> for U96 as unaligned;
+    impl TryFrom<I176> for I96 as unaligned;
+    impl TryFrom<I176> for U104 as unaligned;
+    impl TryFrom<I176> for I104 as unaligned;
+    impl TryFrom<I176> for U112 as unaligned;
+    impl TryFrom<I176> for I112 as unaligned;
+    impl TryFrom<I176> for U120 as unaligned;
+    impl TryFrom<I176> for I120 as unaligned;
+    impl TryFrom<I176> for U128 as unaligned;
+    impl TryFrom<I176> for I128 as unaligned;
+    impl TryFrom<U184> for u8 as primitive;
+    impl TryFrom<U184> for u16 as primitive;
+    impl TryFrom<U184> for u32 as primitive;
+    impl TryFrom<U184> for u64 as primitive;
+    impl TryFrom<U184> for u128 as primitive;
+    impl TryFrom<U184> for usize as primitive;
+    impl TryFrom<U184> for i8 as primitive;
+    impl TryFrom<U184> for i16 as primitive;
+    impl TryFrom<U184> for i32 as primitive;
+    impl TryFrom<U184> for i64 as primitive;
+    impl TryFrom<U184> for i128 as primitive;
+    impl TryFrom<U184> for isize as primitive;
+    impl TryFrom<U184> for U16 as unaligned;
+    impl TryFrom<U184> for I16 as unaligned;
+    impl TryFrom<U184> for U24 as unaligned;
+    impl TryFrom<U184> for I24 as unaligned;
+    impl TryFrom<U184> for U32 as unaligned;
+    impl TryFrom<U184> for I32 as unaligned;
+    impl TryFrom<U184> for U40 as unaligned;
+    impl TryFrom<U184> for I40 as unaligned;
+    impl TryFrom<U184> for U48 as unaligned;
+    impl TryFrom<U184> for I48 as unaligned;
+    impl TryFrom<U184> for U56 as unaligned;
+    impl TryFrom<U184> for I56 as unaligned;
+    impl TryFrom<U184> for U64 as unaligned;
+    impl TryFrom<U184> for I64 as unaligned;
+    impl TryFrom<U184> for U72 as unaligned;
+    impl TryFrom<U184> for I72 as unaligned;
+    impl TryFrom<U184> for U80 as unaligned;
+    impl TryFrom<U184> for I80 as unaligned;
+    impl TryFrom<U184> for U88 as unaligned;
+    impl TryFrom<U184> for I88 as unaligned;
+    impl TryFrom<U184> for U96 as unaligned;
+    impl TryFrom<U184> for I96 as unaligned;
+    impl TryFrom<U184> for U104 as unaligned;
+    impl TryFrom<U184> for I104 as unaligned;
+    impl TryFrom<U184> for U112 as unaligned;
+    impl TryFrom<U184> for I112 as unaligned;
+    impl TryFrom<U184> for U120 as unaligned;
+    impl TryFrom<U184> for I120 as unaligned;
+    impl TryFrom<U184> for U128 as unaligned;
+    impl TryFrom<U184> for I128 as unaligned;
+    impl TryFrom<I184> for u8 as primitive;
+    impl TryFrom<I184> for u16 as primitive;
+    impl TryFrom<I184> for u32 as primitive;
+    impl TryFrom<I184> for u64 as primitive;
+    impl TryFrom<I184> for u128 as primitive;
+    impl TryFrom<I184> for usize as primitive;
+    impl TryFrom<I184> for i8 as primitive;
+    impl TryFrom<I184> for i16 as primitive;
+    impl TryFrom<I184> for i32 as primitive;
+    impl TryFrom<I184> for i64 as primitive;
+    impl TryFrom<I184> for i128 as primitive;
+    impl TryFrom<I184> for isize as primitive;
+    impl TryFrom<I184> for U16 as unaligned;
+    impl TryFrom<I184> for I16 as unaligned;
+    impl TryFrom<I184> for U24 as unaligned;
+    impl TryFrom<I184> for I24 as unaligned;
+    impl TryFrom<I184> for U32 as unaligned;
+    impl TryFrom<I184> for I32 as unaligned;
+    impl TryFrom<I184> for U40 as unaligned;
+    impl TryFrom<I184> for I40 as unaligned;
+    impl TryFrom<I184> for U48 as unaligned;
+    impl TryFrom<I184> for I48 as unaligned;
+    impl TryFrom<I184> for U56 as unaligned;
+    impl TryFrom<I184> for I56 as unaligned;
+    impl TryFrom<I184> for U64 as unaligned;
+    impl TryFrom<I184> for I64 as unaligned;
+    impl TryFrom<I184> for U72 as unaligned;
+    impl TryFrom<I184> for I72 as unaligned;
+    impl TryFrom<I184> for U80 as unaligned;
+    impl TryFrom<I184> for I80 as unaligned;
+    impl TryFrom<I184> for U88 as unaligned;
+    impl TryFrom<I184> for I88 as unaligned;
+    impl TryFrom<I184> for U96 as unaligned;
+    impl TryFrom<I184> for I96 as unaligned;
+    impl TryFrom<I184> for U104 as unaligned;
+    impl TryFrom<I184> for I104 as unaligned;
+    impl TryFrom<I184> for U112 as unaligned;
+    impl TryFrom<I184> for I112 as unaligned;
+    impl TryFrom<I184> for U120 as unaligned;
+    impl TryFrom<I184> for I120 as unaligned;
+    impl TryFrom<I184> for U128 as unaligned;
+    impl TryFrom<I184> for I128 as unaligned;
+    impl TryFrom<U192> for u8 as primitive;
+    impl TryFrom<U192> for u16 as primitive;
+    impl TryFrom<U192> for u32 as primitive;
+    impl TryFrom<U192> for u64 as primitive;
+    impl TryFrom<U192> for u128 as primitive;
+    impl TryFrom<U192> for usize as primitive;
+    impl TryFrom<U192> for i8 as primitive;
+    impl TryFrom<U192> for i16 as primitive;
+    impl TryFrom<U192> for i32 as primitive;
+    impl TryFrom<U192> for i64 as primitive;
+    impl TryFrom<U192> for i128 as primitive;
+    impl TryFrom<U192> for isize as primitive;
+    impl TryFrom<U192> for U16 as unaligned;
+    impl TryFrom<U192> for I16 as unaligned;
+    impl TryFrom<U192> for U24 as unaligned;
+    impl TryFrom<U192> for I24 as unaligned;
+    impl TryFrom<U192> for U32 as unaligned;
+    impl TryFrom<U192> for I32 as unaligned;
+    impl TryFrom<U192> for U40 as unaligned;
+    impl TryFrom<U192> for I40 as unaligned;
+    impl TryFrom<U192> for U48 as unaligned;
+    impl TryFrom<U192> for I48 as unaligned;
+    impl TryFrom<U192> for U56 as unaligned;
+    impl TryFrom<U192> for I56 as unaligned;
+    impl TryFrom<U192> for U64 as unaligned;
+    impl TryFrom<U192> for I64 as unaligned;
+    impl TryFrom<U192> for U72 as unaligned;
+    impl TryFrom<U192> for I72 as unaligned;
+    impl TryFrom<U192> for U80 as unaligned;
+    impl TryFrom<U192> for I80 as unaligned;
+    impl TryFrom<U192> for U88 as unaligned;
+    impl TryFrom<U192> for I88 as unaligned;
+    impl TryFrom<U192> for U96 as unaligned;
+    impl TryFrom<U192> for I96 as unaligned;
+    impl TryFrom<U192> for U104 as unaligned;
+    impl TryFrom<U192> for I104 as unaligned;
+    impl TryFrom<U192> for U112 as unaligned;
+    impl TryFrom<U192> for I112 as unaligned;
+    impl TryFrom<U192> for U120 as unaligned;
+    impl TryFrom<U192> for I120 as unaligned;
+    impl TryFrom<U192> for U128 as unaligned;
+    impl TryFrom<U192> for I128 as unaligned;
+    impl TryFrom<I192> for u8 as primitive;
+    impl TryFrom<I192> for u16 as primitive;
+    impl TryFrom<I192> for u32 as primitive;
+    impl TryFrom<I192> for u64 as primitive;
+    impl TryFrom<I192> for u128 as primitive;
+    impl TryFrom<I192> for usize as primitive;
+    impl TryFrom<I192> for i8 as primitive;
+    impl TryFrom<I192> for i16 as primitive;
+    impl TryFrom<I192> for i32 as primitive;
+    impl TryFrom<I192> for i64 as primitive;
+    impl TryFrom<I192> for i128 as primitive;
+    impl TryFrom<I192> for isize as primitive;
+    impl TryFrom<I192> for U16 as unaligned;
+    impl TryFrom<I192> for I16 as unaligned;
+    impl TryFrom<I192> for U24 as unaligned;
+    impl TryFrom<I192> for I24 as unaligned;
+    impl TryFrom<I192> for U32 as unaligned;
+    impl TryFrom<I192> for I32 as unaligned;
+    impl TryFrom<I192> for U40 as unaligned;
+    impl TryFrom<I192> for I40 as unaligned;
+    impl TryFrom<I192> for U48 as unaligned;
+    impl TryFrom<I192> for I48 as unaligned;
+    impl TryFrom<I192> for U56 as unaligned;
+    impl TryFrom<I192> for I56 as unaligned;
+    impl TryFrom<I192> for U64 as unaligned;
+    impl TryFrom<I192> for I64 as unaligned;
+    impl TryFrom<I192> for U72 as unaligned;
+    impl TryFrom<I192> for I72 as unaligned;
+    impl TryFrom<I192> for U80 as unaligned;
+    impl TryFrom<I192> for I80 as unaligned;
+    impl TryFrom<I192> for U88 as unaligned;
+    impl TryFrom<I192> for I88 as unaligned;
+    impl TryFrom<I192> for U96 as unaligned;
+    impl TryFrom<I192> for I96 as unaligned;
+    impl TryFrom<I192> for U104 as unaligned;
+    impl TryFrom<I192> for I104 as unaligned;
+    impl TryFrom<I192> for U112 as unaligned;
+    impl TryFrom<I192> for I112 as unaligned;
+    impl TryFrom<I192> for U120 as unaligned;
+    impl TryFrom<I192> for I120 as unaligned;
+    impl TryFrom<I192> for U128 as unaligned;
+    impl TryFrom<I192> for I128 as unaligned;
+    impl TryFrom<U200> for u8 as primitive;
+    impl TryFrom<U200> for u16 as primitive;
+    impl TryFrom<U200> for u32 as primitive;
+    impl TryFrom<U200> for u64 as primitive;
+    impl TryFrom<U200> for u128 as primitive;
+    impl TryFrom<U200> for usize as primitive;
+    impl TryFrom<U200> for i8 as primitive;
+    impl TryFrom<U200> for i16 as primitive;
+    impl TryFrom<U200> for i32 as primitive;
+    impl TryFrom<U200> for i64 as primitive;
+    impl TryFrom<U200> for i128 as primitive;
+    impl TryFrom<U200> for isize as primitive;
+    impl TryFrom<U200> for U16 as unaligned;
+    impl TryFrom<U200> for I16 as unaligned;
+    impl TryFrom<U200> for U24 as unaligned;
+    impl TryFrom<U200> for I24 as unaligned;
+    impl TryFrom<U200> for U32 as unaligned;
+    impl TryFrom<U200> for I32 as unaligned;
+    impl TryFrom<U200> for U40 as unaligned;
+    impl TryFrom<U200> for I40 as unaligned;
+    impl TryFrom<U200> for U48 as unaligned;
+    impl TryFrom<U200> for I48 as unaligned;
+    impl TryFrom<U200> for U56 as unaligned;
+    impl TryFrom<U200> for I56 as unaligned;
+    impl TryFrom<U200> for U64 as unaligned;
+    impl TryFrom<U200> for I64 as unaligned;
+    impl TryFrom<U200> for U72 as unaligned;
+    impl TryFrom<U200> for I72 as unaligned;
+    impl TryFrom<U200> for U80 as unaligned;
+    impl TryFrom<U200> for I80 as unaligned;
+    impl TryFrom<U200> for U88 as unaligned;
+    impl TryFrom<U200> for I88 as unaligned;
+    impl TryFrom<U200> for U96 as unaligned;
+    impl TryFrom<U200> for I96 as unaligned;
+    impl TryFrom<U200> for U104 as unaligned;
+    impl TryFrom<U200> for I104 as unaligned;
+    impl TryFrom<U200> for U112 as unaligned;
+    impl TryFrom<U200> for I112 as unaligned;
+    impl TryFrom<U200> for U120 as unaligned;
+    impl TryFrom<U200> for I120 as unaligned;
+    impl TryFrom<U200> for U128 as unaligned;
+    impl TryFrom<U200> for I128 as unaligned;
+    impl TryFrom<I200> for u8 as primitive;
+    impl TryFrom<I200> for u16 as primitive;
+    impl TryFrom<I200> for u32 as primitive;
+    impl TryFrom<I200> for u64 as primitive;
+    impl TryFrom<I200> for u128 as primitive;
+    impl TryFrom<I200> for usize as primitive;
+    impl TryFrom<I200> for i8 as primitive;
+    impl TryFrom<I200> for i16 as primitive;
+    impl TryFrom<I200> for i32 as primitive;
+    impl TryFrom<I200> for i64 as primitive;
+    impl TryFrom<I200> for i128 as primitive;
+    impl TryFrom<I200> for isize as primitive;
+    impl TryFrom<I200> for U16 as unaligned;
+    impl TryFrom<I200> for I16 as unaligned;
+    impl TryFrom<I200> for U24 as unaligned;
+    impl TryFrom<I200> for I24 as unaligned;
+    impl TryFrom<I200> for U32 as unaligned;
+    impl TryFrom<I200> for I32 as unaligned;
+    impl TryFrom<I200> for U40 as unaligned;
+    impl TryFrom<I200> for I40 as unaligned;
+    impl TryFrom<I200> for U48 as unaligned;
+    impl TryFrom<I200> for I48 as unaligned;
+    impl TryFrom<I200> for U56 as unaligned;
+    impl TryFrom<I200> for I56 as unaligned;
+    impl TryFrom<I200> for U64 as unaligned;
+    impl TryFrom<I200> for I64 as unaligned;
+    impl TryFrom<I200> for U72 as unaligned;
+    impl TryFrom<I200> for I72 as unaligned;
+    impl TryFrom<I200> for U80 as unaligned;
+    impl TryFrom<I200> for I80 as unaligned;
+    impl TryFrom<I200> for U88 as unaligned;
+    impl TryFrom<I200> for I88 as unaligned;
+    impl TryFrom<I200> for U96 as unaligned;
+    impl TryFrom<I200> for I96 as unaligned;
+    impl TryFrom<I200> for U104 as unaligned;
+    impl TryFrom<I200> for I104 as unaligned;
+    impl TryFrom<I200> for U112 as unaligned;
+    impl TryFrom<I200> for I112 as unaligned;
+    impl TryFrom<I200> for U120 as unaligned;
+    impl TryFrom<I200> for I120 as unaligned;
+    impl TryFrom<I200> for U128 as unaligned;
+    impl TryFrom<I200> for I128 as unaligned;
+    impl TryFrom<U208> for u8 as primitive;
+    impl TryFrom<U208> for u16 as primitive;
+    impl TryFrom<U208> for u32 as primitive;
+    impl TryFrom<U208> for u64 as primitive;
+    impl TryFrom<U208> for u128 as primitive;
+    impl TryFrom<U208> for usize as primitive;
+    impl TryFrom<U208> for i8 as primitive;
+    impl TryFrom<U208> for i16 as primitive;
+    impl TryFrom<U208> for i32 as primitive;
+    impl TryFrom<U208> for i64 as primitive;
+    impl TryFrom<U208> for i128 as primitive;
+    impl TryFrom<U208> for isize as primitive;
+    impl TryFrom<U208> for U16 as unaligned;
+    impl TryFrom<U208> for I16 as unaligned;
+    impl TryFrom<U208> for U24 as unaligned;
+    impl TryFrom<U208> for I24 as unaligned;
+    impl TryFrom<U208> for U32 as unaligned;
+    impl TryFrom<U208> for I32 as unaligned;
+    impl TryFrom<U208> for U40 as unaligned;
+    impl TryFrom<U208> for I40 as unaligned;
+    impl TryFrom<U208> for U48 as unaligned;
+    impl TryFrom<U208> for I48 as unaligned;
+    impl TryFrom<U208> for U56 as unaligned;
+    impl TryFrom<U208> for I56 as unaligned;
+    impl TryFrom<U208> for U64 as unaligned;
+    impl TryFrom<U208> for I64 as unaligned;
+    impl TryFrom<U208> for U72 as unaligned;
+    impl TryFrom<U208> for I72 as unaligned;
+    impl TryFrom<U208> for U80 as unaligned;
+    impl TryFrom<U208> for I80 as unaligned;
+    impl TryFrom<U208> for U88 as unaligned;
+    impl TryFrom<U208> for I88 as unaligned;
+    impl TryFrom<U208> for U96 as unaligned;
+    impl TryFrom<U208> for I96 as unaligned;
+    impl TryFrom<U208> for U104 as unaligned;
+    impl TryFrom<U208> for I104 as unaligned;
+    impl TryFrom<U208> for U112 as unaligned;
+    impl TryFrom<U208> for I112 as unaligned;
+    impl TryFrom<U208> for U120 as unaligned;
+    impl TryFrom<U208> for I120 as unaligned;
+    impl TryFrom<U208> for U128 as unaligned;
+    impl TryFrom<U208> for I128 as unaligned;
+    impl TryFrom<I208> for u8 as primitive;
+    impl TryFrom<I208> for u16 as primitive;
+    impl TryFrom<I208> for u32 as primitive;
+    impl TryFrom<I208> for u64 as primitive;
+    impl TryFrom<I208> for u128 as primitive;
+    impl TryFrom<I208> for usize as primitive;
+    impl TryFrom<I208> for i8 as primitive;
+    impl TryFrom<I208> for i16 as primitive;
+    impl TryFrom<I208> for i32 as primitive;
+    impl TryFrom<I208> for i64 as primitive;
+    impl TryFrom<I208> for i128 as primitive;
+    impl TryFrom<I208> for isize as primitive;
+    impl TryFrom<I208> for U16 as unaligned;
+    impl TryFrom<I208> for I16 as unaligned;
+    impl TryFrom<I208> for U24 as unaligned;
+    impl TryFrom<I208> for I24 as unaligned;
+    impl TryFrom<I208> for U32 as unaligned;
+    impl TryFrom<I208> for I32 as unaligned;
+    impl TryFrom<I208> for U40 as unaligned;
+    impl TryFrom<I208> for I40 as unaligned;
+    impl TryFrom<I208> for U48 as unaligned;
+    impl TryFrom<I208> for I48 as unaligned;
+    impl TryFrom<I208> for U56 as unaligned;
+    impl TryFrom<I208> for I56 as unaligned;
+    impl TryFrom<I208> for U64 as unaligned;
+    impl TryFrom<I208> for I64 as unaligned;
+    impl TryFrom<I208> for U72 as unaligned;
+    impl TryFrom<I208> for I72 as unaligned;
+    impl TryFrom<I208> for U80 as unaligned;
+    impl TryFrom<I208> for I80 as unaligned;
+    impl TryFrom<I208> for U88 as unaligned;
+    impl TryFrom<I208> for I88 as unaligned;
+    impl TryFrom<I208> for U96 as unaligned;
+    impl TryFrom<I208> for I96 as unaligned;
+    impl TryFrom<I208> for U104 as unaligned;
+    impl TryFrom<I208> for I104 as unaligned;
+    impl TryFrom<I208> for U112 as unaligned;
+    impl TryFrom<I208> for I112 as unaligned;
+    impl TryFrom<I208> for U120 as unaligned;
+    impl TryFrom<I208> for I120 as unaligned;
+    impl TryFrom<I208> for U128 as unaligned;
+    impl TryFrom<I208> for I128 as unaligned;
+    impl TryFrom<U216> for u8 as primitive;
+    impl TryFrom<U216> for u16 as primitive;
+    impl TryFrom<U216> for u32 as primitive;
+    impl TryFrom<U216> for u64 as primitive;
+    impl TryFrom<U216> for u128 as primitive;
+    impl TryFrom<U216> for usize as primitive;
+    impl TryFrom<U216> for i8 as primitive;
+    impl TryFrom<U216> for i16 as primitive;
+    impl TryFrom<U216> for i32 as primitive;
+    impl TryFrom<U216> for i64 as primitive;
+    impl TryFrom<U216> for i128 as primitive;
+    impl TryFrom<U216> for isize as primitive;
+    impl TryFrom<U216> for U16 as unaligned;
+    impl TryFrom<U216> for I16 as unaligned;
+    impl TryFrom<U216> for U24 as unaligned;
+    impl TryFrom<U216> for I24 as unaligned;
+    impl TryFrom<U216> for U32 as unaligned;
+    impl TryFrom<U216> for I32 as unaligned;
+    impl TryFrom<U216> for U40 as unaligned;
+    impl TryFrom<U216> for I40 as unaligned;
+    impl TryFrom<U216> for U48 as unaligned;
+    impl TryFrom<U216> for I48 as unaligned;
+    impl TryFrom<U216> for U56 as unaligned;
+    impl TryFrom<U216> for I56 as unaligned;
+    impl TryFrom<U216> for U64 as unaligned;
+    impl TryFrom<U216> for I64 as unaligned;
+    impl TryFrom<U216> for U72 as unaligned;
+    impl TryFrom<U216> for I72 as unaligned;
+    impl TryFrom<U216> for U80 as unaligned;
+    impl TryFrom<U216> for I80 as unaligned;
+    impl TryFrom<U216> for U88 as unaligned;
+    impl TryFrom<U216> for I88 as unaligned;
+    impl TryFrom<U216> for U96 as unaligned;
+    impl TryFrom<U216> for I96 as unaligned;
+    impl TryFrom<U216> for U104 as unaligned;
+    impl TryFrom<U216> for I104 as unaligned;
+    impl TryFrom<U216> for U112 as unaligned;
+    impl TryFrom<U216> for I112 as unaligned;
+    impl TryFrom<U216> for U120 as unaligned;
+    impl TryFrom<U216> for I120 as unaligned;
+    impl TryFrom<U216> for U128 as unaligned;
+    impl TryFrom<U216> for I128 as unaligned;
+    impl TryFrom<I216> for u8 as primitive;
+    impl TryFrom<I216> for u16 as primitive;
+    impl TryFrom<I216> for u32 as primitive;
+    impl TryFrom<I216> for u64 as primitive;
+    impl TryFrom<I216> for u128 as primitive;
+    impl TryFrom<I216> for usize as primitive;
+    impl TryFrom<I216> for i8 as primitive;
+    impl TryFrom<I216> for i16 as primitive;
+    impl TryFrom<I216> for i32 as primitive;
+    impl TryFrom<I216> for i64 as primitive;
+    impl TryFrom<I216> for i128 as primitive;
+    impl TryFrom<I216> for isize as primitive;
+    impl TryFrom<I216> for U16 as unaligned;
+    impl TryFrom<I216> for I16 as unaligned;
+    impl TryFrom<I216> for U24 as unaligned;
+    impl TryFrom<I216> for I24 as unaligned;
+    impl TryFrom<I216> for U32 as unaligned;
+    impl TryFrom<I216> for I32 as unaligned;
+    impl TryFrom<I216> for U40 as unaligned;
+    impl TryFrom<I216> for I40 as unaligned;
+    impl TryFrom<I216> for U48 as unaligned;
+    impl TryFrom<I216> for I48 as unaligned;
+    impl TryFrom<I216> for U56 as unaligned;
+    impl TryFrom<I216> for I56 as unaligned;
+    impl TryFrom<I216> for U64 as unaligned;
+    impl TryFrom<I216> for I64 as unaligned;
+    impl TryFrom<I216> for U72 as unaligned;
+    impl TryFrom<I216> for I72 as unaligned;
+    impl TryFrom<I216> for U80 as unaligned;
+    impl TryFrom<I216> for I80 as unaligned;
+    impl TryFrom<I216> for U88 as unaligned;
+    impl TryFrom<I216> for I88 as unaligned;
+    impl TryFrom<I216> for U96 as unaligned;
+    impl TryFrom<I216> for I96 as unaligned;
+    impl TryFrom<I216> for U104 as unaligned;
+    impl TryFrom<I216> for I104 as unaligned;
+    impl TryFrom<I216> for U112 as unaligned;
+    impl TryFrom<I216> for I112 as unaligned;
+    impl TryFrom<I216> for U120 as unaligned;
+    impl TryFrom<I216> for I120 as unaligned;
+    impl TryFrom<I216> for U128 as unaligned;
+    impl TryFrom<I216> for I128 as unaligned;
+    impl TryFrom<U224> for u8 as primitive;
+    impl TryFrom<U224> for u16 as primitive;
+    impl TryFrom<U224> for u32 as primitive;
+    impl TryFrom<U224> for u64 as primitive;
+    impl TryFrom<U224> for u128 as primitive;
+    impl TryFrom<U224> for usize as primitive;
+    impl TryFrom<U224> for i8 as primitive;
+    impl TryFrom<U224> for i16 as primitive;
+    impl TryFrom<U224> for i32 as primitive;
+    impl TryFrom<U224> for i64 as primitive;
+    impl TryFrom<U224> for i128 as primitive;
+    impl TryFrom<U224> for isize as primitive;
+    impl TryFrom<U224> for U16 as unaligned;
+    impl TryFrom<U224> for I16 as unaligned;
+    impl TryFrom<U224> for U24 as unaligned;
+    impl TryFrom<U224> for I24 as unaligned;
+    impl TryFrom<U224> for U32 as unaligned;
+    impl TryFrom<U224> for I32 as unaligned;
+    impl TryFrom<U224> for U40 as unaligned;
+    impl TryFrom<U224> for I40 as unaligned;
+    impl TryFrom<U224> for U48 as unaligned;
+    impl TryFrom<U224> for I48 as unaligned;
+    impl TryFrom<U224> for U56 as unaligned;
+    impl TryFrom<U224> for I56 as unaligned;
+    impl TryFrom<U224> for U64 as unaligned;
+    impl TryFrom<U224> for I64 as unaligned;
+    impl TryFrom<U224> for U72 as unaligned;
+    impl TryFrom<U224> for I72 as unaligned;
+    impl TryFrom<U224> for U80 as unaligned;
+    impl TryFrom<U224> for I80 as unaligned;
+    impl TryFrom<U224> for U88 as unaligned;
+    impl TryFrom<U224> for I88 as unaligned;
+    impl TryFrom<U224> for U96 as unaligned;
+    impl TryFrom<U224> for I96 as unaligned;
+    impl TryFrom<U224> for U104 as unaligned;
+    impl TryFrom<U224> for I104 as unaligned;
+    impl TryFrom<U224> for U112 as unaligned;
+    impl TryFrom<U224> for I112 as unaligned;
+    impl TryFrom<U224> for U120 as unaligned;
+    impl TryFrom<U224> for I120 as unaligned;
+    impl TryFrom<U224> for U128 as unaligned;
+    impl TryFrom<U224> for I128 as unaligned;
+    impl TryFrom<I224> for u8 as primitive;
+    impl TryFrom<I224> for u16 as primitive;
+    impl TryFrom<I224> for u32 as primitive;
+    impl TryFrom<I224> for u64 as primitive;
+    impl TryFrom<I224> for u128 as primitive;
+    impl TryFrom<I224> for usize as primitive;
+    impl TryFrom<I224> for i8 as primitive;
+    impl TryFrom<I224> for i16 as primitive;
+    impl TryFrom<I224> for i32 as primitive;
+    impl TryFrom<I224> for i64 as primitive;
+    impl TryFrom<I224> for i128 as primitive;
+    impl TryFrom<I224> for isize as primitive;
+    impl TryFrom<I224> for U16 as unaligned;
+    impl TryFrom<I224> for I16 as unaligned;
+    impl TryFrom<I224> for U24 as unaligned;
+    impl TryFrom<I224> for I24 as unaligned;
+    impl TryFrom<I224> for U32 as unaligned;
+    impl TryFrom<I224> for I32 as unaligned;
+    impl TryFrom<I224> for U40 as unaligned;
+    impl TryFrom<I224> for I40 as unaligned;
+    impl TryFrom<I224> for U48 as unaligned;
+    impl TryFrom<I224> for I48 as unaligned;
+    impl TryFrom<I224> for U56 as unaligned;
+    impl TryFrom<I224> for I56 as unaligned;
+    impl TryFrom<I224> for U64 as unaligned;
+    impl TryFrom<I224> for I64 as unaligned;
+    impl TryFrom<I224> for U72 as unaligned;
+    impl TryFrom<I224> for I72 as unaligned;
+    impl TryFrom<I224> for U80 as unaligned;
+    impl TryFrom<I224> for I80 as unaligned;
+    impl TryFrom<I224> for U88 as unaligned;
+    impl TryFrom<I224> for I88 as unaligned;
+    impl TryFrom<I224> for U96 as unaligned;
+    impl TryFrom<I224> for I96 as unaligned;
+    impl TryFrom<I224> for U104 as unaligned;
+    impl TryFrom<I224> for I104 as unaligned;
+    impl TryFrom<I224> for U112 as unaligned;
+    impl TryFrom<I224> for I112 as unaligned;
+    impl TryFrom<I224> for U120 as unaligned;
+    impl TryFrom<I224> for I120 as unaligned;
+    impl TryFrom<I224> for U128 as unaligned;
+    impl TryFrom<I224> for I128 as unaligned;
+    impl TryFrom<U232> for u8 as primitive;
+    impl TryFrom<U232> for u16 as primitive;
+    impl TryFrom<U232> for u32 as primitive;
+    impl TryFrom<U232> for u64 as primitive;
+    impl TryFrom<U232> for u128 as primitive;
+    impl TryFrom<U232> for usize as primitive;
+    impl TryFrom<U232> for i8 as primitive;
+    impl TryFrom<U232> for i16 as primitive;
+    impl TryFrom<U232> for i32 as primitive;
+    impl TryFrom<U232> for i64 as primitive;
+    impl TryFrom<U232> for i128 as primitive;
+    impl TryFrom<U232> for isize as primitive;
+    impl TryFrom<U232> for U16 as unaligned;
+    impl TryFrom<U232> for I16 as unaligned;
+    impl TryFrom<U232> for U24 as unaligned;
+    impl TryFrom<U232> for I24 as unaligned;
+    impl TryFrom<U232> for U32 as unaligned;
+    impl TryFrom<U232> for I32 as unaligned;
+    impl TryFrom<U232> for U40 as unaligned;
+    impl TryFrom<U232> for I40 as unaligned;
+    impl TryFrom<U232> for U48 as unaligned;
+    impl TryFrom<U232> for I48 as unaligned;
+    impl TryFrom<U232> for U56 as unaligned;
+    impl TryFrom<U232> for I56 as unaligned;
+    impl TryFrom<U232> for U64 as unaligned;
+    impl TryFrom<U232> for I64 as unaligned;
+    impl TryFrom<U232> for U72 as unaligned;
+    impl TryFrom<U232> for I72 as unaligned;
+    impl TryFrom<U232> for U80 as unaligned;
+    impl TryFrom<U232> for I80 as unaligned;
+    impl TryFrom<U232> for U88 as unaligned;
+    impl TryFrom<U232> for I88 as unaligned;
+    impl TryFrom<U232> for U96 as unaligned;
+    impl TryFrom<U232> for I96 as unaligned;
+    impl TryFrom<U232> for U104 as unaligned;
+    impl TryFrom<U232> for I104 as unaligned;
+    impl TryFrom<U232> for U112 as unaligned;
+    impl TryFrom<U232> for I112 as unaligned;
+    impl TryFrom<U232> for U120 as unaligned;
+    impl TryFrom<U232> for I120 as unaligned;
+    impl TryFrom<U232> for U128 as unaligned;
+    impl TryFrom<U232> for I128 as unaligned;
+    impl TryFrom<I232> for u8 as primitive;
+    impl TryFrom<I232> for u16 as primitive;
+    impl TryFrom<I232> for u32 as primitive;
+    impl TryFrom<I232> for u64 as primitive;
+    impl TryFrom<I232> for u128 as primitive;
+    impl TryFrom<I232> for usize as primitive;
+    impl TryFrom<I232> for i8 as primitive;
+    impl TryFrom<I232> for i16 as primitive;
+    impl TryFrom<I232> for i32 as primitive;
+    impl TryFrom<I232> for i64 as primitive;
+    impl TryFrom<I232> for i128 as primitive;
+    impl TryFrom<I232> for isize as primitive;
+    impl TryFrom<I232> for U16 as unaligned;
+    impl TryFrom<I232> for I16 as unaligned;
+    impl TryFrom<I232> for U24 as unaligned;
+    impl TryFrom<I232> for I24 as unaligned;
+    impl TryFrom<I232> for U32 as unaligned;
+    impl TryFrom<I232> for I32 as unaligned;
+    impl TryFrom<I232> for U40 as unaligned;
+    impl TryFrom<I232> for I40 as unaligned;
+    impl TryFrom<I232> for U48 as unaligned;
+    impl TryFrom<I232> for I48 as unaligned;
+    impl TryFrom<I232> for U56 as unaligned;
+    impl TryFrom<I232> for I56 as unaligned;
+    impl TryFrom<I232> for U64 as unaligned;
+    impl TryFrom<I232> for I64 as unaligned;
+    impl TryFrom<I232> for U72 as unaligned;
+    impl TryFrom<I232> for I72 as unaligned;
+    impl TryFrom<I232> for U80 as unaligned;
+    impl TryFrom<I232> for I80 as unaligned;
+    impl TryFrom<I232> for U88 as unaligned;
+    impl TryFrom<I232> for I88 as unaligned;
+    impl TryFrom<I232> for U96 as unaligned;
+    impl TryFrom<I232> for I96 as unaligned;
+    impl TryFrom<I232> for U104 as unaligned;
+    impl TryFrom<I232> for I104 as unaligned;
+    impl TryFrom<I232> for U112 as unaligned;
+    impl TryFrom<I232> for I112 as unaligned;
+    impl TryFrom<I232> for U120 as unaligned;
+    impl TryFrom<I232> for I120 as unaligned;
+    impl TryFrom<I232> for U128 as unaligned;
+    impl TryFrom<I232> for I128 as unaligned;
+    impl TryFrom<U240> for u8 as primitive;
+    impl TryFrom<U240> for u16 as primitive;
+    impl TryFrom<U240> for u32 as primitive;
+    impl TryFrom<U240> for u64 as primitive;
+    impl TryFrom<U240> for u128 as primitive;
+    impl TryFrom<U240> for usize as primitive;
+    impl TryFrom<U240> for i8 as primitive;
+    impl TryFrom<U240> for i16 as primitive;
+    impl TryFrom<U240> for i32 as primitive;
+    impl TryFrom<U240> for i64 as primitive;
+    impl TryFrom<U240> for i128 as primitive;
+    impl TryFrom<U240> for isize as primitive;
+    impl TryFrom<U240> for U16 as unaligned;
+    impl TryFrom<U240> for I16 as unaligned;
+    impl TryFrom<U240> for U24 as unaligned;
+    impl TryFrom<U240> for I24 as unaligned;
+    impl TryFrom<U240> for U32 as unaligned;
+    impl TryFrom<U240> for I32 as unaligned;
+    impl TryFrom<U240> for U40 as unaligned;
+    impl TryFrom<U240> for I40 as unaligned;
+    impl TryFrom<U240> for U48 as unaligned;
+    impl TryFrom<U240> for I48 as unaligned;
+    impl TryFrom<U240> for U56 as unaligned;
+    impl TryFrom<U240> for I56 as unaligned;
+    impl TryFrom<U240> for U64 as unaligned;
+    impl TryFrom<U240> for I64 as unaligned;
+    impl TryFrom<U240> for U72 as unaligned;
+    impl TryFrom<U240> for I72 as unaligned;
+    impl TryFrom<U240> for U80 as unaligned;
+    impl TryFrom<U240> for I80 as unaligned;
+    impl TryFrom<U240> for U88 as unaligned;
+    impl TryFrom<U240> for I88 as unaligned;
+    impl TryFrom<U240> for U96 as unaligned;
+    impl TryFrom<U240> for I96 as unaligned;
+    impl TryFrom<U240> for U104 as unaligned;
+    impl TryFrom<U240> for I104 as unaligned;
+    impl TryFrom<U240> for U112 as unaligned;
+    impl TryFrom<U240> for I112 as unaligned;
+    impl TryFrom<U240> for U120 as unaligned;
+    impl TryFrom<U240> for I120 as unaligned;
+    impl TryFrom<U240> for U128 as unaligned;
+    impl TryFrom<U240> for I128 as unaligned;
+    impl TryFrom<I240> for u8 as primitive;
+    impl TryFrom<I240> for u16 as primitive;
+    impl TryFrom<I240> for u32 as primitive;
+    impl TryFrom<I240> for u64 as primitive;
+    impl TryFrom<I240> for u128 as primitive;
+    impl TryFrom<I240> for usize as primitive;
+    impl TryFrom<I240> for i8 as primitive;
+    impl TryFrom<I240> for i16 as primitive;
+    impl TryFrom<I240> for i32 as primitive;
+    impl TryFrom<I240> for i64 as primitive;
+    impl TryFrom<I240> for i128 as primitive;
+    impl TryFrom<I240> for isize as primitive;
+    impl TryFrom<I240> for U16 as unaligned;
+    impl TryFrom<I240> for I16 as unaligned;
+    impl TryFrom<I240> for U24 as unaligned;
+    impl TryFrom<I240> for I24 as unaligned;
+    impl TryFrom<I240> for U32 as unaligned;
+    impl TryFrom<I240> for I32 as unaligned;
+    impl TryFrom<I240> for U40 as unaligned;
+    impl TryFrom<I240> for I40 as unaligned;
+    impl TryFrom<I240> for U48 as unaligned;
+    impl TryFrom<I240> for I48 as unaligned;
+    impl TryFrom<I240> for U56 as unaligned;
+    impl TryFrom<I240> for I56 as unaligned;
+    impl TryFrom<I240> for U64 as unaligned;
+    impl TryFrom<I240> for I64 as unaligned;
+    impl TryFrom<I240> for U72 as unaligned;
+    impl TryFrom<I240> for I72 as unaligned;
+    impl TryFrom<I240> for U80 as unaligned;
+    impl TryFrom<I240> for I80 as unaligned;
+    impl TryFrom<I240> for U88 as unaligned;
+    impl TryFrom<I240> for I88 as unaligned;
+    impl TryFrom<I240> for U96 as unaligned;
+    impl TryFrom<I240> for I96 as unaligned;
+    impl TryFrom<I240> for U104 as unaligned;
+    impl TryFrom<I240> for I104 as unaligned;
+    impl TryFrom<I240> for U112 as unaligned;
+    impl TryFrom<I240> for I112 as unaligned;
+    impl TryFrom<I240> for U120 as unaligned;
+    impl TryFrom<I240> for I120 as unaligned;
+    impl TryFrom<I240> for U128 as unaligned;
+    impl TryFrom<I240> for I128 as unaligned;
+    impl TryFrom<U248> for u8 as primitive;
+    impl TryFrom<U248> for u16 as primitive;
+    impl TryFrom<U248> for u32 as primitive;
+    impl TryFrom<U248> for u64 as primitive;
+    impl TryFrom<U248> for u128 as primitive;
+    impl TryFrom<U248> for usize as primitive;
+    impl TryFrom<U248> for i8 as primitive;
+    impl TryFrom<U248> for i16 as primitive;
+    impl TryFrom<U248> for i32 as primitive;
+    impl TryFrom<U248> for i64 as primitive;
+    impl TryFrom<U248> for i128 as primitive;
+    impl TryFrom<U248> for isize as primitive;
+    impl TryFrom<U248> for U16 as unaligned;
+    impl TryFrom<U248> for I16 as unaligned;
+    impl TryFrom<U248> for U24 as unaligned;
+    impl TryFrom<U248> for I24 as unaligned;
+    impl TryFrom<U248> for U32 as unaligned;
+    impl TryFrom<U248> for I32 as unaligned;
+    impl TryFrom<U248> for U40 as unaligned;
+    impl TryFrom<U248> for I40 as unaligned;
+    impl TryFrom<U248> for U48 as unaligned;
+    impl TryFrom<U248> for I48 as unaligned;
+    impl TryFrom<U248> for U56 as unaligned;
+    impl TryFrom<U248> for I56 as unaligned;
+    impl TryFrom<U248> for U64 as unaligned;
+    impl TryFrom<U248> for I64 as unaligned;
+    impl TryFrom<U248> for U72 as unaligned;
+    impl TryFrom<U248> for I72 as unaligned;
+    impl TryFrom<U248> for U80 as unaligned;
+    impl TryFrom<U248> for I80 as unaligned;
+    impl TryFrom<U248> for U88 as unaligned;
+    impl TryFrom<U248> for I88 as unaligned;
+    impl TryFrom<U248> for U96 as unaligned;
+    impl TryFrom<U248> for I96 as unaligned;
+    impl TryFrom<U248> for U104 as unaligned;
+    impl TryFrom<U248> for I104 as unaligned;
+    impl TryFrom<U248> for U112 as unaligned;
+    impl TryFrom<U248> for I112 as unaligned;
+    impl TryFrom<U248> for U120 as unaligned;
+    impl TryFrom<U248> for I120 as unaligned;
+    impl TryFrom<U248> for U128 as unaligned;
+    impl TryFrom<U248> for I128 as unaligned;
+    impl TryFrom<I248> for u8 as primitive;
+    impl TryFrom<I248> for u16 as primitive;
+    impl TryFrom<I248> for u32 as primitive;
+    impl TryFrom<I248> for u64 as primitive;
+    impl TryFrom<I248> for u128 as primitive;
+    impl TryFrom<I248> for usize as primitive;
+    impl TryFrom<I248> for i8 as primitive;
+    impl TryFrom<I248> for i16 as primitive;
+    impl TryFrom<I248> for i32 as primitive;
+    impl TryFrom<I248> for i64 as primitive;
+    impl TryFrom<I248> for i128 as primitive;
+    impl TryFrom<I248> for isize as primitive;
+    impl TryFrom<I248> for U16 as unaligned;
+    impl TryFrom<I248> for I16 as unaligned;
+    impl TryFrom<I248> for U24 as unaligned;
+    impl TryFrom<I248> for I24 as unaligned;
+    impl TryFrom<I248> for U32 as unaligned;
+    impl TryFrom<I248> for I32 as unaligned;
+    impl TryFrom<I248> for U40 as unaligned;
+    impl TryFrom<I248> for I40 as unaligned;
+    impl TryFrom<I248> for U48 as unaligned;
+    impl TryFrom<I248> for I48 as unaligned;
+    impl TryFrom<I248> for U56 as unaligned;
+    impl TryFrom<I248> for I56 as unaligned;
+    impl TryFrom<I248> for U64 as unaligned;
+    impl TryFrom<I248> for I64 as unaligned;
+    impl TryFrom<I248> for U72 as unaligned;
+    impl TryFrom<I248> for I72 as unaligned;
+    impl TryFrom<I248> for U80 as unaligned;
+    impl TryFrom<I248> for I80 as unaligned;
+    impl TryFrom<I248> for U88 as unaligned;
+    impl TryFrom<I248> for I88 as unaligned;
+    impl TryFrom<I248> for U96 as unaligned;
+    impl TryFrom<I248> for I96 as unaligned;
+    impl TryFrom<I248> for U104 as unaligned;
+    impl TryFrom<I248> for I104 as unaligned;
+    impl TryFrom<I248> for U112 as unaligned;
+    impl TryFrom<I248> for I112 as unaligned;
+    impl TryFrom<I248> for U120 as unaligned;
+    impl TryFrom<I248> for I120 as unaligned;
+    impl TryFrom<I248> for U128 as unaligned;
+    impl TryFrom<I248> for I128 as unaligned;
+    impl TryFrom<U256> for u8 as primitive;
+    impl TryFrom<U256> for u16 as primitive;
+    impl TryFrom<U256> for u32 as primitive;
+    impl TryFrom<U256> for u64 as primitive;
+    impl TryFrom<U256> for u128 as primitive;
+    impl TryFrom<U256> for usize as primitive;
+    impl TryFrom<U256> for i8 as primitive;
+    impl TryFrom<U256> for i16 as primitive;
+    impl TryFrom<U256> for i32 as primitive;
+    impl TryFrom<U256> for i64 as primitive;
+    impl TryFrom<U256> for i128 as primitive;
+    impl TryFrom<U256> for isize as primitive;
+    impl TryFrom<U256> for U16 as unaligned;
+    impl TryFrom<U256> for I16 as unaligned;
+    impl TryFrom<U256> for U24 as unaligned;
+    impl TryFrom<U256> for I24 as unaligned;
+    impl TryFrom<U256> for U32 as unaligned;
+    impl TryFrom<U256> for I32 as unaligned;
+    impl TryFrom<U256> for U40 as unaligned;
+    impl TryFrom<U256> for I40 as unaligned;
+    impl TryFrom<U256> for U48 as unaligned;
+    impl TryFrom<U256> for I48 as unaligned;
+    impl TryFrom<U256> for U56 as unaligned;
+    impl TryFrom<U256> for I56 as unaligned;
+    impl TryFrom<U256> for U64 as unaligned;
+    impl TryFrom<U256> for I64 as unaligned;
+    impl TryFrom<U256> for U72 as unaligned;
+    impl TryFrom<U256> for I72 as unaligned;
+    impl TryFrom<U256> for U80 as unaligned;
+    impl TryFrom<U256> for I80 as unaligned;
+    impl TryFrom<U256> for U88 as unaligned;
+    impl TryFrom<U256> for I88 as unaligned;
+    impl TryFrom<U256> for U96 as unaligned;
+    impl TryFrom<U256> for I96 as unaligned;
+    impl TryFrom<U256> for U104 as unaligned;
+    impl TryFrom<U256> for I104 as unaligned;
+    impl TryFrom<U256> for U112 as unaligned;
+    impl TryFrom<U256> for I112 as unaligned;
+    impl TryFrom<U256> for U120 as unaligned;
+    impl TryFrom<U256> for I120 as unaligned;
+    impl TryFrom<U256> for U128 as unaligned;
+    impl TryFrom<U256> for I128 as unaligned;
+    impl TryFrom<I256> for u8 as primitive;
+    impl TryFrom<I256> for u16 as primitive;
+    impl TryFrom<I256> for u32 as primitive;
+    impl TryFrom<I256> for u64 as primitive;
+    impl TryFrom<I256> for u128 as primitive;
+    impl TryFrom<I256> for usize as primitive;
+    impl TryFrom<I256> for i8 as primitive;
+    impl TryFrom<I256> for i16 as primitive;
+    impl TryFrom<I256> for i32 as primitive;
+    impl TryFrom<I256> for i64 as primitive;
+    impl TryFrom<I256> for i128 as primitive;
+    impl TryFrom<I256> for isize as primitive;
+    impl TryFrom<I256> for U16 as unaligned;
+    impl TryFrom<I256> for I16 as unaligned;
+    impl TryFrom<I256> for U24 as unaligned;
+    impl TryFrom<I256> for I24 as unaligned;
+    impl TryFrom<I256> for U32 as unaligned;
+    impl TryFrom<I256> for I32 as unaligned;
+    impl TryFrom<I256> for U40 as unaligned;
+    impl TryFrom<I256> for I40 as unaligned;
+    impl TryFrom<I256> for U48 as unaligned;
+    impl TryFrom<I256> for I48 as unaligned;
+    impl TryFrom<I256> for U56 as unaligned;
+    impl TryFrom<I256> for I56 as unaligned;
+    impl TryFrom<I256> for U64 as unaligned;
+    impl TryFrom<I256> for I64 as unaligned;
+    impl TryFrom<I256> for U72 as unaligned;
+    impl TryFrom<I256> for I72 as unaligned;
+    impl TryFrom<I256> for U80 as unaligned;
+    impl TryFrom<I256> for I80 as unaligned;
+    impl TryFrom<I256> for U88 as unaligned;
+    impl TryFrom<I256> for I88 as unaligned;
+    impl TryFrom<I256> for U96 as unaligned;
+    impl TryFrom<I256> for I96 as unaligned;
+    impl TryFrom<I256> for U104 as unaligned;
+    impl TryFrom<I256> for I104 as unaligned;
+    impl TryFrom<I256> for U112 as unaligned;
+    impl TryFrom<I256> for I112 as unaligned;
+    impl TryFrom<I256> for U120 as unaligned;
+    impl TryFrom<I256> for I120 as unaligned;
+    impl TryFrom<I256> for U128 as unaligned;
+    impl TryFrom<I256> for I128 as unaligned;
+}
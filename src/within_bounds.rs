@@ -1,6 +1,6 @@
 pub use crate::{
-    I104, I112, I120, I128, I16, I24, I32, I40, I48, I56, I64, I72, I80, I88, I96, U104, U112,
-    U120, U128, U16, U24, U32, U40, U48, U56, U64, U72, U80, U88, U96,
+    I104, I112, I120, I24, I40, I48, I56, I72, I80, I88, I96, U104, U112, U120, U128, U16, U24,
+    U32, U40, U48, U56, U64, U72, U80, U88, U96,
 };
 
 /// Convenience trait implemented by primitive integers to streamline